@@ -0,0 +1,324 @@
+use std::fmt::Display;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use headless_chrome::{Browser, Element as ChromeNode, LaunchOptions, Tab};
+use headless_chrome::protocol::cdp::DOM;
+use headless_chrome::protocol::cdp::DOM::NodeId;
+use headless_chrome::protocol::cdp::Network::events::ResponseReceivedEventParams;
+use headless_chrome::protocol::cdp::Network::{ClearBrowserCookies, GetResponseBodyReturnObject};
+use headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption;
+use headless_chrome::protocol::cdp::types::Event;
+use serde::Serialize;
+
+use crate::client::driver::{Driver, Element, Locator};
+use crate::client::driver::web::CLEAR_STORAGE;
+use crate::error::{CeriumError, CeriumResult};
+
+/// ConsoleLog - console / browser log entry captured from the page
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsoleLog {
+    pub level: String,
+    pub text: String,
+    pub url: Option<String>,
+}
+
+/// NetworkEvent - response received by the page
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkEvent {
+    pub url: String,
+    pub status: i64,
+    pub mime_type: String,
+}
+
+/// ChromeDriver - local chrome session driven over the Chrome DevTools Protocol,
+/// no selenium grid is needed to run with this backend
+pub struct ChromeDriver {
+    browser: Browser,
+    tab: RwLock<Arc<Tab>>,
+    console_logs: Arc<Mutex<Vec<ConsoleLog>>>,
+    network_events: Arc<Mutex<Vec<NetworkEvent>>>,
+}
+
+/// blocking - headless_chrome is a sync client, run the call on the blocking pool
+async fn blocking<T, E, F>(call: F) -> CeriumResult<T>
+    where
+        T: Send + 'static,
+        E: Display,
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+{
+    tokio::task::spawn_blocking(call)
+        .await
+        .map_err(|err| CeriumError::DriverError(err.to_string()))?
+        .map_err(|err| CeriumError::DriverError(err.to_string()))
+}
+
+/// shadow_root - walk the shadow hosts in the page and return the node of the innermost
+/// shadow root, the target of the shadow locator is looked up in it
+fn shadow_root(tab: &Tab, hosts: &[String]) -> anyhow::Result<NodeId> {
+    tab.get_document()?;
+    let script = format!(
+        "(() => {{ let root = document; for (const selector of {}) {{ \
+            const host = root.querySelector(selector); \
+            if (!host || !host.shadowRoot) return null; root = host.shadowRoot; }} \
+            return root; }})()",
+        serde_json::to_string(hosts)?
+    );
+    let object_id = tab.evaluate(&script, false)?.object_id
+        .ok_or_else(|| anyhow::anyhow!("no shadow root found for {:?}", hosts))?;
+    Ok(tab.call_method(DOM::RequestNode { object_id })?.node_id)
+}
+
+/// find_nodes - node ids of the elements matching the locator
+fn find_nodes(tab: &Tab, locator: &Locator, all: bool) -> anyhow::Result<Vec<NodeId>> {
+    let elements = match (locator.shadow_hosts(), locator.css(), locator.xpath()) {
+        (Some(hosts), Some(css), _) => {
+            let root = shadow_root(tab, &hosts)?;
+            match all {
+                true => tab.run_query_selector_all_on_node(root, &css)?,
+                false => vec![tab.run_query_selector_on_node(root, &css)?],
+            }
+        }
+        (None, Some(css), _) if all => tab.find_elements(&css)?,
+        (None, Some(css), _) => vec![tab.find_element(&css)?],
+        (_, None, Some(xpath)) if all => tab.find_elements_by_xpath(&xpath)?,
+        (_, None, Some(xpath)) => vec![tab.find_element_by_xpath(&xpath)?],
+        _ => anyhow::bail!("unsupported locator {:?}", locator),
+    };
+    Ok(elements.iter().map(|element| element.node_id).collect())
+}
+
+impl ChromeDriver {
+    pub async fn default() -> CeriumResult<Self> {
+        Self::launch(true).await
+    }
+
+    /// launch - will start a new local chrome process and attach the listeners
+    /// for the console logs and network events
+    pub async fn launch(headless: bool) -> CeriumResult<Self> {
+        let browser = blocking(move || {
+            Browser::new(LaunchOptions {
+                headless,
+                idle_browser_timeout: Duration::from_secs(600),
+                ..Default::default()
+            })
+        }).await?;
+        let tab_browser = browser.clone();
+        let tab = blocking(move || tab_browser.new_tab()).await?;
+        let driver = Self {
+            browser,
+            tab: RwLock::new(tab.clone()),
+            console_logs: Arc::new(Mutex::new(Vec::new())),
+            network_events: Arc::new(Mutex::new(Vec::new())),
+        };
+        driver.listen(tab).await?;
+        Ok(driver)
+    }
+
+    /// listen - capture the console logs and the network responses of the tab
+    async fn listen(&self, tab: Arc<Tab>) -> CeriumResult<()> {
+        let console_logs = self.console_logs.clone();
+        let network_events = self.network_events.clone();
+        blocking(move || {
+            tab.enable_log()?;
+            tab.enable_runtime()?;
+            tab.add_event_listener(Arc::new(move |event: &Event| {
+                let log = match event {
+                    Event::LogEntryAdded(ev) => ConsoleLog {
+                        level: format!("{:?}", ev.params.entry.level),
+                        text: ev.params.entry.text.clone(),
+                        url: ev.params.entry.url.clone(),
+                    },
+                    Event::RuntimeConsoleAPICalled(ev) => ConsoleLog {
+                        level: format!("{:?}", ev.params.Type),
+                        text: ev.params.args.iter()
+                            .map(|arg| match (&arg.value, &arg.description) {
+                                (Some(serde_json::Value::String(value)), _) => value.clone(),
+                                (Some(value), _) => value.to_string(),
+                                (None, Some(description)) => description.clone(),
+                                (None, None) => String::new(),
+                            })
+                            .collect::<Vec<String>>()
+                            .join(" "),
+                        url: None,
+                    },
+                    _ => return,
+                };
+                if let Ok(mut logs) = console_logs.lock() {
+                    logs.push(log);
+                }
+            }))?;
+            tab.register_response_handling("orca", Box::new(
+                move |params: ResponseReceivedEventParams,
+                      _body: &dyn Fn() -> anyhow::Result<GetResponseBodyReturnObject>| {
+                    if let Ok(mut events) = network_events.lock() {
+                        events.push(NetworkEvent {
+                            url: params.response.url.clone(),
+                            status: params.response.status as i64,
+                            mime_type: params.response.mime_type.clone(),
+                        });
+                    }
+                }))?;
+            Ok::<(), anyhow::Error>(())
+        }).await
+    }
+
+    fn tab(&self) -> Arc<Tab> {
+        self.tab.read().expect("chrome tab lock poisoned").clone()
+    }
+
+    /// console_logs - console and browser log entries captured so far
+    pub fn console_logs(&self) -> Vec<ConsoleLog> {
+        self.console_logs.lock().map(|logs| logs.clone()).unwrap_or_default()
+    }
+
+    /// network_events - network responses captured so far
+    pub fn network_events(&self) -> Vec<NetworkEvent> {
+        self.network_events.lock().map(|events| events.clone()).unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl Driver for ChromeDriver {
+    async fn session_id(&self) -> CeriumResult<String> {
+        Ok(self.tab().get_target_id().to_string())
+    }
+
+    async fn open(&self, url: &str) -> CeriumResult<()> {
+        let tab = self.tab();
+        let url = url.to_string();
+        blocking(move || tab.navigate_to(&url)?.wait_until_navigated().map(|_| ())).await
+    }
+
+    async fn current_url(&self) -> CeriumResult<String> {
+        Ok(self.tab().get_url())
+    }
+
+    async fn title(&self) -> CeriumResult<String> {
+        let tab = self.tab();
+        blocking(move || tab.get_title()).await
+    }
+
+    async fn create_window(&self, _name: &str) -> CeriumResult<()> {
+        let browser = self.browser.clone();
+        let tab = blocking(move || browser.new_tab()).await?;
+        self.listen(tab.clone()).await?;
+        *self.tab.write().expect("chrome tab lock poisoned") = tab;
+        Ok(())
+    }
+
+    async fn find(&self, locator: &Locator) -> CeriumResult<Box<dyn Element>> {
+        let tab = self.tab();
+        let locator = locator.clone();
+        let node_id = {
+            let tab = tab.clone();
+            blocking(move || {
+                find_nodes(&tab, &locator, false)?
+                    .pop()
+                    .ok_or_else(|| anyhow::anyhow!("no such element: {:?}", locator))
+            }).await?
+        };
+        Ok(Box::new(ChromeElement { tab, node_id }))
+    }
+
+    async fn find_all(&self, locator: &Locator) -> CeriumResult<Vec<Box<dyn Element>>> {
+        let tab = self.tab();
+        let locator = locator.clone();
+        let node_ids = {
+            let tab = tab.clone();
+            blocking(move || find_nodes(&tab, &locator, true)).await?
+        };
+        Ok(node_ids
+            .into_iter()
+            .map(|node_id| Box::new(ChromeElement { tab: tab.clone(), node_id }) as Box<dyn Element>)
+            .collect())
+    }
+
+    async fn take_screenshot(&self) -> CeriumResult<Vec<u8>> {
+        let tab = self.tab();
+        blocking(move || tab.capture_screenshot(CaptureScreenshotFormatOption::Png, None, None, true)).await
+    }
+
+    async fn reset(&self) -> CeriumResult<()> {
+        let tab = self.tab();
+        blocking(move || {
+            tab.call_method(ClearBrowserCookies(None))?;
+            tab.evaluate(CLEAR_STORAGE, false)?;
+            tab.navigate_to("about:blank")?.wait_until_navigated()?;
+            Ok::<(), anyhow::Error>(())
+        }).await?;
+        if let Ok(mut logs) = self.console_logs.lock() {
+            logs.clear();
+        }
+        if let Ok(mut events) = self.network_events.lock() {
+            events.clear();
+        }
+        Ok(())
+    }
+
+    async fn quit(&self) -> CeriumResult<()> {
+        let tabs = self.browser.get_tabs().lock()
+            .map(|tabs| tabs.clone())
+            .unwrap_or_default();
+        blocking(move || {
+            for tab in tabs {
+                tab.close(true)?;
+            }
+            Ok::<(), anyhow::Error>(())
+        }).await
+    }
+}
+
+/// ChromeElement - DOM node of the tab behind the Element trait,
+/// node is resolved again for every call since the chrome element borrows the tab
+pub struct ChromeElement {
+    tab: Arc<Tab>,
+    node_id: NodeId,
+}
+
+impl ChromeElement {
+    async fn call<T, F>(&self, call: F) -> CeriumResult<T>
+        where
+            T: Send + 'static,
+            F: FnOnce(&ChromeNode) -> anyhow::Result<T> + Send + 'static,
+    {
+        let tab = self.tab.clone();
+        let node_id = self.node_id;
+        blocking(move || call(&ChromeNode::new(&tab, node_id)?)).await
+    }
+}
+
+#[async_trait]
+impl Element for ChromeElement {
+    async fn click(&self) -> CeriumResult<()> {
+        self.call(|node| node.click().map(|_| ())).await
+    }
+
+    async fn send_keys(&self, text: &str) -> CeriumResult<()> {
+        let text = text.to_string();
+        self.call(move |node| node.type_into(&text).map(|_| ())).await
+    }
+
+    async fn clear(&self) -> CeriumResult<()> {
+        self.call(|node| {
+            node.call_js_fn("function() { this.value = ''; }", vec![], false).map(|_| ())
+        }).await
+    }
+
+    async fn text(&self) -> CeriumResult<String> {
+        self.call(|node| node.get_inner_text()).await
+    }
+
+    async fn inner_html(&self) -> CeriumResult<String> {
+        self.call(|node| {
+            let result = node.call_js_fn("function() { return this.innerHTML; }", vec![], false)?;
+            Ok(result.value.and_then(|value| value.as_str().map(str::to_string)).unwrap_or_default())
+        }).await
+    }
+
+    async fn attribute(&self, name: &str) -> CeriumResult<Option<String>> {
+        let name = name.to_string();
+        self.call(move |node| node.get_attribute_value(&name)).await
+    }
+}
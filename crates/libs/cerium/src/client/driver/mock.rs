@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::client::driver::{Driver, Element, Locator};
+use crate::error::{CeriumError, CeriumResult};
+
+/// Interaction - call made on the mock driver, recorded in the order it was made
+#[derive(Debug, Clone, PartialEq)]
+pub enum Interaction {
+    Open(String),
+    CreateWindow(String),
+    Find(Locator),
+    Click(Locator),
+    SendKeys(Locator, String),
+    Clear(Locator),
+    Screenshot,
+    Reset,
+    Quit,
+}
+
+/// MockElement - scripted element of the mock page
+#[derive(Debug, Clone, Default)]
+pub struct MockElement {
+    text: String,
+    html: Option<String>,
+    value: String,
+    attributes: HashMap<String, String>,
+    navigate_to: Option<String>,
+}
+
+impl MockElement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    /// html - inner html of the element, the text is used when not set
+    pub fn html(mut self, html: &str) -> Self {
+        self.html = Some(html.to_string());
+        self
+    }
+
+    pub fn attribute(mut self, name: &str, value: &str) -> Self {
+        self.attributes.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// navigates_to - clicking the element will open the url
+    pub fn navigates_to(mut self, url: &str) -> Self {
+        self.navigate_to = Some(url.to_string());
+        self
+    }
+}
+
+/// MockPage - scripted page of the mock driver, elements are matched
+/// by the exact locator (no css or xpath evaluation)
+#[derive(Debug, Clone, Default)]
+pub struct MockPage {
+    title: String,
+    elements: Vec<(Locator, MockElement)>,
+}
+
+impl MockPage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    pub fn element(mut self, locator: Locator, element: MockElement) -> Self {
+        self.elements.push((locator, element));
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    pages: HashMap<String, MockPage>,
+    url: String,
+    screenshot: Vec<u8>,
+    interactions: Vec<Interaction>,
+    failures: Vec<Interaction>,
+    delays: Vec<(Interaction, Duration)>,
+}
+
+impl MockState {
+    fn element(&mut self, locator: &Locator, index: usize) -> CeriumResult<&mut MockElement> {
+        self.pages
+            .get_mut(&self.url)
+            .and_then(|page| page.elements.iter_mut().filter(|(l, _)| l == locator).nth(index))
+            .map(|(_, element)| element)
+            .ok_or_else(|| CeriumError::DriverError(format!("no such element: {:?}", locator)))
+    }
+}
+
+/// MockDriver - in-process driver with the scripted pages to test the engine
+/// without a browser, every call is recorded and failures or delays can be injected
+/// for the call
+#[derive(Debug, Clone, Default)]
+pub struct MockDriver(Arc<Mutex<MockState>>);
+
+impl MockDriver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.0.lock().expect("mock driver lock poisoned")
+    }
+
+    /// page - script the page that will be served for the url
+    pub fn page(self, url: &str, page: MockPage) -> Self {
+        self.state().pages.insert(url.to_string(), page);
+        self
+    }
+
+    /// screenshot - png content returned for every screenshot, empty by default
+    pub fn screenshot(self, content: Vec<u8>) -> Self {
+        self.state().screenshot = content;
+        self
+    }
+
+    /// fail_on - the interaction will fail with the driver error
+    pub fn fail_on(self, interaction: Interaction) -> Self {
+        self.state().failures.push(interaction);
+        self
+    }
+
+    /// delay_on - the interaction will wait for the duration before it completes
+    pub fn delay_on(self, interaction: Interaction, duration: Duration) -> Self {
+        self.state().delays.push((interaction, duration));
+        self
+    }
+
+    /// interactions - all the calls made on the driver so far
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state().interactions.clone()
+    }
+
+    /// value - value typed into the element of the current page
+    pub fn value(&self, locator: &Locator) -> Option<String> {
+        self.state().element(locator, 0).ok().map(|element| element.value.clone())
+    }
+
+    /// record - record the interaction and apply the injected delay and failure
+    async fn record(&self, interaction: Interaction) -> CeriumResult<()> {
+        let (delay, failed) = {
+            let mut state = self.state();
+            state.interactions.push(interaction.clone());
+            let delay = state.delays.iter()
+                .find(|(i, _)| *i == interaction)
+                .map(|(_, duration)| *duration);
+            (delay, state.failures.contains(&interaction))
+        };
+        if let Some(duration) = delay {
+            tokio::time::sleep(duration).await;
+        }
+        if failed {
+            return Err(CeriumError::DriverError(format!("injected failure: {:?}", interaction)));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Driver for MockDriver {
+    async fn session_id(&self) -> CeriumResult<String> {
+        Ok("mock-session".to_string())
+    }
+
+    async fn open(&self, url: &str) -> CeriumResult<()> {
+        self.record(Interaction::Open(url.to_string())).await?;
+        self.state().url = url.to_string();
+        Ok(())
+    }
+
+    async fn current_url(&self) -> CeriumResult<String> {
+        Ok(self.state().url.clone())
+    }
+
+    async fn title(&self) -> CeriumResult<String> {
+        let state = self.state();
+        Ok(state.pages.get(&state.url).map(|page| page.title.clone()).unwrap_or_default())
+    }
+
+    async fn create_window(&self, name: &str) -> CeriumResult<()> {
+        self.record(Interaction::CreateWindow(name.to_string())).await
+    }
+
+    async fn find(&self, locator: &Locator) -> CeriumResult<Box<dyn Element>> {
+        self.record(Interaction::Find(locator.clone())).await?;
+        self.state().element(locator, 0)?;
+        Ok(Box::new(MockDriverElement { driver: self.clone(), locator: locator.clone(), index: 0 }))
+    }
+
+    async fn find_all(&self, locator: &Locator) -> CeriumResult<Vec<Box<dyn Element>>> {
+        self.record(Interaction::Find(locator.clone())).await?;
+        let state = self.state();
+        let count = state.pages.get(&state.url)
+            .map(|page| page.elements.iter().filter(|(l, _)| l == locator).count())
+            .unwrap_or_default();
+        Ok((0..count)
+            .map(|index| {
+                Box::new(MockDriverElement { driver: self.clone(), locator: locator.clone(), index })
+                    as Box<dyn Element>
+            })
+            .collect())
+    }
+
+    async fn take_screenshot(&self) -> CeriumResult<Vec<u8>> {
+        self.record(Interaction::Screenshot).await?;
+        Ok(self.state().screenshot.clone())
+    }
+
+    async fn reset(&self) -> CeriumResult<()> {
+        self.record(Interaction::Reset).await?;
+        let mut state = self.state();
+        for page in state.pages.values_mut() {
+            for (_, element) in page.elements.iter_mut() {
+                element.value.clear();
+            }
+        }
+        state.url = "about:blank".to_string();
+        Ok(())
+    }
+
+    async fn quit(&self) -> CeriumResult<()> {
+        self.record(Interaction::Quit).await
+    }
+}
+
+/// MockDriverElement - element of the mock page behind the Element trait
+pub struct MockDriverElement {
+    driver: MockDriver,
+    locator: Locator,
+    index: usize,
+}
+
+#[async_trait]
+impl Element for MockDriverElement {
+    async fn click(&self) -> CeriumResult<()> {
+        self.driver.record(Interaction::Click(self.locator.clone())).await?;
+        let mut state = self.driver.state();
+        if let Some(url) = state.element(&self.locator, self.index)?.navigate_to.clone() {
+            state.url = url;
+        }
+        Ok(())
+    }
+
+    async fn send_keys(&self, text: &str) -> CeriumResult<()> {
+        self.driver.record(Interaction::SendKeys(self.locator.clone(), text.to_string())).await?;
+        self.driver.state().element(&self.locator, self.index)?.value.push_str(text);
+        Ok(())
+    }
+
+    async fn clear(&self) -> CeriumResult<()> {
+        self.driver.record(Interaction::Clear(self.locator.clone())).await?;
+        self.driver.state().element(&self.locator, self.index)?.value.clear();
+        Ok(())
+    }
+
+    async fn text(&self) -> CeriumResult<String> {
+        Ok(self.driver.state().element(&self.locator, self.index)?.text.clone())
+    }
+
+    async fn inner_html(&self) -> CeriumResult<String> {
+        let mut state = self.driver.state();
+        let element = state.element(&self.locator, self.index)?;
+        Ok(element.html.clone().unwrap_or_else(|| element.text.clone()))
+    }
+
+    async fn attribute(&self, name: &str) -> CeriumResult<Option<String>> {
+        Ok(self.driver.state().element(&self.locator, self.index)?.attributes.get(name).cloned())
+    }
+}
@@ -1 +1,143 @@
+use async_trait::async_trait;
+
+use crate::error::CeriumResult;
+
+pub mod service;
 pub mod web;
+#[cfg(feature = "chrome")]
+pub mod chrome;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod pool;
+
+/// SHADOW_SEPARATOR - separates the css selectors of the shadow locator
+pub const SHADOW_SEPARATOR: &str = ">>>";
+
+/// Locator - how the element will be looked up in the page
+#[derive(Debug, Clone, PartialEq)]
+pub enum Locator {
+    Css(String),
+    Id(String),
+    XPath(String),
+    Name(String),
+    LinkText(String),
+    PartialLinkText(String),
+    ClassName(String),
+    Tag(String),
+    /// element with the exact visible text
+    Text(String),
+    /// css selectors separated by `>>>`, every selector after the first one is looked up
+    /// in the shadow root of the element matched by the previous one
+    Shadow(String),
+}
+
+impl Locator {
+    /// css - css selector of the locator, for the backends without the native strategy
+    pub fn css(&self) -> Option<String> {
+        match self {
+            Locator::Css(value) | Locator::Tag(value) => Some(value.clone()),
+            Locator::Id(value) => Some(format!("[id={}]", css_string(value))),
+            Locator::Name(value) => Some(format!("[name={}]", css_string(value))),
+            Locator::ClassName(value) => Some(format!("[class~={}]", css_string(value))),
+            Locator::Shadow(_) => Some(self.shadow_target()?.to_string()),
+            _ => None,
+        }
+    }
+
+    /// xpath - xpath of the locator that can not be expressed in css
+    pub fn xpath(&self) -> Option<String> {
+        match self {
+            Locator::XPath(value) => Some(value.clone()),
+            Locator::LinkText(value) => Some(format!("//a[normalize-space(.)={}]", xpath_literal(value))),
+            Locator::PartialLinkText(value) => {
+                Some(format!("//a[contains(normalize-space(.), {})]", xpath_literal(value)))
+            }
+            Locator::Text(value) => {
+                Some(format!("//*[text()[normalize-space(.)={}]]", xpath_literal(value)))
+            }
+            _ => None,
+        }
+    }
+
+    /// shadow_hosts - selectors of the shadow hosts to walk through before the target,
+    /// none when the locator does not cross a shadow root
+    pub fn shadow_hosts(&self) -> Option<Vec<String>> {
+        let Locator::Shadow(path) = self else { return None };
+        let mut selectors: Vec<String> = path.split(SHADOW_SEPARATOR).map(|s| s.trim().to_string()).collect();
+        selectors.pop();
+        (!selectors.is_empty()).then_some(selectors)
+    }
+
+    /// shadow_target - selector of the element inside the innermost shadow root
+    pub fn shadow_target(&self) -> Option<&str> {
+        let Locator::Shadow(path) = self else { return None };
+        path.rsplit(SHADOW_SEPARATOR).next().map(str::trim)
+    }
+}
+
+/// css_string - quoted css string for the attribute selector
+fn css_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// xpath_literal - xpath string literal, xpath has no escape so the quote not used by the
+/// value wraps it and the value with both quotes is split with concat
+pub fn xpath_literal(value: &str) -> String {
+    if !value.contains('\'') {
+        format!("'{value}'")
+    } else if !value.contains('"') {
+        format!("\"{value}\"")
+    } else {
+        let parts: Vec<String> = value.split('\'').map(|part| format!("'{part}'")).collect();
+        format!("concat({})", parts.join(", \"'\", "))
+    }
+}
+
+/// Element - element found in the page by the driver
+#[async_trait]
+pub trait Element: Send + Sync {
+    async fn click(&self) -> CeriumResult<()>;
+
+    async fn send_keys(&self, text: &str) -> CeriumResult<()>;
+
+    async fn clear(&self) -> CeriumResult<()>;
+
+    async fn text(&self) -> CeriumResult<String>;
+
+    async fn inner_html(&self) -> CeriumResult<String>;
+
+    async fn attribute(&self, name: &str) -> CeriumResult<Option<String>>;
+}
+
+/// Driver - browser session the engine will drive the test case with,
+/// every browser backend has to implement this
+#[async_trait]
+pub trait Driver: Send + Sync {
+    /// session_id - unique id of the browser session
+    async fn session_id(&self) -> CeriumResult<String>;
+
+    /// open - navigate to the url
+    async fn open(&self, url: &str) -> CeriumResult<()>;
+
+    async fn current_url(&self) -> CeriumResult<String>;
+
+    async fn title(&self) -> CeriumResult<String>;
+
+    async fn create_window(&self, name: &str) -> CeriumResult<()>;
+
+    /// find - find the first element matching the locator
+    async fn find(&self, locator: &Locator) -> CeriumResult<Box<dyn Element>>;
+
+    /// find_all - find all the elements matching the locator
+    async fn find_all(&self, locator: &Locator) -> CeriumResult<Vec<Box<dyn Element>>>;
+
+    /// take_screenshot - will take screenshot and send png back to the requester
+    async fn take_screenshot(&self) -> CeriumResult<Vec<u8>>;
+
+    /// reset - clear the cookies and storage and navigate to about:blank,
+    /// so the session can be reused by the next execution
+    async fn reset(&self) -> CeriumResult<()>;
+
+    /// quit - close the browser session
+    async fn quit(&self) -> CeriumResult<()>;
+}
@@ -0,0 +1,187 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::{debug, info, warn};
+
+use crate::client::driver::Driver;
+use crate::env::Environment;
+use crate::error::{CeriumError, CeriumResult};
+
+type Factory = Arc<
+    dyn Fn() -> Pin<Box<dyn Future<Output = CeriumResult<Arc<dyn Driver>>> + Send>> + Send + Sync,
+>;
+
+/// PoolConfig - limits of the session pool
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    /// max number of the browser sessions leased or idle at the same time
+    pub size: usize,
+    /// idle session older than this will be closed instead of reused
+    pub idle_timeout: Duration,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            size: 4,
+            idle_timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+impl PoolConfig {
+    /// from_env - pool config from the environment, default for the missing values
+    pub fn from_env(env: &Environment) -> Self {
+        let default = Self::default();
+        Self {
+            size: env.session_pool_size.unwrap_or(default.size),
+            idle_timeout: env.session_idle_timeout
+                .map(Duration::from_secs)
+                .unwrap_or(default.idle_timeout),
+        }
+    }
+}
+
+struct IdleSession {
+    driver: Arc<dyn Driver>,
+    since: Instant,
+}
+
+struct PoolInner {
+    config: PoolConfig,
+    factory: Factory,
+    idle: Mutex<Vec<IdleSession>>,
+    permits: Arc<Semaphore>,
+}
+
+/// SessionPool - browser sessions reused across the executions, a session is reset
+/// (cookies, storage and about:blank) before it goes back to the pool
+#[derive(Clone)]
+pub struct SessionPool(Arc<PoolInner>);
+
+impl SessionPool {
+    /// new - create the pool with the factory to open a new browser session
+    pub fn new<F, Fut>(config: PoolConfig, factory: F) -> Self
+        where
+            F: Fn() -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = CeriumResult<Arc<dyn Driver>>> + Send + 'static,
+    {
+        let permits = Arc::new(Semaphore::new(config.size.max(1)));
+        Self(Arc::new(PoolInner {
+            config,
+            factory: Arc::new(move || Box::pin(factory())),
+            idle: Mutex::new(Vec::new()),
+            permits,
+        }))
+    }
+
+    pub fn config(&self) -> &PoolConfig {
+        &self.0.config
+    }
+
+    /// idle_count - number of the sessions waiting to be reused
+    pub fn idle_count(&self) -> usize {
+        self.0.idle.lock().unwrap().len()
+    }
+
+    /// lease - get a healthy idle session or open a new one,
+    /// waits when all the sessions of the pool are leased
+    pub async fn lease(&self) -> CeriumResult<Lease> {
+        let permit = self.0.permits.clone().acquire_owned().await
+            .map_err(|err| CeriumError::DriverError(err.to_string()))?;
+        self.evict_idle().await;
+        loop {
+            let session = self.0.idle.lock().unwrap().pop();
+            let Some(session) = session else { break };
+            if session.driver.current_url().await.is_ok() {
+                debug!("Reusing the idle browser session");
+                return Ok(Lease::new(self.clone(), session.driver, permit));
+            }
+            warn!("Dropping the unhealthy browser session");
+            let _ = session.driver.quit().await;
+        }
+        info!("Opening a new browser session for the pool");
+        let driver = (self.0.factory)().await?;
+        Ok(Lease::new(self.clone(), driver, permit))
+    }
+
+    /// evict_idle - close the sessions that were idle longer than the idle timeout
+    pub async fn evict_idle(&self) {
+        let expired: Vec<IdleSession> = {
+            let mut idle = self.0.idle.lock().unwrap();
+            let (expired, alive) = idle.drain(..)
+                .partition(|s| s.since.elapsed() >= self.0.config.idle_timeout);
+            *idle = alive;
+            expired
+        };
+        for session in expired {
+            debug!("Closing the expired browser session");
+            let _ = session.driver.quit().await;
+        }
+    }
+
+    /// close - close all the idle sessions, leased sessions are closed when released
+    pub async fn close(&self) {
+        let idle: Vec<IdleSession> = self.0.idle.lock().unwrap().drain(..).collect();
+        for session in idle {
+            let _ = session.driver.quit().await;
+        }
+    }
+
+    fn give_back(&self, driver: Arc<dyn Driver>) {
+        self.0.idle.lock().unwrap().push(IdleSession { driver, since: Instant::now() });
+    }
+}
+
+/// Lease - browser session borrowed from the pool, has to be released to be reused,
+/// the session is closed if the lease is dropped without release
+pub struct Lease {
+    pool: SessionPool,
+    driver: Option<Arc<dyn Driver>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl Lease {
+    fn new(pool: SessionPool, driver: Arc<dyn Driver>, permit: OwnedSemaphorePermit) -> Self {
+        Self { pool, driver: Some(driver), _permit: permit }
+    }
+
+    pub fn driver(&self) -> Arc<dyn Driver> {
+        self.driver.clone().expect("lease already released")
+    }
+
+    /// release - reset the session and give it back to the pool,
+    /// the session is closed if the reset fails
+    pub async fn release(mut self) {
+        let Some(driver) = self.driver.take() else { return };
+        match driver.reset().await {
+            Ok(_) => self.pool.give_back(driver),
+            Err(err) => {
+                warn!("Unable to reset the browser session, closing it - {:?}", err);
+                let _ = driver.quit().await;
+            }
+        }
+    }
+
+    /// discard - close the session instead of giving it back to the pool
+    pub async fn discard(mut self) -> CeriumResult<()> {
+        match self.driver.take() {
+            Some(driver) => driver.quit().await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        let Some(driver) = self.driver.take() else { return };
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                let _ = driver.quit().await;
+            });
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::process::{Child, Command};
+use tracing::{debug, info};
+
+use crate::error::{CeriumError, CeriumResult};
+
+/// DriverKind - local webdriver binary that can be launched instead of the selenium hub
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DriverKind {
+    Gecko,
+    Chrome,
+    Edge,
+}
+
+impl DriverKind {
+    /// for_browser - driver binary for the browser name, firefox is the default
+    pub fn for_browser(browser: &str) -> Self {
+        match browser.to_lowercase().as_str() {
+            "chrome" => DriverKind::Chrome,
+            "edge" => DriverKind::Edge,
+            _ => DriverKind::Gecko,
+        }
+    }
+
+    pub fn browser(&self) -> &'static str {
+        match self {
+            DriverKind::Gecko => "firefox",
+            DriverKind::Chrome => "chrome",
+            DriverKind::Edge => "edge",
+        }
+    }
+
+    /// binary - name of the driver binary in the PATH
+    pub fn binary(&self) -> &'static str {
+        match self {
+            DriverKind::Gecko => "geckodriver",
+            DriverKind::Chrome => "chromedriver",
+            DriverKind::Edge => "msedgedriver",
+        }
+    }
+
+    /// env - environment variable to point to the binary outside of the PATH
+    pub fn env(&self) -> &'static str {
+        match self {
+            DriverKind::Gecko => "GECKODRIVER_PATH",
+            DriverKind::Chrome => "CHROMEDRIVER_PATH",
+            DriverKind::Edge => "MSEDGEDRIVER_PATH",
+        }
+    }
+
+    fn port_arg(&self, port: u16) -> Vec<String> {
+        match self {
+            DriverKind::Gecko => vec!["--port".to_string(), port.to_string()],
+            DriverKind::Chrome | DriverKind::Edge => vec![format!("--port={port}")],
+        }
+    }
+}
+
+/// find_binary - find the driver binary from the env variable or the PATH
+pub fn find_binary(kind: DriverKind) -> CeriumResult<PathBuf> {
+    if let Ok(path) = std::env::var(kind.env()) {
+        let path = PathBuf::from(path);
+        if path.is_file() {
+            return Ok(path);
+        }
+        return Err(CeriumError::DriverError(format!(
+            "{} points to {:?} which is not a file", kind.env(), path
+        )));
+    }
+    let name = format!("{}{}", kind.binary(), std::env::consts::EXE_SUFFIX);
+    std::env::var_os("PATH")
+        .and_then(|paths| {
+            std::env::split_paths(&paths)
+                .map(|dir| dir.join(&name))
+                .find(|path| path.is_file())
+        })
+        .ok_or_else(|| CeriumError::DriverError(format!(
+            "{} not found in the PATH, install it or set {}", kind.binary(), kind.env()
+        )))
+}
+
+/// free_port - ask the os for a port that is free right now
+fn free_port() -> CeriumResult<u16> {
+    Ok(TcpListener::bind(("127.0.0.1", 0))?.local_addr()?.port())
+}
+
+/// DriverService - local geckodriver / chromedriver / msedgedriver process on a free port,
+/// the process is killed when the service is stopped or dropped
+pub struct DriverService {
+    kind: DriverKind,
+    port: u16,
+    child: Child,
+}
+
+impl DriverService {
+    /// start - launch the driver binary and wait till it is ready to create the session
+    pub async fn start(kind: DriverKind) -> CeriumResult<Self> {
+        let binary = find_binary(kind)?;
+        let port = free_port()?;
+        info!("Starting {:?} on port {port}", binary);
+        let child = Command::new(&binary)
+            .args(kind.port_arg(port))
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()?;
+        let mut service = Self { kind, port, child };
+        service.wait_ready(Duration::from_secs(10)).await?;
+        Ok(service)
+    }
+
+    pub fn kind(&self) -> DriverKind {
+        self.kind
+    }
+
+    /// url - webdriver endpoint to create the session on
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}", self.port)
+    }
+
+    /// is_ready - health check against the webdriver status endpoint
+    pub async fn is_ready(&self) -> bool {
+        let status = match reqwest::get(format!("{}/status", self.url())).await {
+            Ok(response) => response.json::<serde_json::Value>().await,
+            Err(_) => return false,
+        };
+        status
+            .ok()
+            .and_then(|status| status.get("value")?.get("ready")?.as_bool())
+            .unwrap_or_default()
+    }
+
+    async fn wait_ready(&mut self, timeout: Duration) -> CeriumResult<()> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        while tokio::time::Instant::now() < deadline {
+            if let Some(status) = self.child.try_wait()? {
+                return Err(CeriumError::DriverError(format!(
+                    "{} exited before it was ready - {status}", self.kind.binary()
+                )));
+            }
+            if self.is_ready().await {
+                debug!("{} is ready on port {}", self.kind.binary(), self.port);
+                return Ok(());
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        Err(CeriumError::DriverError(format!(
+            "{} was not ready after {}s", self.kind.binary(), timeout.as_secs()
+        )))
+    }
+
+    /// stop - kill the driver process
+    pub async fn stop(mut self) -> CeriumResult<()> {
+        info!("Stopping {} on port {}", self.kind.binary(), self.port);
+        self.child.kill().await?;
+        Ok(())
+    }
+}
@@ -1,7 +1,14 @@
-use thirtyfour::{CapabilitiesHelper, WebDriver as TFWebDriver};
+use async_trait::async_trait;
+use thirtyfour::{By, Capabilities, CapabilitiesHelper, DesiredCapabilities, WebDriver as TFWebDriver, WebElement};
 
-use crate::error::CeriumResult;
-use thirtyfour::{By, DesiredCapabilities, WebElement};
+use crate::client::driver::{Driver, Element, Locator};
+use crate::client::driver::service::DriverService;
+use crate::error::{CeriumError, CeriumResult};
+
+/// CLEAR_STORAGE - clear the web storage of the current page, pages without the storage
+/// access (e.g. about:blank) will throw so it is ignored
+pub(crate) const CLEAR_STORAGE: &str =
+    "try { window.localStorage.clear(); window.sessionStorage.clear(); } catch (e) {}";
 
 #[derive(Clone)]
 pub struct WebDriver {
@@ -30,40 +37,172 @@ impl WebDriver {
         let helper = WebDriver { driver };
         Ok(helper)
     }
-    
-    pub async fn session_id(&self) -> CeriumResult<String> {
-        Ok(self.driver.session_id().await?.clone().to_string())
-    }
 
     pub async fn default() -> CeriumResult<Self> {
-        let mut caps = DesiredCapabilities::firefox();
-        caps.add("se:recordVideo", true)?;
+        Self::with_browser("firefox").await
+    }
+
+    /// with_browser - will create new session for the browser name (firefox, chrome, edge)
+    /// on the selenium hub, unknown browser will fall back to firefox
+    pub async fn with_browser(browser: &str) -> CeriumResult<Self> {
+        let caps = Self::capabilities(browser, true)?;
         let driver = TFWebDriver::new("http://localhost:4444/wd/hub/session", caps).await?;
         Self::new(driver)
     }
 
-    pub async fn open(&self, url: &str) -> CeriumResult<()> {
+    /// local - will create new session on the local geckodriver / chromedriver / msedgedriver process
+    pub async fn local(service: &DriverService) -> CeriumResult<Self> {
+        let caps = Self::capabilities(service.kind().browser(), false)?;
+        let driver = TFWebDriver::new(service.url().as_str(), caps).await?;
+        Self::new(driver)
+    }
+
+    /// capabilities - browser capabilities, video recording is only supported by the selenium hub
+    fn capabilities(browser: &str, record_video: bool) -> CeriumResult<Capabilities> {
+        let caps: Capabilities = match browser.to_lowercase().as_str() {
+            "chrome" => {
+                let mut caps = DesiredCapabilities::chrome();
+                if record_video {
+                    caps.add("se:recordVideo", true)?;
+                }
+                caps.into()
+            }
+            "edge" => {
+                let mut caps = DesiredCapabilities::edge();
+                if record_video {
+                    caps.add("se:recordVideo", true)?;
+                }
+                caps.into()
+            }
+            _ => {
+                let mut caps = DesiredCapabilities::firefox();
+                if record_video {
+                    caps.add("se:recordVideo", true)?;
+                }
+                caps.into()
+            }
+        };
+        Ok(caps)
+    }
+
+    /// shadow_root - walk the shadow hosts of the locator, the target is looked up
+    /// in the returned shadow root
+    async fn shadow_root(&self, hosts: &[String]) -> CeriumResult<WebElement> {
+        let mut root: Option<WebElement> = None;
+        for host in hosts {
+            let element = match &root {
+                Some(root) => root.find(By::Css(host.as_str())).await?,
+                None => self.driver.find(By::Css(host.as_str())).await?,
+            };
+            root = Some(element.get_shadow_root().await?);
+        }
+        root.ok_or_else(|| CeriumError::DriverError("shadow locator without the host".to_string()))
+    }
+}
+
+// the shadow locator maps to the target selector, the hosts are walked by `shadow_root`
+impl From<&Locator> for By {
+    fn from(locator: &Locator) -> Self {
+        match locator {
+            Locator::Css(value) => By::Css(value.as_str()),
+            Locator::Id(value) => By::Id(value.as_str()),
+            Locator::XPath(value) => By::XPath(value.as_str()),
+            Locator::Name(value) => By::Name(value.as_str()),
+            Locator::LinkText(value) => By::LinkText(value.as_str()),
+            Locator::PartialLinkText(value) => By::PartialLinkText(value.as_str()),
+            Locator::ClassName(value) => By::ClassName(value.as_str()),
+            Locator::Tag(value) => By::Tag(value.as_str()),
+            Locator::Text(_) => By::XPath(locator.xpath().unwrap_or_default().as_str()),
+            Locator::Shadow(_) => By::Css(locator.shadow_target().unwrap_or_default()),
+        }
+    }
+}
+
+#[async_trait]
+impl Driver for WebDriver {
+    async fn session_id(&self) -> CeriumResult<String> {
+        Ok(self.driver.session_id().await?.to_string())
+    }
+
+    async fn open(&self, url: &str) -> CeriumResult<()> {
         Ok(self.driver.goto(url).await?)
     }
 
-    pub async fn quit(self) -> CeriumResult<()> {
-        Ok(self.driver.quit().await?)
+    async fn current_url(&self) -> CeriumResult<String> {
+        Ok(self.driver.current_url().await?.to_string())
     }
 
+    async fn title(&self) -> CeriumResult<String> {
+        Ok(self.driver.title().await?)
+    }
 
-    pub async fn create_window(&self, name: &str) -> CeriumResult<()> {
+    async fn create_window(&self, name: &str) -> CeriumResult<()> {
         let win_handler = self.driver.new_window().await?;
         self.driver.switch_to_window(win_handler).await?;
         self.driver.set_window_name(name).await?;
         Ok(())
     }
 
-    pub async fn find(&self, by: impl Into<By>) -> CeriumResult<WebElement> {
-        Ok(self.driver.find(by).await?)
+    async fn find(&self, locator: &Locator) -> CeriumResult<Box<dyn Element>> {
+        let element = match locator.shadow_hosts() {
+            Some(hosts) => self.shadow_root(&hosts).await?.find(By::from(locator)).await?,
+            None => self.driver.find(By::from(locator)).await?,
+        };
+        Ok(Box::new(WebDriverElement(element)))
+    }
+
+    async fn find_all(&self, locator: &Locator) -> CeriumResult<Vec<Box<dyn Element>>> {
+        let elements = match locator.shadow_hosts() {
+            Some(hosts) => self.shadow_root(&hosts).await?.find_all(By::from(locator)).await?,
+            None => self.driver.find_all(By::from(locator)).await?,
+        };
+        Ok(elements
+            .into_iter()
+            .map(|element| Box::new(WebDriverElement(element)) as Box<dyn Element>)
+            .collect())
     }
 
-    /// take_screenshot - will take screenshot and send png back to the requester
-    pub async fn take_screenshot(&self) -> CeriumResult<Vec<u8>> {
+    async fn take_screenshot(&self) -> CeriumResult<Vec<u8>> {
         Ok(self.driver.screenshot_as_png().await?)
     }
+
+    async fn reset(&self) -> CeriumResult<()> {
+        self.driver.delete_all_cookies().await?;
+        self.driver.execute(CLEAR_STORAGE, Vec::new()).await?;
+        Ok(self.driver.goto("about:blank").await?)
+    }
+
+    async fn quit(&self) -> CeriumResult<()> {
+        Ok(self.driver.clone().quit().await?)
+    }
+}
+
+/// WebDriverElement - thirtyfour element behind the Element trait
+pub struct WebDriverElement(WebElement);
+
+#[async_trait]
+impl Element for WebDriverElement {
+    async fn click(&self) -> CeriumResult<()> {
+        Ok(self.0.click().await?)
+    }
+
+    async fn send_keys(&self, text: &str) -> CeriumResult<()> {
+        Ok(self.0.send_keys(text).await?)
+    }
+
+    async fn clear(&self) -> CeriumResult<()> {
+        Ok(self.0.clear().await?)
+    }
+
+    async fn text(&self) -> CeriumResult<String> {
+        Ok(self.0.text().await?)
+    }
+
+    async fn inner_html(&self) -> CeriumResult<String> {
+        Ok(self.0.inner_html().await?)
+    }
+
+    async fn attribute(&self, name: &str) -> CeriumResult<Option<String>> {
+        Ok(self.0.attr(name).await?)
+    }
 }
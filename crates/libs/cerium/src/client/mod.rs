@@ -26,6 +26,19 @@ impl Client {
             storage_cli,
         }
     }
+
+    /// with_db - create the client on the existing database connection
+    /// (e.g. sqlite in memory for the tests)
+    pub async fn with_db(environment: Option<Environment>, db: DatabaseConnection) -> Self {
+        let _env = environment.unwrap_or(Environment::default());
+        let storage_cli = Self::storage_client(&_env).await;
+        Client {
+            db,
+            env: _env,
+            storage_cli,
+        }
+    }
+
     pub async fn env(&self) -> &Environment {
         &self.env
     }
@@ -11,7 +11,10 @@ pub struct Environment {
     pub storage_access_key: String,
     pub storage_access_secret: String,
     pub storage_base_url: String,
-    pub cors_allowed_origin: Vec<HeaderValue>
+    pub cors_allowed_origin: Vec<HeaderValue>,
+    pub session_pool_size: Option<usize>,
+    /// idle timeout of the pooled browser session in seconds
+    pub session_idle_timeout: Option<u64>,
 }
 
 impl Environment {
@@ -30,6 +33,8 @@ impl Environment {
             storage_access_key: env::var("STORAGE_ACCESS_KEY").unwrap_or("".to_string()),
             storage_access_secret: env::var("STORAGE_ACCESS_SECRET").unwrap_or("".to_string()),
             storage_base_url: env::var("STORAGE_BASE_URL").unwrap_or("".to_string()),
+            session_pool_size: env::var("SESSION_POOL_SIZE").ok().and_then(|v| v.parse().ok()),
+            session_idle_timeout: env::var("SESSION_IDLE_TIMEOUT").ok().and_then(|v| v.parse().ok()),
         }
     }
 }
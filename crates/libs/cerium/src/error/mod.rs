@@ -28,6 +28,10 @@ pub enum CeriumError {
     CredentialsError(#[from] CredentialsError),
     #[error("CredentialsError error: {0}")]
     S3Error(#[from] S3Error),
+    #[error("Driver error: {0}")]
+    DriverError(String),
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
 
 
 }
@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use cerium::client::driver::{Locator, xpath_literal};
+
+    #[test]
+    fn css_for_the_attribute_locators() {
+        assert_eq!(Locator::Id("login".to_string()).css(), Some("[id=\"login\"]".to_string()));
+        assert_eq!(Locator::Name("q".to_string()).css(), Some("[name=\"q\"]".to_string()));
+        assert_eq!(Locator::ClassName("btn".to_string()).css(), Some("[class~=\"btn\"]".to_string()));
+        assert_eq!(Locator::Text("Sign in".to_string()).css(), None);
+    }
+
+    #[test]
+    fn xpath_for_the_text_locators() {
+        assert_eq!(Locator::LinkText("Home".to_string()).xpath(),
+                   Some("//a[normalize-space(.)='Home']".to_string()));
+        assert_eq!(Locator::Text("Sign in".to_string()).xpath(),
+                   Some("//*[text()[normalize-space(.)='Sign in']]".to_string()));
+        assert_eq!(Locator::Css("a".to_string()).xpath(), None);
+    }
+
+    #[test]
+    fn xpath_literal_quotes() {
+        assert_eq!(xpath_literal("it's"), "\"it's\"");
+        assert_eq!(xpath_literal("say \"it's\""), "concat('say \"it', \"'\", 's\"')");
+    }
+
+    #[test]
+    fn shadow_path_is_split_into_hosts_and_target() {
+        let locator = Locator::Shadow("app-shell >>> login-form >>> #email".to_string());
+        assert_eq!(locator.shadow_hosts(), Some(vec!["app-shell".to_string(), "login-form".to_string()]));
+        assert_eq!(locator.shadow_target(), Some("#email"));
+        assert_eq!(locator.css(), Some("#email".to_string()));
+        assert_eq!(Locator::Shadow("#email".to_string()).shadow_hosts(), None);
+    }
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use cerium::client::driver::service::{DriverKind, find_binary};
+
+    #[test]
+    fn browser_selects_the_driver() {
+        assert_eq!(DriverKind::for_browser("chrome"), DriverKind::Chrome);
+        assert_eq!(DriverKind::for_browser("Edge"), DriverKind::Edge);
+        assert_eq!(DriverKind::for_browser("firefox"), DriverKind::Gecko);
+        assert_eq!(DriverKind::for_browser("safari"), DriverKind::Gecko);
+        assert_eq!(DriverKind::for_browser(""), DriverKind::Gecko);
+    }
+
+    #[test]
+    fn driver_has_its_own_binary() {
+        for (kind, browser, binary) in [
+            (DriverKind::Gecko, "firefox", "geckodriver"),
+            (DriverKind::Chrome, "chrome", "chromedriver"),
+            (DriverKind::Edge, "edge", "msedgedriver"),
+        ] {
+            assert_eq!(kind.browser(), browser);
+            assert_eq!(kind.binary(), binary);
+            assert_eq!(DriverKind::for_browser(kind.browser()), kind);
+        }
+    }
+
+    #[test]
+    fn binary_path_from_the_env_has_to_exist() {
+        std::env::set_var(DriverKind::Edge.env(), "/orca/missing/msedgedriver");
+        let err = find_binary(DriverKind::Edge).expect_err("missing binary");
+        std::env::remove_var(DriverKind::Edge.env());
+        assert!(err.to_string().contains("MSEDGEDRIVER_PATH"));
+    }
+}
@@ -41,6 +41,9 @@ pub struct Parameter {
     pub required: bool,
     pub explode: bool,
     pub schema: Schema,
+    /// example value of the parameter, declared or generated from the schema
+    #[serde(default)]
+    pub example: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -1 +1,6 @@
-mod component;
\ No newline at end of file
+pub mod assertion;
+pub mod channel;
+pub mod component;
+pub mod response;
+pub mod spec;
+pub mod step;
@@ -1,5 +1,8 @@
 pub mod datatable;
 pub mod field;
 pub mod history;
+pub mod mock;
 pub mod profile;
+pub mod schedule;
+pub mod sql;
 pub mod ui;
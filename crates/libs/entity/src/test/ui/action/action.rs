@@ -29,6 +29,18 @@ pub enum ActionKind {
     VerifyAttribute,
 }
 
+impl ActionKind {
+    /// need_target - action will act on the element in the page
+    pub fn need_target(&self) -> bool {
+        !matches!(self, ActionKind::Open)
+    }
+
+    /// need_data - action will need the data value to act with
+    pub fn need_data(&self) -> bool {
+        !matches!(self, ActionKind::Click | ActionKind::DoubleClick)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize, Serialize)]
 #[sea_orm(table_name = "action")]
 pub struct Model {
@@ -42,6 +54,10 @@ pub struct Model {
     pub data_value: Option<String>,
     pub target_kind: Option<ActionTargetKind>,
     pub target_value: Option<String>,
+    /// element of the object repository, takes the place of the inline target
+    pub element_id: Option<Uuid>,
+    /// timeout for the action in milliseconds
+    pub timeout: Option<i32>,
 
     pub action_group_id: Uuid,
 
@@ -63,6 +79,13 @@ pub enum Relation {
         to = "super::group::Column::Id"
     )]
     ActionGroup,
+    #[sea_orm(
+        belongs_to = "crate::test::ui::elements::Entity",
+        from = "Column::ElementId",
+        to = "crate::test::ui::elements::Column::Id",
+        on_delete = "SetNull"
+    )]
+    Element,
 }
 
 // // `Related` trait has to be implemented by hand
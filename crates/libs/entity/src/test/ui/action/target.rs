@@ -17,6 +17,22 @@ pub enum ActionTargetKind {
     Id,
     #[sea_orm(string_value = "Xpath")]
     Xpath,
+    #[sea_orm(string_value = "Name")]
+    Name,
+    #[sea_orm(string_value = "LinkText")]
+    LinkText,
+    #[sea_orm(string_value = "PartialLinkText")]
+    PartialLinkText,
+    #[sea_orm(string_value = "ClassName")]
+    ClassName,
+    #[sea_orm(string_value = "Tag")]
+    Tag,
+    /// element with the exact visible text
+    #[sea_orm(string_value = "Text")]
+    Text,
+    /// css selectors separated by `>>>` piercing the shadow roots
+    #[sea_orm(string_value = "Shadow")]
+    Shadow,
 }
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize, Serialize)]
@@ -46,6 +46,15 @@ pub enum BlockType {
     Parallel,
     #[sea_orm(string_value = "Block")]
     Block,
+    /// reference to the API step of [`crate::api::step`]
+    #[sea_orm(string_value = "ApiStep")]
+    ApiStep,
+    /// reference to the WebSocket or Server-Sent Events step of [`crate::api::channel`]
+    #[sea_orm(string_value = "ChannelStep")]
+    ChannelStep,
+    /// reference to the SQL step of [`crate::test::sql::step`]
+    #[sea_orm(string_value = "SqlStep")]
+    SqlStep,
 }
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize, Serialize)]
@@ -62,6 +71,8 @@ pub struct Model {
     pub type_field: BlockType,
     pub reference: Option<Uuid>,
     pub parent_id: Option<Uuid>,
+    /// timeout for the block in milliseconds
+    pub timeout: Option<i32>,
 
     #[serde(skip_deserializing)]
     pub case_id: Uuid,
@@ -24,13 +24,18 @@ pub enum ElementCreateType {
 pub struct Model {
     #[serde(skip_deserializing)]
     #[sea_orm(primary_key)]
-    pub id: i64,
+    pub id: Uuid,
     pub name: String,
+    pub description: Option<String>,
     pub locator_value: String,
     pub element_type: Option<String>,
     pub created_type: ElementCreateType,
     pub locator_type: ActionTargetKind,
-    pub screen_id: i64,
+    /// fallback locator that matched when the primary did not, proposed as the new primary
+    #[serde(skip_deserializing)]
+    pub proposed_locator_id: Option<Uuid>,
+    #[serde(skip_deserializing)]
+    pub screen_id: Uuid,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -41,6 +46,8 @@ pub enum Relation {
         to = "super::screen::Column::Id"
     )]
     Screen,
+    #[sea_orm(has_many = "super::locator::Entity")]
+    Locator,
 }
 
 // `Related` trait has to be implemented by hand
@@ -50,4 +57,10 @@ impl Related<super::screen::Entity> for Entity {
     }
 }
 
+impl Related<super::locator::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Locator.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
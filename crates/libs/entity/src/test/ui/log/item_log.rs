@@ -20,6 +20,9 @@ pub enum ItemLogStatus {
     #[sea_orm(string_value = "RUN")]
     #[serde(rename = "Running")]
     Running,
+    #[sea_orm(string_value = "TOUT")]
+    #[serde(rename = "TimedOut")]
+    TimedOut,
 }
 
 #[derive(Debug, Clone, PartialEq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
@@ -37,6 +40,18 @@ pub enum ItemLogType {
     #[sea_orm(string_value = "TC")]
     #[serde(rename = "TestCase")]
     TestCase,
+    #[sea_orm(string_value = "API")]
+    #[serde(rename = "ApiStep")]
+    ApiStep,
+    #[sea_orm(string_value = "CH")]
+    #[serde(rename = "ChannelStep")]
+    ChannelStep,
+    #[sea_orm(string_value = "SQL")]
+    #[serde(rename = "SqlStep")]
+    SqlStep,
+    #[sea_orm(string_value = "LOC")]
+    #[serde(rename = "Locator")]
+    Locator,
 }
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize, Serialize)]
@@ -3,6 +3,7 @@ pub use request::Model as ExecutionRequest;
 pub mod action;
 pub mod case;
 pub mod elements;
+pub mod locator;
 pub mod screen;
 pub mod suit;
 pub mod object_repository;
@@ -54,6 +54,12 @@ pub enum ExecutionStatus {
     #[sea_orm(string_value = "Failed")]
     #[serde(rename = "Failed")]
     Failed,
+    #[sea_orm(string_value = "Cancelled")]
+    #[serde(rename = "Cancelled")]
+    Cancelled,
+    #[sea_orm(string_value = "TimedOut")]
+    #[serde(rename = "TimedOut")]
+    TimedOut,
 }
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Deserialize, Serialize)]
@@ -70,6 +76,10 @@ pub struct Model {
     pub kind: ExecutionKind,
     pub status: ExecutionStatus,
     pub args: Option<serde_json::Value>,
+    /// timeout for the whole execution in milliseconds
+    pub timeout: Option<i32>,
+    /// report of the dry run execution
+    pub report: Option<serde_json::Value>,
 
     pub log_id: i32,
     pub created_at: DateTimeWithTimeZone,
@@ -96,6 +106,8 @@ pub fn new(
         kind: Set(kind),
         status: Set(status),
         args: NotSet,
+        timeout: NotSet,
+        report: NotSet,
         log_id: Set(log_id),
         created_at: Set(chrono::Utc::now().into()),
         created_by: Set("System".to_string()),
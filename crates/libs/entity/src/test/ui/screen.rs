@@ -9,10 +9,35 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[serde(skip_deserializing)]
     #[sea_orm(primary_key)]
-    pub id: i64,
+    pub id: Uuid,
     pub name: String,
+    pub description: Option<String>,
+    #[serde(skip_deserializing)]
+    pub app_id: Uuid,
 }
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    #[sea_orm(has_many = "super::elements::Entity")]
+    Element,
+    #[sea_orm(
+        belongs_to = "crate::app::app::Entity",
+        from = "Column::AppId",
+        to = "crate::app::app::Column::Id"
+    )]
+    App,
+}
+
+impl Related<super::elements::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Element.def()
+    }
+}
+
+impl Related<crate::app::app::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::App.def()
+    }
+}
 
 impl ActiveModelBehavior for ActiveModel {}
@@ -4,6 +4,18 @@ mod migration001;
 mod migration002;
 mod migration003;
 mod migration004;
+mod migration005;
+mod migration006;
+mod migration007;
+mod migration008;
+mod migration009;
+mod migration010;
+mod migration011;
+mod migration012;
+mod migration013;
+mod migration014;
+mod migration015;
+mod migration016;
 
 pub struct Migrator;
 
@@ -15,6 +27,18 @@ impl MigratorTrait for Migrator {
             Box::new(migration002::Migration),
             Box::new(migration003::Migration),
             Box::new(migration004::Migration),
+            Box::new(migration005::Migration),
+            Box::new(migration006::Migration),
+            Box::new(migration007::Migration),
+            Box::new(migration008::Migration),
+            Box::new(migration009::Migration),
+            Box::new(migration010::Migration),
+            Box::new(migration011::Migration),
+            Box::new(migration012::Migration),
+            Box::new(migration013::Migration),
+            Box::new(migration014::Migration),
+            Box::new(migration015::Migration),
+            Box::new(migration016::Migration),
         ]
     }
 }
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+use entity::app::app;
+use entity::test::schedule;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //******************  Schedule  ******************
+        manager
+            .create_table(
+                Table::create()
+                    .table(schedule::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(schedule::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(schedule::Column::Name).string().not_null())
+                    .col(ColumnDef::new(schedule::Column::Description).string())
+                    .col(ColumnDef::new(schedule::Column::Cron).string().not_null())
+                    .col(
+                        ColumnDef::new(schedule::Column::Timezone)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(schedule::Column::RefId).uuid().not_null())
+                    .col(ColumnDef::new(schedule::Column::RefType).string().not_null())
+                    .col(ColumnDef::new(schedule::Column::ProfileId).uuid())
+                    .col(ColumnDef::new(schedule::Column::Browser).json())
+                    .col(
+                        ColumnDef::new(schedule::Column::Enabled)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(schedule::Column::LastRunAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(schedule::Column::NextRunAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(schedule::Column::AppId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(schedule::Entity, schedule::Column::AppId)
+                            .to(app::Entity, app::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(schedule::Entity).to_owned())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+use entity::prelude::case_block;
+use entity::test::schedule;
+use entity::test::ui::action::action;
+use entity::test::ui::request;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 6 will add the timeout (in milliseconds) for the execution, case block, action
+/// and schedule
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(action::Entity)
+                    .add_column(ColumnDef::new(action::Column::Timeout).integer())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(case_block::Entity)
+                    .add_column(ColumnDef::new(case_block::Column::Timeout).integer())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(request::Entity)
+                    .add_column(ColumnDef::new(request::Column::Timeout).integer())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(schedule::Entity)
+                    .add_column(ColumnDef::new(schedule::Column::Timeout).integer())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(schedule::Entity)
+                    .drop_column(schedule::Column::Timeout)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(request::Entity)
+                    .drop_column(request::Column::Timeout)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(case_block::Entity)
+                    .drop_column(case_block::Column::Timeout)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(action::Entity)
+                    .drop_column(action::Column::Timeout)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use sea_orm_migration::prelude::*;
+
+use entity::test::ui::request;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 7 will add the dry run report for the execution request
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(request::Entity)
+                    .add_column(ColumnDef::new(request::Column::Report).json())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(request::Entity)
+                    .drop_column(request::Column::Report)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use sea_orm_migration::prelude::*;
+
+use entity::app::app;
+use entity::test::ui::action::action;
+use entity::test::ui::{elements, screen};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// FK_ACTION_ELEMENT - foreign key of the action to the element of the object repository
+const FK_ACTION_ELEMENT: &str = "fk_action_element_id";
+
+/// Migration 8 will create the object repository (screens and elements) and let the
+/// action point to the element, the action keeps its inline target once the element is deleted
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //******************  Object Repository  ******************
+        manager
+            .create_table(
+                Table::create()
+                    .table(screen::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(screen::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(screen::Column::Name).string().not_null())
+                    .col(ColumnDef::new(screen::Column::Description).string())
+                    .col(ColumnDef::new(screen::Column::AppId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(screen::Entity, screen::Column::AppId)
+                            .to(app::Entity, app::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(elements::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(elements::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(elements::Column::Name).string().not_null())
+                    .col(ColumnDef::new(elements::Column::Description).string())
+                    .col(ColumnDef::new(elements::Column::LocatorValue).string().not_null())
+                    .col(ColumnDef::new(elements::Column::ElementType).string())
+                    .col(ColumnDef::new(elements::Column::CreatedType).string().not_null())
+                    .col(ColumnDef::new(elements::Column::LocatorType).string().not_null())
+                    .col(ColumnDef::new(elements::Column::ScreenId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(elements::Entity, elements::Column::ScreenId)
+                            .to(screen::Entity, screen::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(action::Entity)
+                    .add_column(ColumnDef::new(action::Column::ElementId).uuid())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_ACTION_ELEMENT)
+                    .from(action::Entity, action::Column::ElementId)
+                    .to(elements::Entity, elements::Column::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_ACTION_ELEMENT)
+                    .table(action::Entity)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(action::Entity)
+                    .drop_column(action::Column::ElementId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(elements::Entity).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(screen::Entity).to_owned())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+use entity::test::ui::{elements, locator};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 9 will create the fallback locators of the object repository element
+/// and the proposed locator column of the element
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(locator::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(locator::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(locator::Column::Priority).integer().not_null())
+                    .col(ColumnDef::new(locator::Column::LocatorType).string().not_null())
+                    .col(ColumnDef::new(locator::Column::LocatorValue).string().not_null())
+                    .col(ColumnDef::new(locator::Column::ElementId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(locator::Entity, locator::Column::ElementId)
+                            .to(elements::Entity, elements::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(elements::Entity)
+                    .add_column(ColumnDef::new(elements::Column::ProposedLocatorId).uuid())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(elements::Entity)
+                    .drop_column(elements::Column::ProposedLocatorId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(locator::Entity).to_owned())
+            .await?;
+        Ok(())
+    }
+}
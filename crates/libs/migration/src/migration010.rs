@@ -0,0 +1,90 @@
+use sea_orm_migration::prelude::*;
+
+use entity::api::{response, step};
+use entity::app::app;
+use entity::test::ui::log::item_log;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 10 will create the API steps and the responses logged while executing them
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //******************  API Automation  ******************
+        manager
+            .create_table(
+                Table::create()
+                    .table(step::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(step::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(step::Column::Name).string().not_null())
+                    .col(ColumnDef::new(step::Column::Description).string())
+                    .col(ColumnDef::new(step::Column::Method).string().not_null())
+                    .col(ColumnDef::new(step::Column::Url).string().not_null())
+                    .col(ColumnDef::new(step::Column::Headers).json())
+                    .col(ColumnDef::new(step::Column::Query).json())
+                    .col(ColumnDef::new(step::Column::Body).text())
+                    .col(ColumnDef::new(step::Column::Auth).json())
+                    .col(ColumnDef::new(step::Column::ExpectedStatus).integer())
+                    .col(ColumnDef::new(step::Column::Timeout).integer())
+                    .col(ColumnDef::new(step::Column::AppId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(step::Entity, step::Column::AppId)
+                            .to(app::Entity, app::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(response::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(response::Column::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key().auto_increment(),
+                    )
+                    .col(ColumnDef::new(response::Column::ItemLogId).integer().not_null())
+                    .col(ColumnDef::new(response::Column::Method).string().not_null())
+                    .col(ColumnDef::new(response::Column::Url).string().not_null())
+                    .col(ColumnDef::new(response::Column::Status).integer())
+                    .col(ColumnDef::new(response::Column::Headers).json())
+                    .col(ColumnDef::new(response::Column::Body).text())
+                    .col(ColumnDef::new(response::Column::Latency).integer().not_null())
+                    .col(ColumnDef::new(response::Column::Error).string())
+                    .col(ColumnDef::new(response::Column::CreatedAt).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(response::Entity, response::Column::ItemLogId)
+                            .to(item_log::Entity, item_log::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(response::Entity).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(step::Entity).to_owned())
+            .await?;
+        Ok(())
+    }
+}
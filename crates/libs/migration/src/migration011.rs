@@ -0,0 +1,83 @@
+use sea_orm_migration::prelude::*;
+
+use entity::api::{spec, step};
+use entity::app::app;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 11 will create the imported OpenAPI documents and let the API step point to
+/// the operation it was generated from
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(spec::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(spec::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(spec::Column::Title).string().not_null())
+                    .col(ColumnDef::new(spec::Column::Version).string().not_null())
+                    .col(ColumnDef::new(spec::Column::Openapi).string().not_null())
+                    .col(ColumnDef::new(spec::Column::Document).json().not_null())
+                    .col(ColumnDef::new(spec::Column::AppId).uuid().not_null())
+                    .col(ColumnDef::new(spec::Column::CreatedAt).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(spec::Entity, spec::Column::AppId)
+                            .to(app::Entity, app::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(step::Entity)
+                    .add_column(ColumnDef::new(step::Column::SpecId).uuid())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(step::Entity)
+                    .add_column(ColumnDef::new(step::Column::OperationId).string())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(step::Entity)
+                    .drop_column(step::Column::OperationId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(step::Entity)
+                    .drop_column(step::Column::SpecId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(spec::Entity).to_owned())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+use entity::api::{assertion, step};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 12 will create the assertions of the API step
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(assertion::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(assertion::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(assertion::Column::ExecutionOrder).integer().not_null())
+                    .col(ColumnDef::new(assertion::Column::Kind).string().not_null())
+                    .col(ColumnDef::new(assertion::Column::Expression).string())
+                    .col(ColumnDef::new(assertion::Column::MatchMode).string())
+                    .col(ColumnDef::new(assertion::Column::Expected).text())
+                    .col(ColumnDef::new(assertion::Column::Variable).string())
+                    .col(ColumnDef::new(assertion::Column::StepId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(assertion::Entity, assertion::Column::StepId)
+                            .to(step::Entity, step::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(assertion::Entity).to_owned())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+use entity::app::app;
+use entity::common::attachment;
+use entity::test::mock::{endpoint, request as mock_request};
+use entity::test::ui::request;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 13 will create the mock endpoints and the requests received by the mock server
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //******************  Mock Server  ******************
+        manager
+            .create_table(
+                Table::create()
+                    .table(endpoint::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(endpoint::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(endpoint::Column::Name).string().not_null())
+                    .col(ColumnDef::new(endpoint::Column::Method).string())
+                    .col(ColumnDef::new(endpoint::Column::Path).string().not_null())
+                    .col(ColumnDef::new(endpoint::Column::Status).integer().not_null())
+                    .col(ColumnDef::new(endpoint::Column::Headers).json())
+                    .col(ColumnDef::new(endpoint::Column::AttachmentId).integer())
+                    .col(ColumnDef::new(endpoint::Column::Delay).integer())
+                    .col(ColumnDef::new(endpoint::Column::Fault).string())
+                    .col(ColumnDef::new(endpoint::Column::AppId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(endpoint::Entity, endpoint::Column::AttachmentId)
+                            .to(attachment::Entity, attachment::Column::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(endpoint::Entity, endpoint::Column::AppId)
+                            .to(app::Entity, app::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(mock_request::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(mock_request::Column::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key().auto_increment(),
+                    )
+                    .col(ColumnDef::new(mock_request::Column::ExecutionId).integer().not_null())
+                    .col(ColumnDef::new(mock_request::Column::EndpointId).uuid())
+                    .col(ColumnDef::new(mock_request::Column::Method).string().not_null())
+                    .col(ColumnDef::new(mock_request::Column::Path).string().not_null())
+                    .col(ColumnDef::new(mock_request::Column::Query).string())
+                    .col(ColumnDef::new(mock_request::Column::Headers).json().not_null())
+                    .col(ColumnDef::new(mock_request::Column::Body).text())
+                    .col(ColumnDef::new(mock_request::Column::Status).integer())
+                    .col(ColumnDef::new(mock_request::Column::CreatedAt).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(mock_request::Entity, mock_request::Column::ExecutionId)
+                            .to(request::Entity, request::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(mock_request::Entity, mock_request::Column::EndpointId)
+                            .to(endpoint::Entity, endpoint::Column::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(mock_request::Entity).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(endpoint::Entity).to_owned())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use sea_orm_migration::prelude::*;
+
+use entity::api::step;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 14 will let the API step carry the GraphQL document
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(step::Entity)
+                    .add_column(ColumnDef::new(step::Column::Graphql).json())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(step::Entity)
+                    .drop_column(step::Column::Graphql)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+use entity::api::channel;
+use entity::app::app;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 15 will create the WebSocket and Server-Sent Events steps
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //******************  Realtime Channels  ******************
+        manager
+            .create_table(
+                Table::create()
+                    .table(channel::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(channel::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(channel::Column::Name).string().not_null())
+                    .col(ColumnDef::new(channel::Column::Description).string())
+                    .col(ColumnDef::new(channel::Column::Action).string().not_null())
+                    .col(ColumnDef::new(channel::Column::Protocol).string().not_null())
+                    .col(ColumnDef::new(channel::Column::Connection).string().not_null())
+                    .col(ColumnDef::new(channel::Column::Url).string())
+                    .col(ColumnDef::new(channel::Column::Headers).json())
+                    .col(ColumnDef::new(channel::Column::Message).text())
+                    .col(ColumnDef::new(channel::Column::MatchKind).string())
+                    .col(ColumnDef::new(channel::Column::Expression).string())
+                    .col(ColumnDef::new(channel::Column::Expected).string())
+                    .col(ColumnDef::new(channel::Column::Variable).string())
+                    .col(ColumnDef::new(channel::Column::Timeout).integer())
+                    .col(ColumnDef::new(channel::Column::AppId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(channel::Entity, channel::Column::AppId)
+                            .to(app::Entity, app::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(channel::Entity).to_owned())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+use entity::app::app;
+use entity::test::profile::profile;
+use entity::test::sql::{connection, step};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Migration 16 will create the database connections and the SQL steps running on them
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        //******************  SQL Steps  ******************
+        manager
+            .create_table(
+                Table::create()
+                    .table(connection::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(connection::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(connection::Column::Name).string().not_null())
+                    .col(ColumnDef::new(connection::Column::Url).string().not_null())
+                    .col(ColumnDef::new(connection::Column::ProfileId).uuid())
+                    .col(ColumnDef::new(connection::Column::AppId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(connection::Entity, connection::Column::ProfileId)
+                            .to(profile::Entity, profile::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(connection::Entity, connection::Column::AppId)
+                            .to(app::Entity, app::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(step::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(step::Column::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(step::Column::Name).string().not_null())
+                    .col(ColumnDef::new(step::Column::Description).string())
+                    .col(ColumnDef::new(step::Column::Connection).string().not_null())
+                    .col(ColumnDef::new(step::Column::Query).text().not_null())
+                    .col(ColumnDef::new(step::Column::ExpectedRows).integer())
+                    .col(ColumnDef::new(step::Column::Checks).json())
+                    .col(ColumnDef::new(step::Column::Timeout).integer())
+                    .col(ColumnDef::new(step::Column::AppId).uuid().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(step::Entity, step::Column::AppId)
+                            .to(app::Entity, app::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(step::Entity).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(connection::Entity).to_owned())
+            .await?;
+        Ok(())
+    }
+}
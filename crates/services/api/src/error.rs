@@ -41,6 +41,9 @@ pub enum OrcaError {
 
     #[error("CeriumError error: {0}")]
     CeriumError(#[from] CeriumError),
+
+    #[error("Bad Request: {0}")]
+    BadRequest(String),
 }
 
 // /// This makes it possible to use `?` to automatically convert a `DbErr`
@@ -91,6 +94,7 @@ impl IntoResponse for OrcaError {
             OrcaError::CeriumError(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
             OrcaError::EngineError(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
             RepoError(err) => (StatusCode::NOT_FOUND, err.to_string()),
+            OrcaError::BadRequest(err) => (StatusCode::BAD_REQUEST, err),
             _ => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Internal Error Not Specify".to_string(),
@@ -5,7 +5,13 @@ use sea_orm::{DatabaseConnection, DbErr};
 use tracing::Level;
 
 use cerium::client::Client;
+use cerium::client::driver::Driver;
+use cerium::client::driver::pool::{PoolConfig, SessionPool};
+use cerium::client::driver::web::WebDriver;
 use cerium::server::App;
+use engine::server::recorder::RecordingRegistry;
+use engine::server::registry::ExecutionRegistry;
+use engine::server::scheduler::Scheduler;
 use migration::MigratorTrait;
 
 use crate::route::handle_router;
@@ -35,8 +41,18 @@ async fn main() {
     app.set_port(8080);
 
     run_migration(cli.db()).await.expect("TODO: panic message");
+    let registry = ExecutionRegistry::new();
+    let pool = SessionPool::new(PoolConfig::from_env(cli.env().await), || async {
+        Ok(Arc::new(WebDriver::default().await?) as Arc<dyn Driver>)
+    });
+    let mut scheduler = Scheduler::new(cli.clone(), registry.clone());
+    scheduler.set_pool(pool.clone());
+    scheduler.start();
     let routers = handle_router()
         .layer(Extension(cli.clone()))
+        .layer(Extension(registry))
+        .layer(Extension(pool))
+        .layer(Extension(RecordingRegistry::new()))
         .layer(OrcaLayer {
             db: Arc::new(cli.db.clone()),
         });
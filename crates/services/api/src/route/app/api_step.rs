@@ -0,0 +1,126 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{get, put};
+use axum::{Extension, Json, Router};
+use sea_orm::prelude::Uuid;
+
+use entity::api::assertion;
+use entity::api::step::Model;
+
+use crate::error::InternalResult;
+use crate::server::session::OrcaSession;
+use crate::service::app::api_step::ApiStepService;
+
+/// api_step_route - this will register all the endpoint of the API Step
+pub(crate) fn api_step_route() -> Router {
+    Router::new()
+        .route("/", get(list_steps).post(create_step))
+        .route(
+            "/:step_id",
+            get(get_step).put(update_step).delete(delete_step),
+        )
+        .route(
+            "/:step_id/assertion",
+            get(list_assertions).post(create_assertion),
+        )
+        .route(
+            "/:step_id/assertion/:assertion_id",
+            put(update_assertion).delete(delete_assertion),
+        )
+}
+
+/// list_steps - list all the API Step of the Application
+async fn list_steps(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ApiStepService::new(session, app_id).list_steps().await?;
+    Ok(Json(result))
+}
+
+/// create_step - this will create new API Step in the Application
+async fn create_step(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ApiStepService::new(session, app_id).create_step(body).await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// get_step - get the API Step of the Application
+async fn get_step(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ApiStepService::new(session, app_id).get_step(step_id).await?;
+    Ok(Json(result))
+}
+
+/// update_step - this will update the request of the API Step
+async fn update_step(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ApiStepService::new(session, app_id)
+        .update_step(step_id, body)
+        .await?;
+    Ok(Json(result))
+}
+
+/// delete_step - this will delete the API Step that is not used by any test case
+async fn delete_step(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    ApiStepService::new(session, app_id).delete_step(step_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// list_assertions - list all the assertions of the API Step
+async fn list_assertions(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ApiStepService::new(session, app_id)
+        .list_assertions(step_id)
+        .await?;
+    Ok(Json(result))
+}
+
+/// create_assertion - this will add the assertion at the end of the API Step
+async fn create_assertion(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<assertion::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ApiStepService::new(session, app_id)
+        .create_assertion(step_id, body)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// update_assertion - this will update the assertion of the API Step
+async fn update_assertion(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id, assertion_id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(body): Json<assertion::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ApiStepService::new(session, app_id)
+        .update_assertion(step_id, assertion_id, body)
+        .await?;
+    Ok(Json(result))
+}
+
+/// delete_assertion - this will delete the assertion of the API Step
+async fn delete_assertion(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id, assertion_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    ApiStepService::new(session, app_id)
+        .delete_assertion(step_id, assertion_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,14 +1,17 @@
-use axum::extract::Path;
-use axum::http::StatusCode;
+use axum::extract::{Path, Query};
+use axum::http::{header, StatusCode};
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use cerium::client::Client;
+use cerium::client::driver::pool::SessionPool;
+use engine::server::registry::ExecutionRegistry;
 use entity::prelude::case::Model;
 use entity::prelude::case_block::Model as BlockModel;
 use uuid::Uuid;
 
 use crate::error::InternalResult;
+use crate::route::{ExportParams, LoadParams, RunParams, ValidateParams};
 use crate::server::session::OrcaSession;
 use crate::service::app::case::CaseService;
 
@@ -20,7 +23,10 @@ pub(crate) fn test_case_route() -> Router {
             "/:case_id",
             Router::new()
                 .route("/detail", get(get_case_info))
-                .route("/run", post(dry_run))
+                .route("/run", post(run_case))
+                .route("/load", post(load_test))
+                .route("/validate", get(validate_case))
+                .route("/export", get(export_case))
                 .nest(
                     "/block",
                     Router::new()
@@ -65,16 +71,65 @@ async fn get_case_info(
     Ok(Json(result))
 }
 
-/// dry_run - Dry run the Test case
-async fn dry_run(
+/// run_case - run the test case in the background, `dry_run=true` only validates it
+/// without the browser
+async fn run_case(
     Extension(session): Extension<OrcaSession>,
     Extension(cli): Extension<Client>,
+    Extension(registry): Extension<ExecutionRegistry>,
+    Extension(pool): Extension<SessionPool>,
     Path((app_id, case_id)): Path<(Uuid, Uuid)>,
+    params: Option<Query<RunParams>>,
 ) -> InternalResult<impl IntoResponse> {
-    let result = CaseService::new(session, cli, app_id).run(case_id).await?;
+    let params = params.unwrap_or_default().0;
+    let result = CaseService::new(session, cli, app_id)
+        .run(case_id, registry, pool, params)
+        .await?;
+    Ok((StatusCode::ACCEPTED, Json(result)))
+}
+
+/// load_test - run the API steps of the test case with the virtual users
+async fn load_test(
+    Extension(session): Extension<OrcaSession>,
+    Extension(cli): Extension<Client>,
+    Extension(registry): Extension<ExecutionRegistry>,
+    Path((app_id, case_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<LoadParams>,
+) -> InternalResult<impl IntoResponse> {
+    let result = CaseService::new(session, cli, app_id)
+        .load_test(case_id, registry, body)
+        .await?;
+    Ok((StatusCode::ACCEPTED, Json(result)))
+}
+
+/// validate_case - statically check the test case and give back the diagnostics
+async fn validate_case(
+    Extension(session): Extension<OrcaSession>,
+    Extension(cli): Extension<Client>,
+    Path((app_id, case_id)): Path<(Uuid, Uuid)>,
+    params: Option<Query<ValidateParams>>,
+) -> InternalResult<impl IntoResponse> {
+    let params = params.unwrap_or_default().0;
+    let result = CaseService::new(session, cli, app_id)
+        .validate(case_id, params.profile_id)
+        .await?;
     Ok(Json(result))
 }
 
+/// export_case - export the test case as the standalone test code
+async fn export_case(
+    Extension(session): Extension<OrcaSession>,
+    Extension(cli): Extension<Client>,
+    Path((app_id, case_id)): Path<(Uuid, Uuid)>,
+    params: Option<Query<ExportParams>>,
+) -> InternalResult<impl IntoResponse> {
+    let params = params.unwrap_or_default().0;
+    let result = CaseService::new(session, cli, app_id)
+        .export(case_id, params.format)
+        .await?;
+    Ok(([(header::CONTENT_TYPE, "text/plain; charset=utf-8")], result))
+}
+
 /// update_block - update test case Block
 async fn update_block(
     Extension(session): Extension<OrcaSession>,
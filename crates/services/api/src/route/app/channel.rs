@@ -0,0 +1,71 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use sea_orm::prelude::Uuid;
+
+use entity::api::channel::Model;
+
+use crate::error::InternalResult;
+use crate::server::session::OrcaSession;
+use crate::service::app::channel::ChannelStepService;
+
+/// channel_step_route - this will register all the endpoint of the WebSocket and SSE steps
+pub(crate) fn channel_step_route() -> Router {
+    Router::new()
+        .route("/", get(list_steps).post(create_step))
+        .route(
+            "/:step_id",
+            get(get_step).put(update_step).delete(delete_step),
+        )
+}
+
+/// list_steps - list all the Channel Step of the Application
+async fn list_steps(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ChannelStepService::new(session, app_id).list_steps().await?;
+    Ok(Json(result))
+}
+
+/// create_step - this will create new Channel Step in the Application
+async fn create_step(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ChannelStepService::new(session, app_id).create_step(body).await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// get_step - get the Channel Step of the Application
+async fn get_step(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ChannelStepService::new(session, app_id).get_step(step_id).await?;
+    Ok(Json(result))
+}
+
+/// update_step - this will update the Channel Step
+async fn update_step(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ChannelStepService::new(session, app_id)
+        .update_step(step_id, body)
+        .await?;
+    Ok(Json(result))
+}
+
+/// delete_step - this will delete the Channel Step that is not used by any test case
+async fn delete_step(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    ChannelStepService::new(session, app_id).delete_step(step_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
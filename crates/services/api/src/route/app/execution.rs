@@ -0,0 +1,57 @@
+use axum::{Extension, Json, Router};
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use uuid::Uuid;
+
+use engine::server::registry::ExecutionRegistry;
+
+use crate::error::InternalResult;
+use crate::server::session::OrcaSession;
+use crate::service::app::execution::ExecutionService;
+
+/// execution_route - this will register all the endpoint in Execution route
+pub(crate) fn execution_route() -> Router {
+    Router::new().nest(
+        "/:execution_id",
+        Router::new()
+            .route("/", get(get_execution))
+            .route("/cancel", post(cancel_execution))
+            .route("/mock", get(list_mock_requests)),
+    )
+}
+
+/// get_execution - get the Execution Request with the current status
+async fn get_execution(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, execution_id)): Path<(Uuid, i32)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ExecutionService::new(session)
+        .get_execution(execution_id)
+        .await?;
+    Ok(Json(result))
+}
+
+/// list_mock_requests - list the requests received by the mock server of the execution
+async fn list_mock_requests(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, execution_id)): Path<(Uuid, i32)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ExecutionService::new(session)
+        .list_mock_requests(execution_id)
+        .await?;
+    Ok(Json(result))
+}
+
+/// cancel_execution - cancel the running Execution Request
+async fn cancel_execution(
+    Extension(session): Extension<OrcaSession>,
+    Extension(registry): Extension<ExecutionRegistry>,
+    Path((_app_id, execution_id)): Path<(Uuid, i32)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ExecutionService::new(session)
+        .cancel_execution(execution_id, &registry)
+        .await?;
+    Ok((StatusCode::ACCEPTED, Json(result)))
+}
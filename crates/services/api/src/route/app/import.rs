@@ -0,0 +1,73 @@
+use axum::{Extension, Json, Router};
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::post;
+use serde_json::Value;
+use uuid::Uuid;
+
+use engine::import::side::SideProject;
+
+use crate::error::InternalResult;
+use crate::route::{OpenApiParams, PostmanParams};
+use crate::server::session::OrcaSession;
+use crate::service::app::import::ImportService;
+
+/// import_route - this will register all the endpoint to import the test from other tools
+pub(crate) fn import_route() -> Router {
+    Router::new()
+        .route("/side", post(import_side))
+        .route("/openapi", post(import_openapi))
+        .route("/postman", post(import_postman))
+        .route("/postman/environment", post(import_postman_environment))
+}
+
+/// import_side - import the Selenium IDE project (`.side` file) into the Application
+async fn import_side(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<SideProject>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ImportService::new(session, app_id).import_side(body).await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// import_openapi - import the OpenAPI 3 document (json) into the Application as API steps
+async fn import_openapi(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    params: Option<Query<OpenApiParams>>,
+    Json(body): Json<Value>,
+) -> InternalResult<impl IntoResponse> {
+    let params = params.unwrap_or_default().0;
+    let result = ImportService::new(session, app_id)
+        .import_openapi(body, params.base_url)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// import_postman - import the Postman v2.1 collection (json) into the Application as API steps
+async fn import_postman(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    params: Option<Query<PostmanParams>>,
+    Json(body): Json<Value>,
+) -> InternalResult<impl IntoResponse> {
+    let params = params.unwrap_or_default().0;
+    let result = ImportService::new(session, app_id)
+        .import_postman(body, params.grouping)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// import_postman_environment - import the Postman environment (json) into the Application as a profile
+async fn import_postman_environment(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<Value>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ImportService::new(session, app_id)
+        .import_postman_environment(body)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
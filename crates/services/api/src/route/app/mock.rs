@@ -0,0 +1,71 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use sea_orm::prelude::Uuid;
+
+use entity::test::mock::endpoint::Model;
+
+use crate::error::InternalResult;
+use crate::server::session::OrcaSession;
+use crate::service::app::mock::MockService;
+
+/// mock_route - this will register all the endpoint of the Mock Endpoint
+pub(crate) fn mock_route() -> Router {
+    Router::new()
+        .route("/", get(list_endpoints).post(create_endpoint))
+        .route(
+            "/:endpoint_id",
+            get(get_endpoint).put(update_endpoint).delete(delete_endpoint),
+        )
+}
+
+/// list_endpoints - list all the Mock Endpoint of the Application
+async fn list_endpoints(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+) -> InternalResult<impl IntoResponse> {
+    let result = MockService::new(session, app_id).list_endpoints().await?;
+    Ok(Json(result))
+}
+
+/// create_endpoint - this will create new Mock Endpoint in the Application
+async fn create_endpoint(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = MockService::new(session, app_id).create_endpoint(body).await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// get_endpoint - get the Mock Endpoint of the Application
+async fn get_endpoint(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, endpoint_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = MockService::new(session, app_id).get_endpoint(endpoint_id).await?;
+    Ok(Json(result))
+}
+
+/// update_endpoint - this will update the Mock Endpoint of the Application
+async fn update_endpoint(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, endpoint_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = MockService::new(session, app_id)
+        .update_endpoint(endpoint_id, body)
+        .await?;
+    Ok(Json(result))
+}
+
+/// delete_endpoint - this will delete the Mock Endpoint of the Application
+async fn delete_endpoint(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, endpoint_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    MockService::new(session, app_id).delete_endpoint(endpoint_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
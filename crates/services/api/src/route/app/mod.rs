@@ -9,21 +9,39 @@ use entity::app::app::Model;
 
 use crate::error::InternalResult;
 use crate::route::app::action::action_route;
+use crate::route::app::api_step::api_step_route;
 use crate::route::app::case::test_case_route;
+use crate::route::app::channel::channel_step_route;
 use crate::route::app::datatable::datatable_route;
+use crate::route::app::execution::execution_route;
 use crate::route::app::group::group_route;
 use crate::route::app::history::history_route;
+use crate::route::app::import::import_route;
+use crate::route::app::mock::mock_route;
+use crate::route::app::object_repo::object_repo_route;
 use crate::route::app::profile::profile_route;
+use crate::route::app::recording::recording_route;
+use crate::route::app::schedule::schedule_route;
+use crate::route::app::sql::sql_route;
 use crate::route::app::suit::suite_route;
 use crate::server::session::OrcaSession;
 use crate::service::app::AppService;
 
 pub(crate) mod action;
+pub(crate) mod api_step;
 pub(crate) mod case;
+pub(crate) mod channel;
 pub(crate) mod datatable;
+pub(crate) mod execution;
 pub(crate) mod group;
 pub(crate) mod history;
+pub(crate) mod import;
+pub(crate) mod mock;
+pub(crate) mod object_repo;
 pub(crate) mod profile;
+pub(crate) mod recording;
+pub(crate) mod schedule;
+pub(crate) mod sql;
 pub(crate) mod suit;
 
 pub fn app_route() -> Router {
@@ -39,9 +57,18 @@ pub fn app_route() -> Router {
                 )
                 .nest("/profile", profile_route())
                 .nest("/datatable", datatable_route())
+                .nest("/screen", object_repo_route())
+                .nest("/recording", recording_route())
+                .nest("/import", import_route())
+                .nest("/api", api_step_route())
+                .nest("/channel", channel_step_route())
+                .nest("/sql", sql_route())
+                .nest("/mock", mock_route())
                 .nest("/case", test_case_route())
                 .nest("/suite", suite_route())
-                .nest("/history", history_route()),
+                .nest("/history", history_route())
+                .nest("/execution", execution_route())
+                .nest("/schedule", schedule_route()),
         )
 }
 
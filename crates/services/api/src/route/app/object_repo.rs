@@ -0,0 +1,178 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{delete, get, post, put};
+use axum::{Extension, Json, Router};
+use sea_orm::prelude::Uuid;
+
+use entity::test::ui::{elements, locator, screen};
+
+use crate::error::InternalResult;
+use crate::server::session::OrcaSession;
+use crate::service::app::object_repo::ObjectRepoService;
+
+/// object_repo_route - this will register all the endpoint of the screens and elements
+/// in the Application object repository
+pub(crate) fn object_repo_route() -> Router {
+    Router::new()
+        .route("/", get(get_screens).post(create_screen))
+        .route(
+            "/:screen_id",
+            get(get_screen).put(update_screen).delete(delete_screen),
+        )
+        .route("/:screen_id/element", get(get_elements).post(create_element))
+        .route(
+            "/:screen_id/element/:element_id",
+            put(update_element).delete(delete_element),
+        )
+        .route(
+            "/:screen_id/element/:element_id/locator",
+            get(get_locators).post(create_locator),
+        )
+        .route(
+            "/:screen_id/element/:element_id/locator/:locator_id",
+            delete(delete_locator),
+        )
+        .route(
+            "/:screen_id/element/:element_id/promote",
+            post(promote_locator),
+        )
+}
+
+/// get_screens - list all the Screen in the Application object repository
+async fn get_screens(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session).get_screens(app_id).await?;
+    Ok(Json(result))
+}
+
+/// create_screen - this will create new Screen in the Application object repository
+async fn create_screen(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<screen::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session)
+        .create_screen(app_id, body)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// get_screen - get the Screen in the Application object repository
+async fn get_screen(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, screen_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session).get_screen(screen_id).await?;
+    Ok(Json(result))
+}
+
+/// update_screen - this will update the Screen in the Application object repository
+async fn update_screen(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, screen_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<screen::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session)
+        .update_screen(screen_id, body)
+        .await?;
+    Ok(Json(result))
+}
+
+/// delete_screen - this will delete the Screen and its elements
+async fn delete_screen(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, screen_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    ObjectRepoService::new(session).delete_screen(screen_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// get_elements - list all the Element in the Screen
+async fn get_elements(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, screen_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session).get_elements(screen_id).await?;
+    Ok(Json(result))
+}
+
+/// create_element - this will create new Element in the Screen
+async fn create_element(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, screen_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<elements::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session)
+        .create_element(screen_id, body)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// update_element - this will update the Element in the Screen
+async fn update_element(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, screen_id, element_id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(body): Json<elements::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session)
+        .update_element(screen_id, element_id, body)
+        .await?;
+    Ok(Json(result))
+}
+
+/// delete_element - this will delete the Element, rejected while actions still use it
+async fn delete_element(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, screen_id, element_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    ObjectRepoService::new(session)
+        .delete_element(screen_id, element_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// get_locators - list the fallback locators of the Element in the priority order
+async fn get_locators(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, _screen_id, element_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session).get_locators(element_id).await?;
+    Ok(Json(result))
+}
+
+/// create_locator - this will add the fallback locator to the Element
+async fn create_locator(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, screen_id, element_id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(body): Json<locator::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session)
+        .create_locator(screen_id, element_id, body)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// delete_locator - this will delete the fallback locator of the Element
+async fn delete_locator(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, _screen_id, element_id, locator_id)): Path<(Uuid, Uuid, Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    ObjectRepoService::new(session)
+        .delete_locator(element_id, locator_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// promote_locator - accept the locator proposed by the self healing as the new primary
+async fn promote_locator(
+    Extension(session): Extension<OrcaSession>,
+    Path((_app_id, screen_id, element_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ObjectRepoService::new(session)
+        .promote_locator(screen_id, element_id)
+        .await?;
+    Ok(Json(result))
+}
@@ -0,0 +1,81 @@
+use axum::{Extension, Json, Router};
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{delete, post};
+use serde::Deserialize;
+use serde_json::json;
+use uuid::Uuid;
+
+use engine::server::recorder::{RecordedEvent, RecordingRegistry};
+
+use crate::error::InternalResult;
+use crate::server::session::OrcaSession;
+use crate::service::app::recording::RecordingService;
+
+/// StartRecording - body to open the recording session
+#[derive(Deserialize)]
+pub struct StartRecording {
+    /// name of the Action Group the recording will be saved as
+    pub name: String,
+}
+
+/// recording_route - this will register all the endpoint of the recorder, the recorder
+/// script or extension streams the captured events to the session
+pub(crate) fn recording_route() -> Router {
+    Router::new()
+        .route("/", post(start_recording))
+        .route("/:recording_id", delete(discard_recording))
+        .route("/:recording_id/event", post(record_events))
+        .route("/:recording_id/finish", post(finish_recording))
+}
+
+/// start_recording - open a new recording session for the Application
+async fn start_recording(
+    Extension(session): Extension<OrcaSession>,
+    Extension(registry): Extension<RecordingRegistry>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<StartRecording>,
+) -> InternalResult<impl IntoResponse> {
+    let result = RecordingService::new(session, registry, app_id)
+        .start_recording(body.name)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// record_events - append the captured browser events to the recording session
+async fn record_events(
+    Extension(session): Extension<OrcaSession>,
+    Extension(registry): Extension<RecordingRegistry>,
+    Path((app_id, recording_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<Vec<RecordedEvent>>,
+) -> InternalResult<impl IntoResponse> {
+    let count = RecordingService::new(session, registry, app_id)
+        .record_events(recording_id, body)
+        .await?;
+    Ok((StatusCode::ACCEPTED, Json(json!({"events": count}))))
+}
+
+/// finish_recording - save the recording session as a new Action Group
+async fn finish_recording(
+    Extension(session): Extension<OrcaSession>,
+    Extension(registry): Extension<RecordingRegistry>,
+    Path((app_id, recording_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = RecordingService::new(session, registry, app_id)
+        .finish_recording(recording_id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// discard_recording - close the recording session without saving it
+async fn discard_recording(
+    Extension(session): Extension<OrcaSession>,
+    Extension(registry): Extension<RecordingRegistry>,
+    Path((app_id, recording_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    RecordingService::new(session, registry, app_id)
+        .discard_recording(recording_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,82 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use serde_json::json;
+use uuid::Uuid;
+
+use entity::test::schedule::Model;
+
+use crate::error::InternalResult;
+use crate::server::session::OrcaSession;
+use crate::service::app::schedule::ScheduleService;
+
+/// schedule_route - this will register all the endpoint in Schedule route
+pub(crate) fn schedule_route() -> Router {
+    Router::new()
+        .route("/", get(list_schedules).post(create_schedule))
+        .route(
+            "/:schedule_id",
+            get(get_schedule)
+                .put(update_schedule)
+                .delete(delete_schedule),
+        )
+}
+
+/// list_schedules - list all the Schedules in the Application
+async fn list_schedules(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ScheduleService::new(session, app_id)
+        .list_schedules()
+        .await?;
+    Ok(Json(result))
+}
+
+/// create_schedule - this will create new Schedule in the Application
+async fn create_schedule(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ScheduleService::new(session, app_id)
+        .create_schedule(body)
+        .await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// get_schedule - get the Schedule with the last and next run
+async fn get_schedule(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, schedule_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ScheduleService::new(session, app_id)
+        .get_schedule(schedule_id)
+        .await?;
+    Ok(Json(result))
+}
+
+/// update_schedule - this will update the existing Schedule in the Application
+async fn update_schedule(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, schedule_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = ScheduleService::new(session, app_id)
+        .update_schedule(schedule_id, body)
+        .await?;
+    Ok(Json(result))
+}
+
+/// delete_schedule - this will delete the existing Schedule in the Application
+async fn delete_schedule(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, schedule_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    ScheduleService::new(session, app_id)
+        .delete_schedule(schedule_id)
+        .await?;
+    Ok(Json(json!({"status": "success"})))
+}
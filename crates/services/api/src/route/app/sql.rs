@@ -0,0 +1,128 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use sea_orm::prelude::Uuid;
+
+use entity::test::sql::{connection, step};
+
+use crate::error::InternalResult;
+use crate::server::session::OrcaSession;
+use crate::service::app::sql::SqlService;
+
+/// sql_route - this will register all the endpoint of the Database Connection and SQL Step
+pub(crate) fn sql_route() -> Router {
+    Router::new()
+        .route(
+            "/connection",
+            get(list_connections).post(create_connection),
+        )
+        .route(
+            "/connection/:connection_id",
+            get(get_connection).put(update_connection).delete(delete_connection),
+        )
+        .route("/step", get(list_steps).post(create_step))
+        .route(
+            "/step/:step_id",
+            get(get_step).put(update_step).delete(delete_step),
+        )
+}
+
+/// list_connections - list all the Database Connection of the Application
+async fn list_connections(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+) -> InternalResult<impl IntoResponse> {
+    let result = SqlService::new(session, app_id).list_connections().await?;
+    Ok(Json(result))
+}
+
+/// create_connection - this will create new Database Connection in the Application
+async fn create_connection(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<connection::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = SqlService::new(session, app_id).create_connection(body).await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// get_connection - get the Database Connection of the Application
+async fn get_connection(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, connection_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = SqlService::new(session, app_id).get_connection(connection_id).await?;
+    Ok(Json(result))
+}
+
+/// update_connection - this will update the Database Connection
+async fn update_connection(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, connection_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<connection::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = SqlService::new(session, app_id)
+        .update_connection(connection_id, body)
+        .await?;
+    Ok(Json(result))
+}
+
+/// delete_connection - this will delete the Database Connection
+async fn delete_connection(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, connection_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    SqlService::new(session, app_id).delete_connection(connection_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// list_steps - list all the SQL Step of the Application
+async fn list_steps(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+) -> InternalResult<impl IntoResponse> {
+    let result = SqlService::new(session, app_id).list_steps().await?;
+    Ok(Json(result))
+}
+
+/// create_step - this will create new SQL Step in the Application
+async fn create_step(
+    Extension(session): Extension<OrcaSession>,
+    Path(app_id): Path<Uuid>,
+    Json(body): Json<step::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = SqlService::new(session, app_id).create_step(body).await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// get_step - get the SQL Step of the Application
+async fn get_step(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    let result = SqlService::new(session, app_id).get_step(step_id).await?;
+    Ok(Json(result))
+}
+
+/// update_step - this will update the SQL Step
+async fn update_step(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<step::Model>,
+) -> InternalResult<impl IntoResponse> {
+    let result = SqlService::new(session, app_id)
+        .update_step(step_id, body)
+        .await?;
+    Ok(Json(result))
+}
+
+/// delete_step - this will delete the SQL Step that is not used by any test case
+async fn delete_step(
+    Extension(session): Extension<OrcaSession>,
+    Path((app_id, step_id)): Path<(Uuid, Uuid)>,
+) -> InternalResult<impl IntoResponse> {
+    SqlService::new(session, app_id).delete_step(step_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
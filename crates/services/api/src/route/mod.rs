@@ -1,6 +1,8 @@
 use crate::route::public::local_route;
 use axum::Router;
+use engine::controller::load::LoadConfig;
 use serde::Deserialize;
+use uuid::Uuid;
 use crate::route::auth::auth_route;
 
 pub(crate) mod admin;
@@ -38,6 +40,59 @@ impl Pagination {
     }
 }
 
+/// RunParams - optional parameters for triggering the execution
+#[derive(Deserialize, Default)]
+pub struct RunParams {
+    /// timeout for the whole execution in milliseconds
+    pub timeout: Option<i32>,
+    /// validate the execution without the browser
+    pub dry_run: Option<bool>,
+    pub profile_id: Option<Uuid>,
+    /// browser name (firefox, chrome, edge) for the webdriver backend
+    pub browser: Option<String>,
+    /// driver backend - webdriver on the selenium hub (default), local for the
+    /// geckodriver / chromedriver / msedgedriver process or cdp for the local chrome
+    pub backend: Option<String>,
+}
+
+/// LoadParams - load test of the API test case, the virtual users config comes along
+/// with the execution parameters
+#[derive(Deserialize)]
+pub struct LoadParams {
+    /// timeout for the whole load test in milliseconds
+    pub timeout: Option<i32>,
+    pub profile_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub config: LoadConfig,
+}
+
+/// ValidateParams - optional parameters for validating the test case
+#[derive(Deserialize, Default)]
+pub struct ValidateParams {
+    pub profile_id: Option<Uuid>,
+}
+
+/// ExportParams - optional parameters for exporting the test case
+#[derive(Deserialize, Default)]
+pub struct ExportParams {
+    /// code format of the export, rust by default
+    pub format: Option<String>,
+}
+
+/// OpenApiParams - optional parameters for importing the OpenAPI document
+#[derive(Deserialize, Default)]
+pub struct OpenApiParams {
+    /// base url of the API steps in place of the servers of the document
+    pub base_url: Option<String>,
+}
+
+/// PostmanParams - optional parameters for importing the Postman collection
+#[derive(Deserialize, Default)]
+pub struct PostmanParams {
+    /// `folder` for a test case per top level folder (default), `collection` for a single test case
+    pub grouping: Option<String>,
+}
+
 impl Default for Pagination {
     fn default() -> Self {
         Self {
@@ -0,0 +1,205 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, ModelTrait,
+    PaginatorTrait, QueryFilter, QueryOrder,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use entity::api::assertion;
+use entity::api::assertion::AssertionKind;
+use entity::api::step::{Column, Entity, Model};
+use entity::prelude::case_block;
+use entity::prelude::case_block::BlockType;
+
+use crate::error::{InternalResult, OrcaError, OrcaRepoError};
+use crate::server::session::OrcaSession;
+
+pub(crate) struct ApiStepService(OrcaSession, Uuid);
+
+impl ApiStepService {
+    pub fn new(session: OrcaSession, app_id: Uuid) -> Self {
+        Self(session, app_id)
+    }
+
+    pub fn trx(&self) -> &DatabaseTransaction {
+        self.0.trx()
+    }
+
+    /// list_steps - list all the API Step of the Application
+    pub async fn list_steps(&self) -> InternalResult<Vec<Model>> {
+        let steps = Entity::find()
+            .filter(Column::AppId.eq(self.1))
+            .order_by_asc(Column::Name)
+            .all(self.trx())
+            .await?;
+        Ok(steps)
+    }
+
+    /// get_step - get the API Step of the Application
+    pub async fn get_step(&self, step_id: Uuid) -> InternalResult<Model> {
+        let step = Entity::find_by_id(step_id)
+            .filter(Column::AppId.eq(self.1))
+            .one(self.trx())
+            .await?;
+        match step {
+            Some(step) => Ok(step),
+            None => Err(OrcaRepoError::ModelNotFound(
+                "API Step".to_string(),
+                step_id.to_string(),
+            ))?,
+        }
+    }
+
+    /// create_step - create new API Step in the Application
+    pub async fn create_step(&self, mut step: Model) -> InternalResult<Model> {
+        step.id = Uuid::new_v4();
+        step.app_id = self.1;
+        Self::check_step(&step)?;
+        let step = step.into_active_model();
+        let result = step.insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// update_step - update the request of the API Step
+    pub async fn update_step(&self, step_id: Uuid, mut step: Model) -> InternalResult<Model> {
+        self.get_step(step_id).await?;
+        step.id = step_id;
+        step.app_id = self.1;
+        Self::check_step(&step)?;
+        let step = step.into_active_model().reset_all();
+        let result = step.update(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// check_step - check the auth and the GraphQL document of the API Step
+    fn check_step(step: &Model) -> InternalResult<()> {
+        step.auth().map_err(|err| OrcaError::BadRequest(format!("Invalid auth - {err}")))?;
+        let graphql = step.graphql()
+            .map_err(|err| OrcaError::BadRequest(format!("Invalid GraphQL document - {err}")))?;
+        match graphql.as_ref().map(|graphql| graphql.operation()) {
+            Some(None) => Err(OrcaError::BadRequest("GraphQL document has no query or mutation".to_string())),
+            Some(Some("subscription")) => {
+                Err(OrcaError::BadRequest("GraphQL subscriptions are not supported".to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// delete_step - delete the API Step that is not used in any test case
+    pub async fn delete_step(&self, step_id: Uuid) -> InternalResult<()> {
+        self.get_step(step_id).await?;
+        let blocks = case_block::Entity::find()
+            .filter(case_block::Column::TypeField.eq(BlockType::ApiStep))
+            .filter(case_block::Column::Reference.eq(step_id))
+            .count(self.trx())
+            .await?;
+        if blocks > 0 {
+            return Err(OrcaError::BadRequest(format!(
+                "API Step {step_id} is used by {blocks} test case block(s)"
+            )));
+        }
+        Entity::delete_by_id(step_id).exec(self.trx()).await?;
+        info!("Deleting API Step - {:?}", step_id);
+        Ok(())
+    }
+
+    /// list_assertions - list all the assertions of the API Step in the execution order
+    pub async fn list_assertions(&self, step_id: Uuid) -> InternalResult<Vec<assertion::Model>> {
+        let step = self.get_step(step_id).await?;
+        let assertions = step
+            .find_related(assertion::Entity)
+            .order_by_asc(assertion::Column::ExecutionOrder)
+            .all(self.trx())
+            .await?;
+        Ok(assertions)
+    }
+
+    /// check_assertion - check the assertion has what its kind needs
+    fn check_assertion(assertion: &assertion::Model) -> InternalResult<()> {
+        let missing_expression = assertion
+            .expression
+            .as_deref()
+            .map_or(true, |e| e.trim().is_empty());
+        match assertion.kind {
+            AssertionKind::JsonPath | AssertionKind::JsonPointer | AssertionKind::Header
+                if missing_expression =>
+            {
+                Err(OrcaError::BadRequest(format!(
+                    "{:?} assertion needs the expression",
+                    assertion.kind
+                )))?
+            }
+            AssertionKind::JsonSchema => {
+                if let Some(expected) = assertion.expected.as_deref() {
+                    serde_json::from_str::<serde_json::Value>(expected).map_err(|err| {
+                        OrcaError::BadRequest(format!("Invalid JSON Schema - {err}"))
+                    })?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// create_assertion - add the assertion at the end of the API Step
+    pub async fn create_assertion(
+        &self,
+        step_id: Uuid,
+        mut body: assertion::Model,
+    ) -> InternalResult<assertion::Model> {
+        let step = self.get_step(step_id).await?;
+        Self::check_assertion(&body)?;
+        let count = step.find_related(assertion::Entity).count(self.trx()).await?;
+        body.id = Uuid::new_v4();
+        body.step_id = step_id;
+        body.execution_order = count as i32 + 1;
+        let result = body.into_active_model().insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// get_assertion - get the assertion of the API Step
+    async fn get_assertion(
+        &self,
+        step_id: Uuid,
+        assertion_id: Uuid,
+    ) -> InternalResult<assertion::Model> {
+        self.get_step(step_id).await?;
+        let assertion = assertion::Entity::find_by_id(assertion_id)
+            .filter(assertion::Column::StepId.eq(step_id))
+            .one(self.trx())
+            .await?;
+        match assertion {
+            Some(assertion) => Ok(assertion),
+            None => Err(OrcaRepoError::ModelNotFound(
+                "Assertion".to_string(),
+                assertion_id.to_string(),
+            ))?,
+        }
+    }
+
+    /// update_assertion - update the assertion of the API Step
+    pub async fn update_assertion(
+        &self,
+        step_id: Uuid,
+        assertion_id: Uuid,
+        mut body: assertion::Model,
+    ) -> InternalResult<assertion::Model> {
+        self.get_assertion(step_id, assertion_id).await?;
+        Self::check_assertion(&body)?;
+        body.id = assertion_id;
+        body.step_id = step_id;
+        let assertion = body.into_active_model().reset_all();
+        let result = assertion.update(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// delete_assertion - delete the assertion of the API Step
+    pub async fn delete_assertion(&self, step_id: Uuid, assertion_id: Uuid) -> InternalResult<()> {
+        self.get_assertion(step_id, assertion_id).await?;
+        assertion::Entity::delete_by_id(assertion_id)
+            .exec(self.trx())
+            .await?;
+        info!("Deleting Assertion - {:?}", assertion_id);
+        Ok(())
+    }
+}
@@ -5,12 +5,18 @@ use sea_orm::{
 };
 use sea_orm::ActiveValue::Set;
 use sea_query::{Condition, Expr};
+use serde_json::json;
 use tracing::{debug, info};
 use uuid::Uuid;
 
 use cerium::client::Client;
-use cerium::client::driver::web::WebDriver;
-use engine::controller::case::CaseController;
+use cerium::client::driver::pool::SessionPool;
+use engine::controller::validator::{ExecutionReport, Validator};
+use engine::error::EngineError;
+use engine::export::ExportFormat;
+use engine::export::rust::RustExporter;
+use engine::server::executor::Executor;
+use engine::server::registry::ExecutionRegistry;
 use entity::prelude::case::{Column, Entity, Model};
 use entity::prelude::case_block::{
     ActiveModel as BlockActiveModel, Column as BlockColumn, Entity as BlockEntity,
@@ -20,7 +26,8 @@ use entity::test::history;
 use entity::test::ui::{ExecutionRequest, request};
 use entity::test::ui::request::{ExecutionKind, ExecutionStatus, ExecutionType, new};
 
-use crate::error::{InternalResult, OrcaRepoError};
+use crate::error::{InternalResult, OrcaError, OrcaRepoError};
+use crate::route::{LoadParams, RunParams};
 use crate::server::session::OrcaSession;
 use crate::service::app::history::HistoryService;
 
@@ -190,7 +197,8 @@ impl CaseService {
     }
 
     /// run - this will run the single tes case
-    pub async fn run(&self, case_id: Uuid) -> InternalResult<()> {
+    pub async fn run(&self, case_id: Uuid, registry: ExecutionRegistry, pool: SessionPool,
+                     params: RunParams) -> InternalResult<ExecutionRequest> {
         let case = Entity::find_by_id(case_id).one(self.trx()).await?;
         debug!("run {:?}", case);
         if case.is_none() {
@@ -199,37 +207,85 @@ impl CaseService {
                 case_id.to_string(),
             ))?;
         }
-        let _case = case.unwrap();
-        let er_am = new(case_id, ExecutionType::TestCase, ExecutionKind::Trigger, ExecutionStatus::Started, 0, false, None);
-        // let er = ExecutionRequest {
-        //     description: Some(format!("Executing - {case_name}", case_name = _case.name)),
-        //     is_dry_run: true,
-        //     ref_id: case_id,
-        //     ref_type: ExecutionType::TestCase,
-        //     kind: ExecutionKind::Trigger,
-        //     status: ExecutionStatus::Started,
-        //     args: None,
-        //     log_id: 0,
-        //     created_at: chrono::Utc::now().into(),
-        //     created_by: Some("system".to_string()),
-        //     finished_at: None,
-        //     updated_at: None,
-        // };
+        let is_dry_run = params.dry_run.unwrap_or_default();
+        let mut er_am = new(case_id, ExecutionType::TestCase, ExecutionKind::Trigger, ExecutionStatus::Started, 0, is_dry_run, None);
+        er_am.timeout = Set(params.timeout);
+        er_am.args = Set(Some(json!({
+            "profile_id": params.profile_id,
+            "browser": {"name": params.browser, "backend": params.backend},
+        })));
+        // Execution Request is saved outside the request transaction so the
+        // background execution and the cancel endpoint can see it right away
+        let er_am = er_am.save(self.1.db()).await?;
+        let er = er_am.try_into_model()?;
+        info!("Triggering Execution Request - {:?}", er.id);
+        Executor::new(self.1.clone(), registry)
+            .with_pool(Some(pool))
+            .trigger(er.clone());
+        Ok(er)
+    }
 
-        let mut er_am = er_am.save(self.trx()).await?;
-        debug!("run 2 {:?}", er_am);
-        let ui_driver = WebDriver::default().await?;
-        let controller = CaseController::new(self.trx(), ui_driver.clone(), self.1.clone());
-        let er = er_am.clone().try_into_model()?;
-        controller.process(&_case, &er, None).await?;
-        ui_driver.quit().await?;
+    /// load_test - run the API steps of the test case with the virtual users, the report
+    /// is saved on the execution request once the load test is done
+    pub async fn load_test(&self, case_id: Uuid, registry: ExecutionRegistry,
+                           params: LoadParams) -> InternalResult<ExecutionRequest> {
+        let case = Entity::find_by_id(case_id).one(self.trx()).await?;
+        if case.is_none() {
+            return Err(OrcaRepoError::ModelNotFound(
+                "Test Case".to_string(),
+                case_id.to_string(),
+            ))?;
+        }
+        params.config.check().map_err(OrcaError::BadRequest)?;
+        let mut er_am = new(case_id, ExecutionType::TestCase, ExecutionKind::Trigger, ExecutionStatus::Started, 0, false, None);
+        er_am.timeout = Set(params.timeout);
+        er_am.args = Set(Some(json!({
+            "profile_id": params.profile_id,
+            "load": params.config,
+        })));
+        // saved outside the request transaction like the run, the executor picks it up right away
+        let er_am = er_am.save(self.1.db()).await?;
+        let er = er_am.try_into_model()?;
+        info!("Triggering Load Test - {:?}", er.id);
+        Executor::new(self.1.clone(), registry).trigger(er.clone());
+        Ok(er)
+    }
 
-        er_am.status = Set(ExecutionStatus::Completed);
-        er_am.finished_at = Set(chrono::Utc::now().into());
-        er_am.updated_at = Set(chrono::Utc::now().into());
-        er_am.save(self.trx()).await?;
+    /// validate - statically check the test case blocks and actions
+    pub async fn validate(&self, case_id: Uuid, profile_id: Option<Uuid>) -> InternalResult<ExecutionReport> {
+        let case = Entity::find_by_id(case_id).one(self.trx()).await?;
+        if case.is_none() {
+            return Err(OrcaRepoError::ModelNotFound(
+                "Test Case".to_string(),
+                case_id.to_string(),
+            ))?;
+        }
+        let report = Validator::new(self.trx())
+            .validate_case(case_id, profile_id)
+            .await?;
+        Ok(report)
+    }
 
-        Ok(())
+    /// export - generate the standalone test code of the test case in the format
+    pub async fn export(&self, case_id: Uuid, format: Option<String>) -> InternalResult<String> {
+        let format = format.unwrap_or_else(|| "rust".to_string());
+        let format = ExportFormat::parse(&format)
+            .map_err(|err| OrcaError::BadRequest(err.to_string()))?;
+        let case = Entity::find_by_id(case_id).one(self.trx()).await?;
+        let case = case.ok_or_else(|| {
+            OrcaRepoError::ModelNotFound("Test Case".to_string(), case_id.to_string())
+        })?;
+        let blocks = self.query_case(case_id, None).await?;
+        let code = match format {
+            ExportFormat::Rust => RustExporter::new(self.trx()).export(&case, &blocks).await
+                .map_err(|err| match err {
+                    EngineError::Unsupported(message) => OrcaError::BadRequest(message),
+                    err @ EngineError::MissingParameter(..) => OrcaError::BadRequest(err.to_string()),
+                    err => err.into(),
+                })?,
+        };
+        info!("Exported Test Case {} as {:?}", case_id, format);
+        Ok(code)
     }
 
     /// push_into_index - This will Append New Block to the code for spe
@@ -0,0 +1,89 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, PaginatorTrait,
+    QueryFilter, QueryOrder,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use entity::api::channel::{Column, Entity, Model};
+use entity::prelude::case_block;
+use entity::prelude::case_block::BlockType;
+
+use crate::error::{InternalResult, OrcaError, OrcaRepoError};
+use crate::server::session::OrcaSession;
+
+pub(crate) struct ChannelStepService(OrcaSession, Uuid);
+
+impl ChannelStepService {
+    pub fn new(session: OrcaSession, app_id: Uuid) -> Self {
+        Self(session, app_id)
+    }
+
+    pub fn trx(&self) -> &DatabaseTransaction {
+        self.0.trx()
+    }
+
+    /// list_steps - list all the Channel Step of the Application
+    pub async fn list_steps(&self) -> InternalResult<Vec<Model>> {
+        let steps = Entity::find()
+            .filter(Column::AppId.eq(self.1))
+            .order_by_asc(Column::Name)
+            .all(self.trx())
+            .await?;
+        Ok(steps)
+    }
+
+    /// get_step - get the Channel Step of the Application
+    pub async fn get_step(&self, step_id: Uuid) -> InternalResult<Model> {
+        let step = Entity::find_by_id(step_id)
+            .filter(Column::AppId.eq(self.1))
+            .one(self.trx())
+            .await?;
+        match step {
+            Some(step) => Ok(step),
+            None => Err(OrcaRepoError::ModelNotFound(
+                "Channel Step".to_string(),
+                step_id.to_string(),
+            ))?,
+        }
+    }
+
+    /// create_step - create new Channel Step in the Application
+    pub async fn create_step(&self, mut step: Model) -> InternalResult<Model> {
+        step.id = Uuid::new_v4();
+        step.app_id = self.1;
+        step.check().map_err(OrcaError::BadRequest)?;
+        let step = step.into_active_model();
+        let result = step.insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// update_step - update the Channel Step of the Application
+    pub async fn update_step(&self, step_id: Uuid, mut step: Model) -> InternalResult<Model> {
+        self.get_step(step_id).await?;
+        step.id = step_id;
+        step.app_id = self.1;
+        step.check().map_err(OrcaError::BadRequest)?;
+        let step = step.into_active_model().reset_all();
+        let result = step.update(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// delete_step - delete the Channel Step that is not used in any test case
+    pub async fn delete_step(&self, step_id: Uuid) -> InternalResult<()> {
+        self.get_step(step_id).await?;
+        let blocks = case_block::Entity::find()
+            .filter(case_block::Column::TypeField.eq(BlockType::ChannelStep))
+            .filter(case_block::Column::Reference.eq(step_id))
+            .count(self.trx())
+            .await?;
+        if blocks > 0 {
+            return Err(OrcaError::BadRequest(format!(
+                "Channel Step {step_id} is used by {blocks} test case block(s)"
+            )));
+        }
+        Entity::delete_by_id(step_id).exec(self.trx()).await?;
+        info!("Deleting Channel Step - {:?}", step_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, QueryFilter,
+               QueryOrder};
+use sea_orm::ActiveValue::Set;
+use tracing::info;
+
+use engine::server::registry::ExecutionRegistry;
+use entity::test::mock::request as mock_request;
+use entity::test::ui::request::{Entity, ExecutionStatus, Model};
+
+use crate::error::{InternalResult, OrcaError, OrcaRepoError};
+use crate::server::session::OrcaSession;
+
+pub(crate) struct ExecutionService(OrcaSession);
+
+impl ExecutionService {
+    pub fn new(session: OrcaSession) -> Self {
+        Self(session)
+    }
+
+    pub fn trx(&self) -> &DatabaseTransaction {
+        self.0.trx()
+    }
+
+    /// get_execution - get the Execution Request by id
+    pub async fn get_execution(&self, execution_id: i32) -> InternalResult<Model> {
+        let er = Entity::find_by_id(execution_id).one(self.trx()).await?;
+        if er.is_none() {
+            return Err(OrcaRepoError::ModelNotFound(
+                "Execution Request".to_string(),
+                execution_id.to_string(),
+            ))?;
+        }
+        Ok(er.unwrap())
+    }
+
+    /// list_mock_requests - list the requests received by the mock server of the execution
+    pub async fn list_mock_requests(&self, execution_id: i32) -> InternalResult<Vec<mock_request::Model>> {
+        self.get_execution(execution_id).await?;
+        let requests = mock_request::Entity::find()
+            .filter(mock_request::Column::ExecutionId.eq(execution_id))
+            .order_by_asc(mock_request::Column::Id)
+            .all(self.trx())
+            .await?;
+        Ok(requests)
+    }
+
+    /// cancel_execution - this will cancel the running execution, the engine will stop
+    /// before the next step and the remaining items will be skipped
+    pub async fn cancel_execution(
+        &self,
+        execution_id: i32,
+        registry: &ExecutionRegistry,
+    ) -> InternalResult<Model> {
+        let er = self.get_execution(execution_id).await?;
+        if er.status != ExecutionStatus::Started && er.status != ExecutionStatus::Running {
+            return Err(OrcaError::BadRequest(format!(
+                "Execution Request {execution_id} is already {:?}",
+                er.status
+            )));
+        }
+        if registry.cancel(execution_id) {
+            info!("Cancel requested for Execution Request - {:?}", execution_id);
+            return Ok(er);
+        }
+        // nothing is running for the request anymore (e.g. server got restarted)
+        let mut er_am = er.into_active_model();
+        er_am.status = Set(ExecutionStatus::Cancelled);
+        er_am.finished_at = Set(chrono::Utc::now().into());
+        er_am.updated_at = Set(chrono::Utc::now().into());
+        let result = er_am.update(self.trx()).await?;
+        info!("Cancelled the orphan Execution Request - {:?}", execution_id);
+        Ok(result)
+    }
+}
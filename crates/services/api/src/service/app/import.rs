@@ -0,0 +1,70 @@
+use sea_orm::DatabaseTransaction;
+use serde_json::Value;
+use uuid::Uuid;
+
+use engine::error::EngineError;
+use engine::import::ImportReport;
+use engine::import::openapi::OpenApiImporter;
+use engine::import::postman::{PostmanGrouping, PostmanImporter};
+use engine::import::side::{SideImporter, SideProject};
+
+use crate::error::{InternalResult, OrcaError};
+use crate::server::session::OrcaSession;
+
+pub(crate) struct ImportService(OrcaSession, Uuid);
+
+impl ImportService {
+    pub fn new(session: OrcaSession, app_id: Uuid) -> Self {
+        Self(session, app_id)
+    }
+
+    pub fn trx(&self) -> &DatabaseTransaction {
+        self.0.trx()
+    }
+
+    /// import_side - import the Selenium IDE project into the Application
+    pub async fn import_side(&self, project: SideProject) -> InternalResult<ImportReport> {
+        let report = SideImporter::new(self.trx(), self.1).import(project).await?;
+        Ok(report)
+    }
+
+    /// import_openapi - import the OpenAPI document into the Application, the servers of the
+    /// document are overridden by the base url, a relative server needs the base url
+    pub async fn import_openapi(&self, document: Value, base_url: Option<String>) -> InternalResult<ImportReport> {
+        let report = OpenApiImporter::new(self.trx(), self.1)
+            .import(document, base_url)
+            .await
+            .map_err(|err| match err {
+                EngineError::Unsupported(message) => OrcaError::BadRequest(message),
+                err @ EngineError::MissingParameter(..) => OrcaError::BadRequest(err.to_string()),
+                err => err.into(),
+            })?;
+        Ok(report)
+    }
+
+    /// import_postman - import the Postman v2.1 collection into the Application as API steps
+    /// grouped in the test cases
+    pub async fn import_postman(&self, collection: Value, grouping: Option<String>) -> InternalResult<ImportReport> {
+        let result = match PostmanGrouping::parse(grouping.as_deref()) {
+            Ok(grouping) => PostmanImporter::new(self.trx(), self.1).import(collection, grouping).await,
+            Err(err) => Err(err),
+        };
+        let report = result.map_err(|err| match err {
+            EngineError::Unsupported(message) => OrcaError::BadRequest(message),
+            err => err.into(),
+        })?;
+        Ok(report)
+    }
+
+    /// import_postman_environment - import the Postman environment into the Application as a profile
+    pub async fn import_postman_environment(&self, environment: Value) -> InternalResult<ImportReport> {
+        let report = PostmanImporter::new(self.trx(), self.1)
+            .import_environment(environment)
+            .await
+            .map_err(|err| match err {
+                EngineError::Unsupported(message) => OrcaError::BadRequest(message),
+                err => err.into(),
+            })?;
+        Ok(report)
+    }
+}
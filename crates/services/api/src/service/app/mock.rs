@@ -0,0 +1,110 @@
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, QueryFilter, QueryOrder};
+use tracing::info;
+use uuid::Uuid;
+
+use engine::server::mock::RECORDED_PATH;
+use entity::common::attachment::{self, StorageCategory};
+use entity::test::mock::endpoint::{Column, Entity, Model};
+
+use crate::error::{InternalResult, OrcaError, OrcaRepoError};
+use crate::server::session::OrcaSession;
+
+pub(crate) struct MockService(OrcaSession, Uuid);
+
+impl MockService {
+    pub fn new(session: OrcaSession, app_id: Uuid) -> Self {
+        Self(session, app_id)
+    }
+
+    pub fn trx(&self) -> &DatabaseTransaction {
+        self.0.trx()
+    }
+
+    /// list_endpoints - list all the Mock Endpoint of the Application
+    pub async fn list_endpoints(&self) -> InternalResult<Vec<Model>> {
+        let endpoints = Entity::find()
+            .filter(Column::AppId.eq(self.1))
+            .order_by_asc(Column::Path)
+            .all(self.trx())
+            .await?;
+        Ok(endpoints)
+    }
+
+    /// get_endpoint - get the Mock Endpoint of the Application
+    pub async fn get_endpoint(&self, endpoint_id: Uuid) -> InternalResult<Model> {
+        let endpoint = Entity::find_by_id(endpoint_id)
+            .filter(Column::AppId.eq(self.1))
+            .one(self.trx())
+            .await?;
+        match endpoint {
+            Some(endpoint) => Ok(endpoint),
+            None => Err(OrcaRepoError::ModelNotFound(
+                "Mock Endpoint".to_string(),
+                endpoint_id.to_string(),
+            ))?,
+        }
+    }
+
+    /// check_endpoint - check the path, status and the MockData attachment of the endpoint
+    async fn check_endpoint(&self, endpoint: &Model) -> InternalResult<()> {
+        if !endpoint.path.starts_with('/') || endpoint.path == RECORDED_PATH {
+            return Err(OrcaError::BadRequest(format!(
+                "Invalid mock path '{}'",
+                endpoint.path
+            )));
+        }
+        if !(100..600).contains(&endpoint.status) {
+            return Err(OrcaError::BadRequest(format!(
+                "Invalid mock status {}",
+                endpoint.status
+            )));
+        }
+        if let Some(attachment_id) = endpoint.attachment_id {
+            let attachment = attachment::Entity::find_by_id(attachment_id)
+                .one(self.trx())
+                .await?;
+            match attachment {
+                Some(attachment) if attachment.category == StorageCategory::MockData => {}
+                Some(_) => {
+                    return Err(OrcaError::BadRequest(format!(
+                        "Attachment {attachment_id} is not a MockData attachment"
+                    )))
+                }
+                None => Err(OrcaRepoError::ModelNotFound(
+                    "Attachment".to_string(),
+                    attachment_id.to_string(),
+                ))?,
+            }
+        }
+        Ok(())
+    }
+
+    /// create_endpoint - create new Mock Endpoint in the Application
+    pub async fn create_endpoint(&self, mut endpoint: Model) -> InternalResult<Model> {
+        self.check_endpoint(&endpoint).await?;
+        endpoint.id = Uuid::new_v4();
+        endpoint.app_id = self.1;
+        let endpoint = endpoint.into_active_model();
+        let result = endpoint.insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// update_endpoint - update the Mock Endpoint of the Application
+    pub async fn update_endpoint(&self, endpoint_id: Uuid, mut endpoint: Model) -> InternalResult<Model> {
+        self.get_endpoint(endpoint_id).await?;
+        self.check_endpoint(&endpoint).await?;
+        endpoint.id = endpoint_id;
+        endpoint.app_id = self.1;
+        let endpoint = endpoint.into_active_model().reset_all();
+        let result = endpoint.update(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// delete_endpoint - delete the Mock Endpoint of the Application
+    pub async fn delete_endpoint(&self, endpoint_id: Uuid) -> InternalResult<()> {
+        self.get_endpoint(endpoint_id).await?;
+        Entity::delete_by_id(endpoint_id).exec(self.trx()).await?;
+        info!("Deleting Mock Endpoint - {:?}", endpoint_id);
+        Ok(())
+    }
+}
@@ -7,11 +7,20 @@ use crate::error::InternalResult;
 use crate::server::session::OrcaSession;
 
 pub(crate) mod action;
+pub(crate) mod api_step;
 pub(crate) mod case;
+pub(crate) mod channel;
 pub(crate) mod datatable;
+pub(crate) mod execution;
 pub(crate) mod group;
 pub(crate) mod history;
+pub(crate) mod import;
+pub(crate) mod mock;
+pub(crate) mod object_repo;
 pub(crate) mod profile;
+pub(crate) mod recording;
+pub(crate) mod schedule;
+pub(crate) mod sql;
 pub(crate) mod suit;
 
 pub(crate) struct AppService(OrcaSession);
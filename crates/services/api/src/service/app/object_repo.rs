@@ -0,0 +1,251 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel,
+    PaginatorTrait, QueryFilter, QueryOrder, TryIntoModel,
+};
+use sea_orm::ActiveValue::Set;
+use tracing::info;
+use uuid::Uuid;
+
+use entity::test::ui::action::action;
+use entity::test::ui::elements;
+use entity::test::ui::locator;
+use entity::test::ui::screen;
+
+use crate::error::{InternalResult, OrcaError, OrcaRepoError};
+use crate::server::session::OrcaSession;
+
+pub(crate) struct ObjectRepoService(OrcaSession);
+
+impl ObjectRepoService {
+    pub fn new(session: OrcaSession) -> Self {
+        Self(session)
+    }
+
+    pub fn trx(&self) -> &DatabaseTransaction {
+        self.0.trx()
+    }
+
+    /// get_screens - list all the Screen of the Application object repository
+    pub async fn get_screens(&self, app_id: Uuid) -> InternalResult<Vec<screen::Model>> {
+        let screens = screen::Entity::find()
+            .filter(screen::Column::AppId.eq(app_id))
+            .order_by_asc(screen::Column::Name)
+            .all(self.trx())
+            .await?;
+        Ok(screens)
+    }
+
+    /// get_screen - get the Screen of the Application object repository
+    pub async fn get_screen(&self, screen_id: Uuid) -> InternalResult<screen::Model> {
+        let screen = screen::Entity::find_by_id(screen_id).one(self.trx()).await?;
+        match screen {
+            Some(screen) => Ok(screen),
+            None => Err(OrcaRepoError::ModelNotFound(
+                "Screen".to_string(),
+                screen_id.to_string(),
+            ))?,
+        }
+    }
+
+    /// create_screen - create new Screen in the Application object repository
+    pub async fn create_screen(
+        &self,
+        app_id: Uuid,
+        mut screen: screen::Model,
+    ) -> InternalResult<screen::Model> {
+        screen.id = Uuid::new_v4();
+        screen.app_id = app_id;
+        let screen = screen.into_active_model();
+        let result = screen.insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// update_screen - update the name and description of the Screen
+    pub async fn update_screen(
+        &self,
+        screen_id: Uuid,
+        screen: screen::Model,
+    ) -> InternalResult<screen::Model> {
+        let mut _screen = self.get_screen(screen_id).await?.into_active_model();
+        _screen.name = Set(screen.name);
+        _screen.description = Set(screen.description);
+        let result = _screen.save(self.trx()).await?;
+        Ok(result.try_into_model()?)
+    }
+
+    /// delete_screen - delete the Screen along with its elements
+    pub async fn delete_screen(&self, screen_id: Uuid) -> InternalResult<()> {
+        let elements = elements::Entity::find()
+            .filter(elements::Column::ScreenId.eq(screen_id))
+            .all(self.trx())
+            .await?;
+        for element in elements {
+            self.check_unused(element.id).await?;
+        }
+        let result = screen::Entity::delete_by_id(screen_id).exec(self.trx()).await?;
+        if result.rows_affected == 0 {
+            return Err(OrcaRepoError::ModelNotFound(
+                "Screen".to_string(),
+                screen_id.to_string(),
+            ))?;
+        }
+        info!("Deleting Screen - {:?}", screen_id);
+        Ok(())
+    }
+
+    /// get_elements - list all the Element of the Screen
+    pub async fn get_elements(&self, screen_id: Uuid) -> InternalResult<Vec<elements::Model>> {
+        let elements = elements::Entity::find()
+            .filter(elements::Column::ScreenId.eq(screen_id))
+            .order_by_asc(elements::Column::Name)
+            .all(self.trx())
+            .await?;
+        Ok(elements)
+    }
+
+    /// create_element - create new Element in the Screen
+    pub async fn create_element(
+        &self,
+        screen_id: Uuid,
+        mut element: elements::Model,
+    ) -> InternalResult<elements::Model> {
+        self.get_screen(screen_id).await?;
+        element.id = Uuid::new_v4();
+        element.screen_id = screen_id;
+        let element = element.into_active_model();
+        let result = element.insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// update_element - update the Element, the actions referencing it will pick up
+    /// the new locator on the next run
+    pub async fn update_element(
+        &self,
+        screen_id: Uuid,
+        element_id: Uuid,
+        mut element: elements::Model,
+    ) -> InternalResult<elements::Model> {
+        self.get_element(screen_id, element_id).await?;
+        element.id = element_id;
+        element.screen_id = screen_id;
+        let element = element.into_active_model().reset_all();
+        let result = element.update(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// delete_element - delete the Element, fails when an action still references it
+    pub async fn delete_element(&self, screen_id: Uuid, element_id: Uuid) -> InternalResult<()> {
+        self.check_unused(element_id).await?;
+        let result = elements::Entity::delete_many()
+            .filter(elements::Column::Id.eq(element_id))
+            .filter(elements::Column::ScreenId.eq(screen_id))
+            .exec(self.trx())
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(OrcaRepoError::ModelNotFound(
+                "Element".to_string(),
+                element_id.to_string(),
+            ))?;
+        }
+        info!("Deleting Element - {:?}", element_id);
+        Ok(())
+    }
+
+    /// get_element - get the Element of the Screen
+    pub async fn get_element(&self, screen_id: Uuid, element_id: Uuid) -> InternalResult<elements::Model> {
+        let element = elements::Entity::find_by_id(element_id)
+            .filter(elements::Column::ScreenId.eq(screen_id))
+            .one(self.trx())
+            .await?;
+        match element {
+            Some(element) => Ok(element),
+            None => Err(OrcaRepoError::ModelNotFound(
+                "Element".to_string(),
+                element_id.to_string(),
+            ))?,
+        }
+    }
+
+    /// get_locators - list the fallback locators of the Element in the priority order
+    pub async fn get_locators(&self, element_id: Uuid) -> InternalResult<Vec<locator::Model>> {
+        let locators = locator::Entity::find()
+            .filter(locator::Column::ElementId.eq(element_id))
+            .order_by_asc(locator::Column::Priority)
+            .all(self.trx())
+            .await?;
+        Ok(locators)
+    }
+
+    /// create_locator - add the fallback locator to the Element
+    pub async fn create_locator(
+        &self,
+        screen_id: Uuid,
+        element_id: Uuid,
+        mut locator: locator::Model,
+    ) -> InternalResult<locator::Model> {
+        self.get_element(screen_id, element_id).await?;
+        locator.id = Uuid::new_v4();
+        locator.element_id = element_id;
+        let locator = locator.into_active_model();
+        let result = locator.insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// delete_locator - delete the fallback locator of the Element
+    pub async fn delete_locator(&self, element_id: Uuid, locator_id: Uuid) -> InternalResult<()> {
+        let result = locator::Entity::delete_many()
+            .filter(locator::Column::Id.eq(locator_id))
+            .filter(locator::Column::ElementId.eq(element_id))
+            .exec(self.trx())
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(OrcaRepoError::ModelNotFound(
+                "Locator".to_string(),
+                locator_id.to_string(),
+            ))?;
+        }
+        info!("Deleting Locator - {:?}", locator_id);
+        Ok(())
+    }
+
+    /// promote_locator - accept the healing proposal, the proposed fallback becomes the
+    /// primary locator and the old primary takes its place in the fallbacks
+    pub async fn promote_locator(&self, screen_id: Uuid, element_id: Uuid) -> InternalResult<elements::Model> {
+        let element = self.get_element(screen_id, element_id).await?;
+        let Some(locator_id) = element.proposed_locator_id else {
+            return Err(OrcaError::BadRequest(format!(
+                "Element {element_id} has no proposed locator"
+            )));
+        };
+        let fallback = locator::Entity::find_by_id(locator_id)
+            .one(self.trx())
+            .await?
+            .ok_or_else(|| OrcaRepoError::ModelNotFound("Locator".to_string(), locator_id.to_string()))?;
+        let mut _fallback = fallback.clone().into_active_model();
+        _fallback.locator_type = Set(element.locator_type.clone());
+        _fallback.locator_value = Set(element.locator_value.clone());
+        _fallback.update(self.trx()).await?;
+
+        let mut _element = element.into_active_model();
+        _element.locator_type = Set(fallback.locator_type);
+        _element.locator_value = Set(fallback.locator_value);
+        _element.proposed_locator_id = Set(None);
+        let result = _element.update(self.trx()).await?;
+        info!("Promoted Locator {:?} of Element {:?}", locator_id, element_id);
+        Ok(result)
+    }
+
+    /// check_unused - make sure no action references the element
+    async fn check_unused(&self, element_id: Uuid) -> InternalResult<()> {
+        let used = action::Entity::find()
+            .filter(action::Column::ElementId.eq(element_id))
+            .count(self.trx())
+            .await?;
+        if used > 0 {
+            return Err(OrcaError::BadRequest(format!(
+                "Element {element_id} is used by {used} action(s)"
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use sea_orm::DatabaseTransaction;
+use uuid::Uuid;
+
+use engine::server::recorder::{RecordedEvent, Recorder, RecordingRegistry, RecordingSession};
+use entity::test::ui::action::group::Model as ActionGroupModel;
+
+use crate::error::{InternalResult, OrcaRepoError};
+use crate::server::session::OrcaSession;
+
+pub(crate) struct RecordingService(OrcaSession, RecordingRegistry, Uuid);
+
+impl RecordingService {
+    pub fn new(session: OrcaSession, registry: RecordingRegistry, app_id: Uuid) -> Self {
+        Self(session, registry, app_id)
+    }
+
+    pub fn trx(&self) -> &DatabaseTransaction {
+        self.0.trx()
+    }
+
+    /// session - recording session of the Application
+    fn session(&self, recording_id: Uuid) -> InternalResult<RecordingSession> {
+        match self.1.get(recording_id) {
+            Some(session) if session.app_id == self.2 => Ok(session),
+            _ => Err(OrcaRepoError::ModelNotFound(
+                "Recording".to_string(),
+                recording_id.to_string(),
+            ))?,
+        }
+    }
+
+    /// start_recording - open a new recording session for the Application
+    pub async fn start_recording(&self, name: String) -> InternalResult<RecordingSession> {
+        Ok(self.1.start(self.2, name))
+    }
+
+    /// record_events - append the events captured in the browser to the session
+    pub async fn record_events(
+        &self,
+        recording_id: Uuid,
+        events: Vec<RecordedEvent>,
+    ) -> InternalResult<usize> {
+        self.session(recording_id)?;
+        let count = self.1.push(recording_id, events).ok_or_else(|| {
+            OrcaRepoError::ModelNotFound("Recording".to_string(), recording_id.to_string())
+        })?;
+        Ok(count)
+    }
+
+    /// finish_recording - close the session and save the recorded events as a new
+    /// Action Group with the actions and the object repository elements
+    pub async fn finish_recording(&self, recording_id: Uuid) -> InternalResult<ActionGroupModel> {
+        self.session(recording_id)?;
+        let session = self.1.remove(recording_id).ok_or_else(|| {
+            OrcaRepoError::ModelNotFound("Recording".to_string(), recording_id.to_string())
+        })?;
+        let group = Recorder::new(self.trx(), self.2).save(session).await?;
+        Ok(group)
+    }
+
+    /// discard_recording - close the session without saving the events
+    pub async fn discard_recording(&self, recording_id: Uuid) -> InternalResult<()> {
+        self.session(recording_id)?;
+        self.1.remove(recording_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, QueryFilter,
+    QueryOrder,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use engine::server::scheduler::next_run;
+use entity::test::schedule::{Column, Entity, Model};
+
+use crate::error::{InternalResult, OrcaError, OrcaRepoError};
+use crate::server::session::OrcaSession;
+
+pub(crate) struct ScheduleService(OrcaSession, Uuid);
+
+impl ScheduleService {
+    pub fn new(session: OrcaSession, app_id: Uuid) -> Self {
+        Self(session, app_id)
+    }
+
+    pub fn trx(&self) -> &DatabaseTransaction {
+        self.0.trx()
+    }
+
+    /// list_schedules - list all the Schedules in the Orca Application
+    pub async fn list_schedules(&self) -> InternalResult<Vec<Model>> {
+        let schedules = Entity::find()
+            .filter(Column::AppId.eq(self.1))
+            .order_by_asc(Column::Name)
+            .all(self.trx())
+            .await?;
+        Ok(schedules)
+    }
+
+    /// get_schedule - get the Schedule by id
+    pub async fn get_schedule(&self, schedule_id: Uuid) -> InternalResult<Model> {
+        let schedule = Entity::find_by_id(schedule_id)
+            .filter(Column::AppId.eq(self.1))
+            .one(self.trx())
+            .await?;
+        if schedule.is_none() {
+            return Err(OrcaRepoError::ModelNotFound(
+                "Schedule".to_string(),
+                schedule_id.to_string(),
+            ))?;
+        }
+        Ok(schedule.unwrap())
+    }
+
+    /// create_schedule - this will create new Schedule in the Application
+    pub async fn create_schedule(&self, mut schedule: Model) -> InternalResult<Model> {
+        schedule.id = Uuid::new_v4();
+        schedule.app_id = self.1;
+        schedule.last_run_at = None;
+        schedule.next_run_at = Some(Self::next_run_at(&schedule)?);
+        let schedule = schedule.into_active_model();
+        let result = schedule.insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// update_schedule - this will update the existing Schedule and move it to the next run
+    pub async fn update_schedule(
+        &self,
+        schedule_id: Uuid,
+        mut schedule: Model,
+    ) -> InternalResult<Model> {
+        let existing = self.get_schedule(schedule_id).await?;
+        schedule.id = schedule_id;
+        schedule.app_id = self.1;
+        schedule.last_run_at = existing.last_run_at;
+        schedule.next_run_at = Some(Self::next_run_at(&schedule)?);
+        let schedule = schedule.into_active_model().reset_all();
+        let result = schedule.update(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// delete_schedule - this will delete the existing Schedule in the Application
+    pub async fn delete_schedule(&self, schedule_id: Uuid) -> InternalResult<()> {
+        let result = Entity::delete_many()
+            .filter(Column::Id.eq(schedule_id))
+            .filter(Column::AppId.eq(self.1))
+            .exec(self.trx())
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(OrcaRepoError::ModelNotFound(
+                "Schedule".to_string(),
+                schedule_id.to_string(),
+            ))?;
+        }
+        info!("Deleted Schedule - {:?}", schedule_id);
+        Ok(())
+    }
+
+    /// next_run_at - validate the cron expression and timezone and get the next run
+    fn next_run_at(schedule: &Model) -> InternalResult<sea_orm::prelude::DateTimeWithTimeZone> {
+        next_run(&schedule.cron, &schedule.timezone, chrono::Utc::now().into())
+            .map_err(|err| OrcaError::BadRequest(err.to_string()))
+    }
+}
@@ -0,0 +1,176 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, PaginatorTrait,
+    QueryFilter, QueryOrder,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use entity::prelude::case_block;
+use entity::prelude::case_block::BlockType;
+use entity::test::profile::profile;
+use entity::test::sql::{connection, step};
+
+use crate::error::{InternalResult, OrcaError, OrcaRepoError};
+use crate::server::session::OrcaSession;
+
+/// SUPPORTED_SCHEMES - database connections the SQL steps can run on
+const SUPPORTED_SCHEMES: [&str; 2] = ["postgres://", "postgresql://"];
+
+pub(crate) struct SqlService(OrcaSession, Uuid);
+
+impl SqlService {
+    pub fn new(session: OrcaSession, app_id: Uuid) -> Self {
+        Self(session, app_id)
+    }
+
+    pub fn trx(&self) -> &DatabaseTransaction {
+        self.0.trx()
+    }
+
+    /// list_connections - list all the Database Connection of the Application
+    pub async fn list_connections(&self) -> InternalResult<Vec<connection::Model>> {
+        let connections = connection::Entity::find()
+            .filter(connection::Column::AppId.eq(self.1))
+            .order_by_asc(connection::Column::Name)
+            .all(self.trx())
+            .await?;
+        Ok(connections)
+    }
+
+    /// get_connection - get the Database Connection of the Application
+    pub async fn get_connection(&self, connection_id: Uuid) -> InternalResult<connection::Model> {
+        let connection = connection::Entity::find_by_id(connection_id)
+            .filter(connection::Column::AppId.eq(self.1))
+            .one(self.trx())
+            .await?;
+        match connection {
+            Some(connection) => Ok(connection),
+            None => Err(OrcaRepoError::ModelNotFound(
+                "Database Connection".to_string(),
+                connection_id.to_string(),
+            ))?,
+        }
+    }
+
+    /// check_connection - check the url is a Postgres one and the profile is of the Application
+    async fn check_connection(&self, connection: &connection::Model) -> InternalResult<()> {
+        if !SUPPORTED_SCHEMES.iter().any(|scheme| connection.url.starts_with(scheme)) {
+            return Err(OrcaError::BadRequest(format!(
+                "Database Connection '{}' has to be a Postgres url",
+                connection.name
+            )));
+        }
+        if let Some(profile_id) = connection.profile_id {
+            profile::Entity::find_by_id(profile_id)
+                .filter(profile::Column::AppId.eq(self.1))
+                .one(self.trx())
+                .await?
+                .ok_or_else(|| OrcaRepoError::ModelNotFound("Profile".to_string(), profile_id.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// create_connection - create new Database Connection in the Application
+    pub async fn create_connection(&self, mut connection: connection::Model) -> InternalResult<connection::Model> {
+        self.check_connection(&connection).await?;
+        connection.id = Uuid::new_v4();
+        connection.app_id = self.1;
+        let connection = connection.into_active_model();
+        let result = connection.insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// update_connection - update the Database Connection of the Application
+    pub async fn update_connection(&self, connection_id: Uuid,
+                                   mut connection: connection::Model) -> InternalResult<connection::Model> {
+        self.get_connection(connection_id).await?;
+        self.check_connection(&connection).await?;
+        connection.id = connection_id;
+        connection.app_id = self.1;
+        let connection = connection.into_active_model().reset_all();
+        let result = connection.update(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// delete_connection - delete the Database Connection of the Application
+    pub async fn delete_connection(&self, connection_id: Uuid) -> InternalResult<()> {
+        self.get_connection(connection_id).await?;
+        connection::Entity::delete_by_id(connection_id).exec(self.trx()).await?;
+        info!("Deleting Database Connection - {:?}", connection_id);
+        Ok(())
+    }
+
+    /// list_steps - list all the SQL Step of the Application
+    pub async fn list_steps(&self) -> InternalResult<Vec<step::Model>> {
+        let steps = step::Entity::find()
+            .filter(step::Column::AppId.eq(self.1))
+            .order_by_asc(step::Column::Name)
+            .all(self.trx())
+            .await?;
+        Ok(steps)
+    }
+
+    /// get_step - get the SQL Step of the Application
+    pub async fn get_step(&self, step_id: Uuid) -> InternalResult<step::Model> {
+        let step = step::Entity::find_by_id(step_id)
+            .filter(step::Column::AppId.eq(self.1))
+            .one(self.trx())
+            .await?;
+        match step {
+            Some(step) => Ok(step),
+            None => Err(OrcaRepoError::ModelNotFound(
+                "SQL Step".to_string(),
+                step_id.to_string(),
+            ))?,
+        }
+    }
+
+    /// check_step - check the query and the checks of the SQL Step
+    fn check_step(step: &step::Model) -> InternalResult<()> {
+        if step.query.trim().is_empty() {
+            return Err(OrcaError::BadRequest("SQL Step is missing the query".to_string()));
+        }
+        step.checks()
+            .map_err(|err| OrcaError::BadRequest(format!("Invalid checks - {err}")))?;
+        Ok(())
+    }
+
+    /// create_step - create new SQL Step in the Application
+    pub async fn create_step(&self, mut step: step::Model) -> InternalResult<step::Model> {
+        Self::check_step(&step)?;
+        step.id = Uuid::new_v4();
+        step.app_id = self.1;
+        let step = step.into_active_model();
+        let result = step.insert(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// update_step - update the SQL Step of the Application
+    pub async fn update_step(&self, step_id: Uuid, mut step: step::Model) -> InternalResult<step::Model> {
+        self.get_step(step_id).await?;
+        Self::check_step(&step)?;
+        step.id = step_id;
+        step.app_id = self.1;
+        let step = step.into_active_model().reset_all();
+        let result = step.update(self.trx()).await?;
+        Ok(result)
+    }
+
+    /// delete_step - delete the SQL Step that is not used in any test case
+    pub async fn delete_step(&self, step_id: Uuid) -> InternalResult<()> {
+        self.get_step(step_id).await?;
+        let blocks = case_block::Entity::find()
+            .filter(case_block::Column::TypeField.eq(BlockType::SqlStep))
+            .filter(case_block::Column::Reference.eq(step_id))
+            .count(self.trx())
+            .await?;
+        if blocks > 0 {
+            return Err(OrcaError::BadRequest(format!(
+                "SQL Step {step_id} is used by {blocks} test case block(s)"
+            )));
+        }
+        step::Entity::delete_by_id(step_id).exec(self.trx()).await?;
+        info!("Deleting SQL Step - {:?}", step_id);
+        Ok(())
+    }
+}
@@ -1,28 +1,53 @@
-use s3::Region;
+use std::sync::Arc;
+
 use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, TryIntoModel};
 use sea_orm::ActiveValue::Set;
 use sea_orm::prelude::{DateTimeWithTimeZone, Uuid};
-use thirtyfour::By;
-use tracing::info;
+use tracing::{info, warn};
 
 use cerium::client::Client;
-use cerium::client::driver::web::WebDriver;
+use cerium::client::driver::{Driver, Element, Locator};
 use cerium::client::storage::s3::S3Client;
 use entity::prelude::target::ActionTargetKind;
 use entity::test::ui::action::action;
 use entity::test::ui::action::action::ActionKind;
 use entity::test::ui::action::group::{Entity as ActionGroupEntity, Model as ActionGroupModel};
+use entity::test::ui::elements;
+use entity::test::ui::locator as element_locator;
 use entity::test::ui::ExecutionRequest;
 use entity::test::ui::log::item_log::{ItemLogStatus, ItemLogType, new};
 use entity::test::ui::log::ItemLog;
 
+use crate::controller::{failed_status, with_timeout};
+use crate::controller::data::{fill, Variables};
 use crate::error::{EngineError, EngineResult};
+use crate::server::registry::ExecutionToken;
+
+/// locator - resolve the action target to the driver locator, every command and the
+/// object repository fallbacks go through it
+pub(crate) fn locator(kind: ActionTargetKind, value: String) -> Locator {
+    match kind {
+        ActionTargetKind::Css => Locator::Css(value),
+        ActionTargetKind::Id => Locator::Id(value),
+        ActionTargetKind::Xpath => Locator::XPath(value),
+        ActionTargetKind::Name => Locator::Name(value),
+        ActionTargetKind::LinkText => Locator::LinkText(value),
+        ActionTargetKind::PartialLinkText => Locator::PartialLinkText(value),
+        ActionTargetKind::ClassName => Locator::ClassName(value),
+        ActionTargetKind::Tag => Locator::Tag(value),
+        ActionTargetKind::Text => Locator::Text(value),
+        ActionTargetKind::Shadow => Locator::Shadow(value),
+    }
+}
 
 pub struct ActionController<'ccl> {
     db: &'ccl DatabaseTransaction,
-    driver: WebDriver,
+    driver: Arc<dyn Driver>,
     client: Client,
     storage_cli: S3Client,
+    /// runtime variables to resolve the `${key}` placeholders of the actions
+    variables: Variables,
+    token: ExecutionToken,
 }
 
 impl<'ccl> ActionController<'ccl> {
@@ -31,16 +56,18 @@ impl<'ccl> ActionController<'ccl> {
     /// # Arguments
     ///
     /// * `db` - A reference to a `DatabaseTransaction` instance.
-    /// * `driver` - A `WebDriver` instance.
+    /// * `driver` - A `Driver` session the actions will run on.
     /// * `client` - A `Client` instance.
+    /// * `token` - A `ExecutionToken` to check if the execution got cancelled.
     ///
     /// # Returns
     ///
     /// Returns a new `ActionController` instance.
     pub fn new(
         db: &'ccl DatabaseTransaction,
-        driver: WebDriver,
+        driver: Arc<dyn Driver>,
         client: Client,
+        token: ExecutionToken,
     ) -> ActionController<'ccl> {
         let storage_cli = client.storage_cli.clone();
         // Return a new ActionController instance
@@ -49,9 +76,30 @@ impl<'ccl> ActionController<'ccl> {
             driver,
             client,
             storage_cli,
+            variables: Variables::new(),
+            token,
         }
     }
 
+    /// with_variables - share the runtime variables of the execution with the controller
+    pub fn with_variables(mut self, variables: Variables) -> Self {
+        self.variables = variables;
+        self
+    }
+
+    /// resolve - fill the `${key}` placeholders of the action data and target with the
+    /// runtime variables
+    fn resolve(&self, action: &action::Model) -> EngineResult<action::Model> {
+        let scope = self.variables.scope();
+        let data_value = action.data_value.as_deref()
+            .map(|value| fill(&scope, "action.data_value", value))
+            .transpose()?;
+        let target_value = action.target_value.as_deref()
+            .map(|value| fill(&scope, "action.target_value", value))
+            .transpose()?;
+        Ok(action::Model { data_value, target_value, ..action.clone() })
+    }
+
     /// Asynchronous method that handles the logic for executing the "Open" action in a test case.
     ///
     /// # Arguments
@@ -63,14 +111,15 @@ impl<'ccl> ActionController<'ccl> {
     ///
     /// ```rust
     /// use sea_orm::{DatabaseConnection, DatabaseTransaction};
-    /// use cerium::client::driver::web::WebDriver;
+    /// use cerium::client::driver::{Driver, Locator};
     /// use engine::controller::action::ActionController;
+    /// use engine::server::registry::ExecutionToken;
     /// use entity::test::ui::action::action::Model;
     ///
     /// let db = DatabaseTransaction::new();
-    /// let driver = WebDriver::default();
+    /// let driver = Arc::new(WebDriver::default().await?);
     /// let action = Model::new();
-    /// let controller = ActionController::new(&db, driver);
+    /// let controller = ActionController::new(&db, driver, client, ExecutionToken::new());
     /// controller.command_open(&action).await;
     /// ```
     ///
@@ -87,6 +136,66 @@ impl<'ccl> ActionController<'ccl> {
         }
     }
 
+    /// target - inline locator of the action
+    fn target(&self, action: &action::Model) -> EngineResult<Locator> {
+        let target_value = action.target_value.clone().ok_or_else(|| {
+            EngineError::MissingParameter("action.target_value".to_string(), "".to_string())
+        })?;
+        let target_kind = action.target_kind.clone().ok_or_else(|| {
+            EngineError::MissingParameter("action.target_kind".to_string(), "".to_string())
+        })?;
+        Ok(locator(target_kind, target_value))
+    }
+
+    /// find - find the target element of the action, the object repository element takes
+    /// the place of the inline target when the action points to one
+    async fn find(&self, action: &action::Model, log: Option<&ItemLog>) -> EngineResult<Box<dyn Element>> {
+        let Some(element_id) = action.element_id else {
+            return Ok(self.driver.find(&self.target(action)?).await?);
+        };
+        let element = elements::Entity::find_by_id(element_id).one(self.db).await?
+            .ok_or_else(|| EngineError::MissingParameter(
+                "action.element_id".to_string(),
+                element_id.to_string(),
+            ))?;
+        let primary = locator(element.locator_type.clone(), element.locator_value.clone());
+        let err = match self.driver.find(&primary).await {
+            Ok(we) => return Ok(we),
+            Err(err) => err,
+        };
+        self.heal(element, &primary, log).await?.ok_or_else(|| err.into())
+    }
+
+    /// heal - try the fallback locators of the element in the priority order after the
+    /// primary did not match, the matched fallback is proposed as the new primary and
+    /// logged under the log of the action
+    async fn heal(&self, element: elements::Model, primary: &Locator,
+                  log: Option<&ItemLog>) -> EngineResult<Option<Box<dyn Element>>> {
+        let fallbacks = element.find_related(element_locator::Entity)
+            .order_by_asc(element_locator::Column::Priority)
+            .all(self.db)
+            .await?;
+        for fallback in fallbacks {
+            let fallback_locator = locator(fallback.locator_type.clone(), fallback.locator_value.clone());
+            let Ok(we) = self.driver.find(&fallback_locator).await else { continue };
+            warn!("Element {} ({}) did not match {:?}, healed with the fallback {:?}",
+                element.name, element.id, primary, fallback_locator);
+            if let Some(log) = log {
+                let mut healed = new(log.ref_id, ItemLogType::Locator, fallback.id, Some(log.id));
+                healed.status = Set(ItemLogStatus::Success);
+                healed.finished_at = Set(chrono::Utc::now().into());
+                healed.insert(self.db).await?;
+            }
+            if element.proposed_locator_id != Some(fallback.id) {
+                let mut element = element.into_active_model();
+                element.proposed_locator_id = Set(Some(fallback.id));
+                element.update(self.db).await?;
+            }
+            return Ok(Some(we));
+        }
+        Ok(None)
+    }
+
     /// Asynchronously enters data into a target element on a web page using a WebDriver.
     ///
     /// # Arguments
@@ -108,7 +217,7 @@ impl<'ccl> ActionController<'ccl> {
     ///     data_value: Some("example data".to_string()),
     ///     target_value: Some("example target".to_string()),
     ///     action_group_id: Default::default(),data: None,target_kind: Some(ActionTargetKind::Css),
-    /// target: None,};
+    /// target: None,timeout: None,element_id: None,};
     /// ui_helper.command_enter(&action).await;
     /// ```
     ///
@@ -119,25 +228,13 @@ impl<'ccl> ActionController<'ccl> {
     /// # Returns
     ///
     /// Returns `Ok(())` if the action of entering data into the target element is successful.
-    async fn command_enter(&self, action: &action::Model) -> EngineResult<()> {
+    async fn command_enter(&self, action: &action::Model, log: Option<&ItemLog>) -> EngineResult<()> {
         let data_value = action.data_value.clone().ok_or_else(|| {
             EngineError::MissingParameter("action.data_value".to_string(), "".to_string())
         })?;
-        let target_value = action.target_value.clone().ok_or_else(|| {
-            EngineError::MissingParameter("action.target_value".to_string(), "".to_string())
-        })?;
-        let target_kind = action.target_kind.clone().ok_or_else(|| {
-            EngineError::MissingParameter("action.target_kind".to_string(), "".to_string())
-        })?;
-        let by_kind = match target_kind {
-            ActionTargetKind::Css => By::Css(target_value.as_str()),
-            ActionTargetKind::Id => By::Id(target_value.as_str()),
-            ActionTargetKind::Xpath => By::XPath(target_value.as_str()),
-        };
-        self.driver
-            .find(by_kind)
+        self.find(action, log)
             .await?
-            .send_keys(data_value)
+            .send_keys(data_value.as_str())
             .await?;
         Ok(())
     }
@@ -159,7 +256,7 @@ impl<'ccl> ActionController<'ccl> {
     ///     execution_order: 0,description: None,kind: 
     ///     ActionKind::Click,data_kind: None,data_value: Some("button".to_string()),
     ///     target_kind: Some(ActionTargetKind::Css),
-    ///     target_value: None,action_group_id: Default::default(),data: None,target: None,};
+    ///     target_value: None,action_group_id: Default::default(),data: None,target: None,timeout: None,element_id: None,};
     ///
     /// ui_helper.command_click(&action);
     /// ```
@@ -167,47 +264,16 @@ impl<'ccl> ActionController<'ccl> {
     /// # Returns
     ///
     /// Returns `Ok(())` if the click action is performed successfully.
-    async fn command_click(&self, action: &action::Model) -> EngineResult<()> {
-        let target_value = action.target_value.clone().ok_or_else(|| {
-            EngineError::MissingParameter(
-                "command_click.action.target_value".to_string(),
-                "".to_string(),
-            )
-        })?;
-        let target_kind = action.target_kind.clone().ok_or_else(|| {
-            EngineError::MissingParameter(
-                "command_click.action.target_kind".to_string(),
-                "".to_string(),
-            )
-        })?;
-        let by_kind = match target_kind {
-            ActionTargetKind::Css => By::Css(target_value.as_str()),
-            ActionTargetKind::Id => By::Id(target_value.as_str()),
-            ActionTargetKind::Xpath => By::XPath(target_value.as_str()),
-        };
-        self.driver.find(by_kind).await?.click().await?;
+    async fn command_click(&self, action: &action::Model, log: Option<&ItemLog>) -> EngineResult<()> {
+        self.find(action, log).await?.click().await?;
         Ok(())
     }
 
-    async fn command_verify_text(&self, action: &action::Model) -> EngineResult<()> {
+    async fn command_verify_text(&self, action: &action::Model, log: Option<&ItemLog>) -> EngineResult<()> {
         let data_value = action.data_value.clone().ok_or_else(|| {
             EngineError::MissingParameter("action.data_value".to_string(), "".to_string())
         })?;
-        let target_value = action.target_value.clone().ok_or_else(|| {
-            EngineError::MissingParameter(
-                "command_verify_text.action.target_value".to_string(),
-                "".to_string(),
-            )
-        })?;
-        let target_kind = action.target_kind.clone().ok_or_else(|| {
-            EngineError::MissingParameter("action.target_kind".to_string(), "".to_string())
-        })?;
-        let by_kind = match target_kind {
-            ActionTargetKind::Css => By::Css(target_value.as_str()),
-            ActionTargetKind::Id => By::Id(target_value.as_str()),
-            ActionTargetKind::Xpath => By::XPath(target_value.as_str()),
-        };
-        let we = self.driver.find(by_kind).await?;
+        let we = self.find(action, log).await?;
         let text = we.inner_html().await?;
         info!(text);
         if text != data_value {
@@ -221,14 +287,14 @@ impl<'ccl> ActionController<'ccl> {
         Ok(())
     }
 
-    pub async fn step_executor(&self, action: &action::Model) -> EngineResult<()> {
+    pub async fn step_executor(&self, action: &action::Model, log: Option<&ItemLog>) -> EngineResult<()> {
         let set_response = match action.kind.clone() {
             ActionKind::Open => self.command_open(action).await?,
-            ActionKind::Enter => self.command_enter(action).await?,
-            ActionKind::Click => self.command_click(action).await?,
+            ActionKind::Enter => self.command_enter(action, log).await?,
+            ActionKind::Click => self.command_click(action, log).await?,
             ActionKind::DoubleClick => {}
 
-            ActionKind::VerifyText => self.command_verify_text(action).await?,
+            ActionKind::VerifyText => self.command_verify_text(action, log).await?,
             _ => {}
         };
         Ok(())
@@ -237,6 +303,10 @@ impl<'ccl> ActionController<'ccl> {
     async fn take_screenshot(&self, id: String) -> EngineResult<()> {
         let session_id = self.driver.session_id().await?;
         let content = self.driver.take_screenshot().await?;
+        if content.is_empty() {
+            // nothing captured by the driver, nothing to upload
+            return Ok(());
+        }
         let _result = self
             .storage_cli
             .create("orca", format!("session/{session_id}/{id}.png").as_str(), content.as_slice())
@@ -248,23 +318,40 @@ impl<'ccl> ActionController<'ccl> {
                                 log: Option<&ItemLog>) -> EngineResult<()> {
         let log_id = log.map(|l| l.id);
         let mut log_am = new(er.ref_id, ItemLogType::Action, action.id, log_id).save(self.db).await?;
+        if self.token.is_cancelled() {
+            info!("[{er}] Skipping Action {action_id} - execution cancelled", er=er.ref_id, action_id = action.id);
+            log_am.status = Set(ItemLogStatus::Skipped);
+            log_am.finished_at = Set(chrono::Utc::now().into());
+            log_am.save(self.db).await?;
+            return Ok(());
+        }
         info!("[{er}] Trigger Action {action_id}", er=er.ref_id, action_id = action.id);
         let start = chrono::Utc::now();
         info!(
             "Executing step == [id] {:?}, [desc] {:?}",
             action.id, action.description
         );
-        self.step_executor(&action).await?;
-        self.take_screenshot(action.id.to_string()).await?;
+        let action_log = log_am.clone().try_into_model()?;
+        let result = match self.resolve(action) {
+            Ok(action) => with_timeout("Action", action.timeout, self.step_executor(&action, Some(&action_log))).await,
+            Err(err) => Err(err),
+        };
+        let result = match result {
+            Ok(_) => self.take_screenshot(action.id.to_string()).await,
+            Err(err) => Err(err),
+        };
         info!(
             "Done step == [id] {:?}, [desc] {:?}",
             action.id, action.description
         );
         log_am.execution_time = Set((chrono::Utc::now() - start).num_milliseconds() as i32);
-        log_am.status = Set(ItemLogStatus::Success);
+        log_am.status = Set(match &result {
+            Ok(_) => ItemLogStatus::Success,
+            Err(err) => failed_status(err),
+        });
         log_am.finished_at = Set(chrono::Utc::now().into());
         log_am.save(self.db).await?;
-        Ok(())
+        result
     }
 
     pub async fn execute_action_group(&self, action_group: ActionGroupModel, er: &ExecutionRequest,
@@ -295,12 +382,18 @@ impl<'ccl> ActionController<'ccl> {
         }
         let mut log_am = log_am.save(self.db).await?;
         let log = log_am.clone().try_into_model()?;
-        self.execute_action_group(action_group, er, Some(&log)).await?;
+        let result = self.execute_action_group(action_group, er, Some(&log)).await;
+        // the actions left after the cancel are logged as skipped, so is the group
+        let skipped = self.token.is_cancelled();
 
         log_am.execution_time = Set((chrono::Utc::now() - start).num_milliseconds() as i32);
-        log_am.status = Set(ItemLogStatus::Success);
+        log_am.status = Set(match &result {
+            Ok(_) if skipped => ItemLogStatus::Skipped,
+            Ok(_) => ItemLogStatus::Success,
+            Err(err) => failed_status(err),
+        });
         log_am.finished_at = Set(chrono::Utc::now().into());
         log_am.save(self.db).await?;
-        Ok(())
+        result
     }
 }
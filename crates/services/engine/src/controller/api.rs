@@ -0,0 +1,302 @@
+use std::time::Instant;
+
+use reqwest::{Client as HttpClient, Method, Request};
+use sea_orm::{ActiveModelTrait, DatabaseTransaction, EntityTrait, ModelTrait, NotSet, QueryOrder,
+               TryIntoModel};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::Uuid;
+use serde_json::{Map, Value};
+use tracing::info;
+
+use entity::api::{assertion, response, spec, step};
+use entity::api::step::{ApiAuth, ApiMethod};
+use entity::test::ui::ExecutionRequest;
+use entity::test::ui::log::item_log::{ItemLogStatus, ItemLogType, new};
+use entity::test::ui::log::ItemLog;
+
+use crate::controller::{failed_status, with_timeout};
+use crate::controller::assertion::{evaluate, operation_schema};
+use crate::controller::data::{fill, Variables};
+use crate::controller::graphql;
+use crate::error::{EngineError, EngineResult};
+use crate::server::registry::ExecutionToken;
+
+/// ApiResponse - response received for the API step
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    pub status: i32,
+    pub headers: Map<String, Value>,
+    pub body: String,
+    /// time taken for the response in milliseconds
+    pub latency: i32,
+}
+
+fn method(method: &ApiMethod) -> Method {
+    match method {
+        ApiMethod::Get => Method::GET,
+        ApiMethod::Post => Method::POST,
+        ApiMethod::Put => Method::PUT,
+        ApiMethod::Patch => Method::PATCH,
+        ApiMethod::Delete => Method::DELETE,
+        ApiMethod::Head => Method::HEAD,
+        ApiMethod::Options => Method::OPTIONS,
+    }
+}
+
+pub struct ApiController<'acl> {
+    db: &'acl DatabaseTransaction,
+    http: HttpClient,
+    variables: Variables,
+    token: ExecutionToken,
+}
+
+impl<'acl> ApiController<'acl> {
+    pub fn new(db: &'acl DatabaseTransaction, variables: Variables,
+               token: ExecutionToken) -> ApiController<'acl> {
+        Self { db, http: HttpClient::new(), variables, token }
+    }
+
+    /// request - build the http request of the step with the query, headers, auth and body,
+    /// the placeholders are resolved with the runtime variables of the execution
+    pub fn request(&self, step: &step::Model) -> EngineResult<Request> {
+        let scope = self.variables.scope();
+        let mut query = vec![];
+        for (name, value) in step.query() {
+            query.push((name, fill(&scope, "api_step.query", &value)?));
+        }
+        let mut builder = self.http.request(method(&step.method), fill(&scope, "api_step.url", &step.url)?)
+            .query(&query);
+        for (name, value) in step.headers() {
+            builder = builder.header(name, fill(&scope, "api_step.headers", &value)?);
+        }
+        let auth = |value: &str| fill(&scope, "api_step.auth", value);
+        builder = match step.auth()? {
+            Some(ApiAuth::Basic { username, password }) => {
+                builder.basic_auth(auth(&username)?, password.as_deref().map(auth).transpose()?)
+            }
+            Some(ApiAuth::Bearer { token }) => builder.bearer_auth(auth(&token)?),
+            Some(ApiAuth::ApiKey { name, value, in_query: true }) => builder.query(&[(name, auth(&value)?)]),
+            Some(ApiAuth::ApiKey { name, value, in_query: false }) => builder.header(name, auth(&value)?),
+            None => builder,
+        };
+        if let Some(document) = step.graphql()? {
+            if document.operation() == Some("subscription") {
+                return Err(EngineError::Unsupported(format!("GraphQL subscription in the API step {}", step.id)));
+            }
+            let variables = document.variables.as_ref()
+                .map(|variables| graphql::variables(&scope, variables))
+                .transpose()
+                .map_err(|missing| {
+                    EngineError::MissingParameter("api_step.graphql placeholder(s)".to_string(), missing.join(", "))
+                })?;
+            let payload = graphql::payload(&document, variables);
+            builder = match step.method {
+                ApiMethod::Get => {
+                    let params: Vec<(String, String)> = payload.as_object().into_iter().flatten()
+                        .map(|(name, value)| (name.clone(), match value {
+                            Value::String(value) => value.clone(),
+                            value => value.to_string(),
+                        }))
+                        .collect();
+                    builder.query(&params)
+                }
+                _ if step.headers().iter().any(|(name, _)| name.eq_ignore_ascii_case("content-type")) => {
+                    builder.body(payload.to_string())
+                }
+                _ => builder.header("content-type", "application/json").body(payload.to_string()),
+            };
+        } else if let Some(body) = step.body.as_deref() {
+            builder = builder.body(fill(&scope, "api_step.body", body)?);
+        }
+        Ok(builder.build()?)
+    }
+
+    /// send - send the request and read the whole response
+    async fn send(&self, request: Request) -> EngineResult<ApiResponse> {
+        let start = Instant::now();
+        let res = self.http.execute(request).await?;
+        let status = res.status().as_u16() as i32;
+        let mut headers = Map::new();
+        for (name, value) in res.headers() {
+            let value = String::from_utf8_lossy(value.as_bytes()).to_string();
+            match headers.get_mut(name.as_str()) {
+                Some(Value::String(existing)) => existing.push_str(&format!(", {value}")),
+                _ => {
+                    headers.insert(name.to_string(), Value::String(value));
+                }
+            }
+        }
+        let body = res.text().await?;
+        Ok(ApiResponse { status, headers, body, latency: start.elapsed().as_millis() as i32 })
+    }
+
+    /// call - send the request of the step and log the response against the item log,
+    /// the response with the unexpected status fails the step
+    async fn call(&self, step: &step::Model, log: &ItemLog) -> EngineResult<ApiResponse> {
+        let request = self.request(step)?;
+        let mut api_log = response::ActiveModel {
+            id: NotSet,
+            item_log_id: Set(log.id),
+            method: Set(request.method().to_string()),
+            url: Set(request.url().to_string()),
+            status: Set(None),
+            headers: Set(None),
+            body: Set(None),
+            latency: Set(0),
+            error: Set(None),
+            created_at: Set(chrono::Utc::now().into()),
+        };
+        let start = Instant::now();
+        let result = with_timeout("API Step", step.timeout, self.send(request)).await;
+        let result = match result {
+            Ok(res) => {
+                api_log.status = Set(Some(res.status));
+                api_log.headers = Set(Some(Value::Object(res.headers.clone())));
+                api_log.body = Set(Some(res.body.clone()));
+                api_log.latency = Set(res.latency);
+                match step.expected_status {
+                    Some(expected) if expected != res.status => Err(EngineError::AssertionFailed(
+                        format!("expected status {expected} but got {}", res.status),
+                    )),
+                    _ => Ok(res),
+                }
+            }
+            Err(err) => {
+                api_log.latency = Set(start.elapsed().as_millis() as i32);
+                api_log.error = Set(Some(err.to_string()));
+                Err(err)
+            }
+        };
+        api_log.insert(self.db).await?;
+        match result {
+            Ok(res) if step.graphql.is_some() => match graphql::errors(&res.body) {
+                Some(errors) => Err(EngineError::AssertionFailed(errors)),
+                None => Ok(res),
+            },
+            result => result,
+        }
+    }
+
+    /// assert - run the assertions of the step on the response, each with its own item log,
+    /// the extracted values are stored in the runtime variables
+    async fn assert(&self, step: &step::Model, res: &ApiResponse, er: &ExecutionRequest,
+                    log: &ItemLog) -> EngineResult<()> {
+        let assertions = step.find_related(assertion::Entity)
+            .order_by_asc(assertion::Column::ExecutionOrder)
+            .all(self.db).await?;
+        if assertions.is_empty() {
+            return Ok(());
+        }
+        let schema = match (step.spec_id, step.operation_id.as_deref()) {
+            (Some(spec_id), Some(operation_id)) => spec::Entity::find_by_id(spec_id)
+                .one(self.db).await?
+                .and_then(|spec| operation_schema(&spec.document, operation_id, res.status)),
+            _ => None,
+        };
+        let mut failures = vec![];
+        for assertion in assertions.iter() {
+            let mut log_am = new(er.ref_id, ItemLogType::Assertion, assertion.id, Some(log.id))
+                .save(self.db).await?;
+            let start = chrono::Utc::now();
+            let result = self.check(assertion, res, schema.as_ref());
+            if let Err(err) = &result {
+                info!("[{er}] Assertion {id} of API Step {step_id} failed - {err}", er=er.ref_id,
+                      id = assertion.id, step_id = step.id);
+                failures.push(format!("{:?} {}", assertion.kind, err));
+            }
+            log_am.execution_time = Set((chrono::Utc::now() - start).num_milliseconds() as i32);
+            log_am.status = Set(match result {
+                Ok(_) => ItemLogStatus::Success,
+                Err(_) => ItemLogStatus::Failed,
+            });
+            log_am.finished_at = Set(chrono::Utc::now().into());
+            log_am.save(self.db).await?;
+        }
+        if !failures.is_empty() {
+            return Err(EngineError::AssertionFailed(failures.join("; ")));
+        }
+        Ok(())
+    }
+
+    /// check - evaluate the assertion on the response with the expected value resolved,
+    /// the extracted value is stored in the runtime variables
+    fn check(&self, assertion: &assertion::Model, res: &ApiResponse, schema: Option<&Value>) -> Result<(), String> {
+        let expected = assertion.expected.as_deref()
+            .map(|expected| self.variables.scope().resolve(expected))
+            .transpose()
+            .map_err(|missing| format!("unresolved placeholder(s) {missing:?} in expected value"))?;
+        let value = evaluate(&assertion::Model { expected, ..assertion.clone() }, res, schema)?;
+        let variable = assertion.variable.as_deref().map(str::trim).filter(|v| !v.is_empty());
+        if let (Some(variable), Some(value)) = (variable, value) {
+            self.variables.set_json(variable, &value);
+        }
+        Ok(())
+    }
+
+    /// probe - send the request of the step without any log, for the load test where the
+    /// step is sent over and over by every virtual user
+    pub(crate) async fn probe(&self, step: &step::Model) -> EngineResult<ApiResponse> {
+        let request = self.request(step)?;
+        with_timeout("API Step", step.timeout, self.send(request)).await
+    }
+
+    /// verify - check the response of the probe against the expected status and the assertions
+    pub(crate) fn verify(&self, step: &step::Model, assertions: &[assertion::Model], document: Option<&Value>,
+                         res: &ApiResponse) -> EngineResult<()> {
+        if let Some(expected) = step.expected_status.filter(|expected| *expected != res.status) {
+            return Err(EngineError::AssertionFailed(format!("expected status {expected} but got {}", res.status)));
+        }
+        if let Some(errors) = step.graphql.as_ref().and_then(|_| graphql::errors(&res.body)) {
+            return Err(EngineError::AssertionFailed(errors));
+        }
+        let schema = match (document, step.operation_id.as_deref()) {
+            (Some(document), Some(operation_id)) => operation_schema(document, operation_id, res.status),
+            _ => None,
+        };
+        let failures: Vec<String> = assertions.iter()
+            .filter_map(|assertion| {
+                self.check(assertion, res, schema.as_ref()).err().map(|err| format!("{:?} {}", assertion.kind, err))
+            })
+            .collect();
+        if !failures.is_empty() {
+            return Err(EngineError::AssertionFailed(failures.join("; ")));
+        }
+        Ok(())
+    }
+
+    /// execute - run the API step by the step ID
+    pub async fn execute(&self, id: Uuid, er: &ExecutionRequest,
+                         log: Option<&ItemLog>) -> EngineResult<()> {
+        let step = step::Entity::find_by_id(id).one(self.db).await?
+            .ok_or(EngineError::MissingParameter("ApiStep".to_string(), id.into()))?;
+        let log_id = log.map(|l| l.id);
+        let mut log_am = new(er.ref_id, ItemLogType::ApiStep, id, log_id).save(self.db).await?;
+        if self.token.is_cancelled() {
+            info!("[{er}] Skipping API Step {step_id} - execution cancelled", er=er.ref_id, step_id = id);
+            log_am.status = Set(ItemLogStatus::Skipped);
+            log_am.finished_at = Set(chrono::Utc::now().into());
+            log_am.save(self.db).await?;
+            return Ok(());
+        }
+        info!("[{er}] Trigger API Step {step_id}", er=er.ref_id, step_id = id);
+        let start = chrono::Utc::now();
+        let log = log_am.clone().try_into_model()?;
+        let result = match self.call(&step, &log).await {
+            Ok(res) => {
+                info!("[{er}] API Step {step_id} responded {status} in {latency}ms", er=er.ref_id,
+                      step_id = id, status = res.status, latency = res.latency);
+                self.assert(&step, &res, er, &log).await.map(|_| res)
+            }
+            Err(err) => Err(err),
+        };
+
+        log_am.execution_time = Set((chrono::Utc::now() - start).num_milliseconds() as i32);
+        log_am.status = Set(match &result {
+            Ok(_) => ItemLogStatus::Success,
+            Err(err) => failed_status(err),
+        });
+        log_am.finished_at = Set(chrono::Utc::now().into());
+        log_am.save(self.db).await?;
+        result.map(|_| ())
+    }
+}
@@ -0,0 +1,143 @@
+use jsonschema::JSONSchema;
+use regex::Regex;
+use serde_json::{json, Value};
+use serde_json_path::JsonPath;
+
+use entity::api::assertion::{self, AssertionKind, MatchMode};
+
+use crate::controller::api::ApiResponse;
+use crate::import::openapi::resolve;
+
+/// text - value as the plain text, the strings without the quotes
+pub fn text(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn number(value: &str) -> Result<f64, String> {
+    value.trim().parse::<f64>().map_err(|_| format!("'{value}' is not a number"))
+}
+
+/// equals - the expected value is compared as json first and as the plain text next
+fn equals(actual: &Value, expected: &str) -> bool {
+    serde_json::from_str::<Value>(expected).map_or(false, |e| &e == actual) || text(actual) == expected
+}
+
+/// json_path - values matched by the JSONPath, a single match is given back as it is
+/// and the multiple matches as the array
+pub fn json_path(body: &Value, path: &str) -> Result<Option<Value>, String> {
+    let query = JsonPath::parse(path).map_err(|err| format!("Invalid JSONPath '{path}' - {err}"))?;
+    let nodes = query.query(body).all();
+    Ok(match nodes.len() {
+        0 => None,
+        1 => Some(nodes[0].clone()),
+        _ => Some(Value::Array(nodes.into_iter().cloned().collect())),
+    })
+}
+
+/// compare - check the actual value against the expected one with the match mode
+pub fn compare(mode: &MatchMode, actual: Option<&Value>, expected: Option<&str>) -> Result<(), String> {
+    let want = || expected.ok_or_else(|| format!("{mode:?} needs the expected value"));
+    let found = || actual.ok_or_else(|| "no value found".to_string());
+    let matched = match mode {
+        MatchMode::Exists => actual.map_or(false, |v| !v.is_null()),
+        MatchMode::NotExists => actual.map_or(true, Value::is_null),
+        MatchMode::Equals => equals(found()?, want()?),
+        MatchMode::NotEquals => actual.map_or(true, |a| !equals(a, expected.unwrap_or_default())),
+        MatchMode::Contains => {
+            let expected = want()?;
+            match found()? {
+                Value::Array(items) => items.iter().any(|item| equals(item, expected)),
+                Value::Object(map) => map.contains_key(expected),
+                value => text(value).contains(expected),
+            }
+        }
+        MatchMode::Regex => {
+            let pattern = want()?;
+            let regex = Regex::new(pattern).map_err(|err| format!("Invalid regex '{pattern}' - {err}"))?;
+            regex.is_match(&text(found()?))
+        }
+        MatchMode::GreaterThan => number(&text(found()?))? > number(want()?)?,
+        MatchMode::LessThan => number(&text(found()?))? < number(want()?)?,
+    };
+    if matched {
+        return Ok(());
+    }
+    let actual = actual.map_or("nothing".to_string(), text);
+    match expected {
+        Some(expected) => Err(format!("expected {mode:?} '{expected}' but got '{actual}'")),
+        None => Err(format!("expected {mode:?} but got '{actual}'")),
+    }
+}
+
+/// validate_schema - check the json against the JSON Schema, all the violations are reported
+pub fn validate_schema(schema: &Value, instance: &Value) -> Result<(), String> {
+    let compiled = JSONSchema::compile(schema).map_err(|err| format!("Invalid schema - {err}"))?;
+    if let Err(errors) = compiled.validate(instance) {
+        let messages: Vec<String> = errors
+            .map(|err| format!("{err} at '{}'", err.instance_path))
+            .collect();
+        return Err(messages.join("; "));
+    }
+    Ok(())
+}
+
+/// operation_schema - json schema of the response of the OpenAPI operation for the status,
+/// the components of the document are kept along so the `$ref` still resolve
+pub fn operation_schema(document: &Value, operation_id: &str, status: i32) -> Option<Value> {
+    let operation = document.get("paths")?.as_object()?.iter().find_map(|(path, item)| {
+        item.as_object()?.iter()
+            .find(|(method, operation)| {
+                operation.get("operationId").and_then(Value::as_str) == Some(operation_id)
+                    || format!("{method} {path}") == operation_id
+            })
+            .map(|(_, operation)| operation)
+    })?;
+    let responses = operation.get("responses")?;
+    let response = responses.get(status.to_string())
+        .or_else(|| responses.get(format!("{}XX", status / 100)))
+        .or_else(|| responses.get("default"))?;
+    let content = resolve(document, response).get("content")?.as_object()?;
+    let (_, media) = content.iter().find(|(content_type, _)| content_type.contains("json"))?;
+    let mut schema = json!({"allOf": [media.get("schema")?]});
+    if let Some(components) = document.get("components") {
+        schema["components"] = components.clone();
+    }
+    Some(schema)
+}
+
+/// evaluate - run the assertion on the response and give back the value it looked at,
+/// the schema is the response schema of the OpenAPI operation if the step has one
+pub fn evaluate(assertion: &assertion::Model, response: &ApiResponse,
+                schema: Option<&Value>) -> Result<Option<Value>, String> {
+    let body = || serde_json::from_str::<Value>(&response.body)
+        .map_err(|err| format!("Response body is not json - {err}"));
+    let expression = || assertion.expression.clone()
+        .filter(|e| !e.trim().is_empty())
+        .ok_or_else(|| format!("{:?} assertion is missing the expression", assertion.kind));
+    let actual = match assertion.kind {
+        AssertionKind::Status => Some(json!(response.status)),
+        AssertionKind::JsonPath => json_path(&body()?, &expression()?)?,
+        AssertionKind::JsonPointer => body()?.pointer(&expression()?).cloned(),
+        AssertionKind::Header => response.headers.get(&expression()?.to_lowercase()).cloned(),
+        AssertionKind::ResponseTime => Some(json!(response.latency)),
+        AssertionKind::JsonSchema => {
+            let schema = match &assertion.expected {
+                Some(expected) => serde_json::from_str(expected)
+                    .map_err(|err| format!("Invalid schema - {err}"))?,
+                None => schema.cloned().ok_or("No schema given and no OpenAPI schema for the response")?,
+            };
+            validate_schema(&schema, &body()?)?;
+            return Ok(None);
+        }
+    };
+    let mode = assertion.match_mode.clone().unwrap_or(match (&assertion.kind, &assertion.expected) {
+        (AssertionKind::ResponseTime, Some(_)) => MatchMode::LessThan,
+        (_, Some(_)) => MatchMode::Equals,
+        (_, None) => MatchMode::Exists,
+    });
+    compare(&mode, actual.as_ref(), assertion.expected.as_deref())?;
+    Ok(actual)
+}
@@ -1,10 +1,12 @@
+use std::sync::Arc;
+
 use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder, TryIntoModel};
 use sea_orm::ActiveValue::Set;
 use sea_orm::prelude::Uuid;
 use tracing::{debug, info};
 
 use cerium::client::Client;
-use cerium::client::driver::web::WebDriver;
+use cerium::client::driver::Driver;
 use entity::prelude::case::Entity;
 use entity::prelude::case_block;
 use entity::prelude::case_block::{BlockKind, BlockType};
@@ -14,21 +16,70 @@ use entity::test::ui::log::{item_log, ItemLog};
 use entity::test::ui::log::item_log::{ItemLogStatus, ItemLogType, new};
 
 use crate::controller::action::ActionController;
+#[cfg(feature = "api-automation")]
+use crate::controller::api::ApiController;
+#[cfg(feature = "api-automation")]
+use crate::controller::channel::{ChannelController, Channels};
+#[cfg(feature = "api-automation")]
+use crate::controller::sql::{SqlConnections, SqlController};
+use crate::controller::{close_running, failed_status, load_profile, with_timeout};
+use crate::controller::data::Variables;
 use crate::error::{EngineError, EngineResult};
+use crate::server::registry::ExecutionToken;
 
 pub struct CaseController<'ccl> {
     db: &'ccl DatabaseTransaction,
     cli: Client,
-    drive: WebDriver,
+    drive: Arc<dyn Driver>,
+    /// runtime variables extracted by the steps of the execution
+    variables: Variables,
+    /// WebSocket and Server-Sent Events connections opened by the steps of the execution
+    #[cfg(feature = "api-automation")]
+    channels: Channels,
+    /// database connections of the SQL steps of the execution
+    #[cfg(feature = "api-automation")]
+    connections: SqlConnections,
+    token: ExecutionToken,
 }
 
 impl<'ccl> CaseController<'ccl> {
     pub fn new(
         db: &'ccl DatabaseTransaction,
-        drive: WebDriver,
+        drive: Arc<dyn Driver>,
         cli: Client,
+        token: ExecutionToken,
     ) -> CaseController<'ccl> {
-        Self { db, drive, cli }
+        Self {
+            db,
+            drive,
+            cli,
+            variables: Variables::new(),
+            #[cfg(feature = "api-automation")]
+            channels: Channels::new(),
+            #[cfg(feature = "api-automation")]
+            connections: SqlConnections::new(),
+            token,
+        }
+    }
+
+    /// with_variables - share the runtime variables of the execution with the controller
+    pub fn with_variables(mut self, variables: Variables) -> Self {
+        self.variables = variables;
+        self
+    }
+
+    /// with_channels - share the connections of the execution with the controller
+    #[cfg(feature = "api-automation")]
+    pub fn with_channels(mut self, channels: Channels) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// with_connections - share the SQL connections of the execution with the controller
+    #[cfg(feature = "api-automation")]
+    pub fn with_connections(mut self, connections: SqlConnections) -> Self {
+        self.connections = connections;
+        self
     }
 
 
@@ -59,13 +110,18 @@ impl<'ccl> CaseController<'ccl> {
         let case = Entity::find_by_id(id).one(self.db).await?
             .ok_or(EngineError::MissingParameter("ActionGroup".to_string(), id.into()))?;
         let log = log_am.clone().try_into_model()?;
-        self.process(&case, er, Some(&log)).await?;
+        load_profile(self.db, er, &self.variables).await?;
+        let result = self.process(&case, er, Some(&log)).await;
 
         log_am.execution_time = Set((chrono::Utc::now() - start).num_milliseconds() as i32);
-        log_am.status = Set(ItemLogStatus::Success);
+        log_am.status = Set(match &result {
+            Ok(_) if self.token.is_cancelled() => ItemLogStatus::Skipped,
+            Ok(_) => ItemLogStatus::Success,
+            Err(err) => failed_status(err),
+        });
         log_am.finished_at = Set(chrono::Utc::now().into());
         log_am.save(self.db).await?;
-        Ok(())
+        result
     }
 
 
@@ -100,11 +156,41 @@ impl<'ccl> CaseController<'ccl> {
         Ok(())
     }
 
-    /// switch_block - function to switch the block based on the type and kind of the block
+    /// switch_block - run the block within the timeout of the block, the logs left running
+    /// by the dropped block are closed and the block gets a timed out log of its own
     async fn switch_block(&self, block: &case_block::Model, er: &ExecutionRequest,
                           log: Option<&ItemLog>) -> EngineResult<()> {
         debug!("Processing Block - {:#?}", block);
-        let result = match block.kind {
+        let start = chrono::Utc::now();
+        let result = with_timeout("Case Block", block.timeout, self.process_block(block, er, log)).await;
+        if let Err(EngineError::Timeout(item, _)) = &result {
+            if item == "Case Block" {
+                close_running(self.db, er, start.into(), ItemLogStatus::TimedOut).await?;
+                let mut log_am = new(er.ref_id, Self::log_type(block), block.id, log.map(|l| l.id));
+                log_am.execution_time = Set((chrono::Utc::now() - start).num_milliseconds() as i32);
+                log_am.status = Set(ItemLogStatus::TimedOut);
+                log_am.finished_at = Set(chrono::Utc::now().into());
+                log_am.insert(self.db).await?;
+            }
+        }
+        result
+    }
+
+    /// log_type - log type of the item referenced by the block
+    fn log_type(block: &case_block::Model) -> ItemLogType {
+        match block.type_field {
+            BlockType::Assertion => ItemLogType::Assertion,
+            BlockType::ApiStep => ItemLogType::ApiStep,
+            BlockType::ChannelStep => ItemLogType::ChannelStep,
+            BlockType::SqlStep => ItemLogType::SqlStep,
+            _ => ItemLogType::ActionGroup,
+        }
+    }
+
+    /// process_block - function to switch the block based on the type and kind of the block
+    async fn process_block(&self, block: &case_block::Model, er: &ExecutionRequest,
+                           log: Option<&ItemLog>) -> EngineResult<()> {
+        match block.kind {
             // BlockKind::Loop => match block.type_field {
             //     BlockType::InMemory => self.process_action_group(block),
             //     BlockType::DataTable => self.process_action_group(block),
@@ -118,14 +204,15 @@ impl<'ccl> CaseController<'ccl> {
             //     _ => todo!("Need to raise a error from here since non other supported"),
             // },
             BlockKind::Reference => match block.type_field {
-                BlockType::ActionGroup => self.process_action_group(block, er, log),
-                BlockType::Assertion => self.process_action_group(block, er, log),
-                _ => todo!("Need to raise a error from here since non other supported"),
+                BlockType::ActionGroup => self.process_action_group(block, er, log).await,
+                BlockType::Assertion => self.process_action_group(block, er, log).await,
+                BlockType::ApiStep => self.process_api_step(block, er, log).await,
+                BlockType::ChannelStep => self.process_channel_step(block, er, log).await,
+                BlockType::SqlStep => self.process_sql_step(block, er, log).await,
+                _ => Err(EngineError::Unsupported(format!("block type {:?}", block.type_field))),
             },
-            _ => return Ok(())
+            _ => Ok(())
         }
-            .await?;
-        Ok(())
     }
 
     async fn process_in_memory_loop(&self, block: &case_block::Model, er: &ExecutionRequest, log: Option<&ItemLog>) -> EngineResult<()> {
@@ -140,12 +227,67 @@ impl<'ccl> CaseController<'ccl> {
         Ok(())
     }
 
+    /// process_api_step - send the request of the API step referenced by the block
+    #[cfg(feature = "api-automation")]
+    async fn process_api_step(&self, block: &case_block::Model, er: &ExecutionRequest,
+                              log: Option<&ItemLog>) -> EngineResult<()> {
+        let step_id = block.reference
+            .ok_or(EngineError::MissingParameter("block.reference".to_string(), block.id.into()))?;
+        ApiController::new(self.db, self.variables.clone(), self.token.clone())
+            .execute(step_id, er, log)
+            .await
+    }
+
+    #[cfg(not(feature = "api-automation"))]
+    async fn process_api_step(&self, block: &case_block::Model, _er: &ExecutionRequest,
+                              _log: Option<&ItemLog>) -> EngineResult<()> {
+        Err(EngineError::Unsupported(format!("API step block {} needs the api-automation feature", block.id)))
+    }
+
+    /// process_channel_step - open, send, wait or close on the connection of the channel step
+    #[cfg(feature = "api-automation")]
+    async fn process_channel_step(&self, block: &case_block::Model, er: &ExecutionRequest,
+                                  log: Option<&ItemLog>) -> EngineResult<()> {
+        let step_id = block.reference
+            .ok_or(EngineError::MissingParameter("block.reference".to_string(), block.id.into()))?;
+        ChannelController::new(self.db, self.variables.clone(), self.channels.clone(), self.token.clone())
+            .execute(step_id, er, log)
+            .await
+    }
+
+    #[cfg(not(feature = "api-automation"))]
+    async fn process_channel_step(&self, block: &case_block::Model, _er: &ExecutionRequest,
+                                  _log: Option<&ItemLog>) -> EngineResult<()> {
+        Err(EngineError::Unsupported(format!("Channel step block {} needs the api-automation feature", block.id)))
+    }
+
+    /// process_sql_step - run the query of the SQL step referenced by the block
+    #[cfg(feature = "api-automation")]
+    async fn process_sql_step(&self, block: &case_block::Model, er: &ExecutionRequest,
+                              log: Option<&ItemLog>) -> EngineResult<()> {
+        let step_id = block.reference
+            .ok_or(EngineError::MissingParameter("block.reference".to_string(), block.id.into()))?;
+        SqlController::new(self.db, self.variables.clone(), self.connections.clone(), self.token.clone())
+            .execute(step_id, er, log)
+            .await
+    }
+
+    #[cfg(not(feature = "api-automation"))]
+    async fn process_sql_step(&self, block: &case_block::Model, _er: &ExecutionRequest,
+                              _log: Option<&ItemLog>) -> EngineResult<()> {
+        Err(EngineError::Unsupported(format!("SQL step block {} needs the api-automation feature", block.id)))
+    }
+
     async fn process_action_group(&self, block: &case_block::Model, er: &ExecutionRequest,
                                   log: Option<&ItemLog>) -> EngineResult<()> {
         info!("Starting processing {block_id} ", block_id = block.id);
-        let controller = ActionController::new(self.db, self.drive.clone(), self.cli.clone());
+        let controller = ActionController::new(self.db, self.drive.clone(), self.cli.clone(),
+                                               self.token.clone())
+            .with_variables(self.variables.clone());
+        let reference = block.reference
+            .ok_or(EngineError::MissingParameter("block.reference".to_string(), block.id.into()))?;
         let result = controller
-            .execute(block.reference.unwrap(), er, log)
+            .execute(reference, er, log)
             .await?;
         Ok(result)
     }
@@ -0,0 +1,352 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use futures_util::{SinkExt, StreamExt};
+use regex::Regex;
+use reqwest::Client as HttpClient;
+use sea_orm::{ActiveModelTrait, DatabaseTransaction, EntityTrait};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::Uuid;
+use serde_json::Value;
+use tokio::sync::{mpsc, Notify};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
+use tokio_tungstenite::tungstenite::Message;
+use tracing::info;
+
+use entity::api::assertion::MatchMode;
+use entity::api::channel::{self, ChannelAction, ChannelMatch, ChannelProtocol};
+use entity::test::ui::ExecutionRequest;
+use entity::test::ui::log::item_log::{ItemLogStatus, ItemLogType, new};
+use entity::test::ui::log::ItemLog;
+
+use crate::controller::{failed_status, with_timeout};
+use crate::controller::assertion::{compare, json_path, text};
+use crate::controller::data::{fill, Variables};
+use crate::error::{EngineError, EngineResult};
+use crate::server::registry::ExecutionToken;
+
+/// DEFAULT_WAIT - milliseconds the wait step waits for the message when it has no timeout
+pub const DEFAULT_WAIT: i32 = 10_000;
+
+/// CLOSE_TIMEOUT - time given to the WebSocket Close frame before the connection is dropped
+const CLOSE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Inbox - messages received on the connection that are not taken by a wait step yet
+#[derive(Debug, Default)]
+struct Inbox {
+    messages: VecDeque<String>,
+    closed: bool,
+}
+
+/// Connection - open WebSocket or Server-Sent Events connection, the messages are received
+/// in the background so the message pushed while the UI actions run is not missed, the
+/// dropped WebSocket is closed with the Close frame
+#[derive(Debug)]
+pub struct Connection {
+    outgoing: Option<mpsc::UnboundedSender<String>>,
+    inbox: Arc<Mutex<Inbox>>,
+    notify: Arc<Notify>,
+    handle: JoinHandle<()>,
+}
+
+/// receive - keep the message in the inbox and wake up the waiting step
+fn receive(inbox: &Mutex<Inbox>, notify: &Notify, message: String) {
+    inbox.lock().unwrap().messages.push_back(message);
+    notify.notify_one();
+}
+
+/// event_data - data of the Server-Sent Event, the multiple `data:` lines are joined
+fn event_data(event: &str) -> Option<String> {
+    let data: Vec<&str> = event.lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|data| data.strip_prefix(' ').unwrap_or(data))
+        .collect();
+    (!data.is_empty()).then(|| data.join("\n"))
+}
+
+impl Connection {
+    /// websocket - open the WebSocket with the headers on the handshake request
+    pub async fn websocket(url: &str, headers: &[(String, String)]) -> EngineResult<Self> {
+        let mut request = url.into_client_request()?;
+        for (name, value) in headers {
+            let header = HeaderName::from_bytes(name.as_bytes()).ok()
+                .zip(HeaderValue::from_str(value).ok())
+                .ok_or_else(|| EngineError::Unsupported(format!("WebSocket handshake header '{name}'")))?;
+            request.headers_mut().insert(header.0, header.1);
+        }
+        let (stream, _) = connect_async(request).await?;
+        let (mut sink, mut source) = stream.split();
+        let (outgoing, mut pending) = mpsc::unbounded_channel::<String>();
+        let (inbox, notify) = (Arc::new(Mutex::new(Inbox::default())), Arc::new(Notify::new()));
+        let (received, signal) = (inbox.clone(), notify.clone());
+        let handle = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    message = pending.recv() => match message {
+                        Some(message) => {
+                            if sink.send(Message::Text(message)).await.is_err() {
+                                break;
+                            }
+                        }
+                        None => {
+                            let _ = tokio::time::timeout(CLOSE_TIMEOUT, sink.send(Message::Close(None))).await;
+                            break;
+                        }
+                    },
+                    message = source.next() => match message {
+                        Some(Ok(Message::Text(message))) => receive(&received, &signal, message),
+                        Some(Ok(Message::Binary(data))) => {
+                            receive(&received, &signal, String::from_utf8_lossy(&data).to_string())
+                        }
+                        Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                        Some(Ok(_)) => {}
+                    },
+                }
+            }
+            received.lock().unwrap().closed = true;
+            signal.notify_one();
+        });
+        Ok(Self { outgoing: Some(outgoing), inbox, notify, handle })
+    }
+
+    /// sse - open the Server-Sent Events stream, the data of every event is a message
+    pub async fn sse(http: &HttpClient, url: &str, headers: &[(String, String)]) -> EngineResult<Self> {
+        let mut builder = http.get(url).header("accept", "text/event-stream");
+        for (name, value) in headers {
+            builder = builder.header(name, value);
+        }
+        let mut res = builder.send().await?.error_for_status()?;
+        let (inbox, notify) = (Arc::new(Mutex::new(Inbox::default())), Arc::new(Notify::new()));
+        let (received, signal) = (inbox.clone(), notify.clone());
+        let handle = tokio::spawn(async move {
+            let mut buffer: Vec<u8> = vec![];
+            while let Ok(Some(chunk)) = res.chunk().await {
+                buffer.extend(chunk.iter().filter(|byte| **byte != b'\r'));
+                while let Some(end) = buffer.windows(2).position(|w| w == b"\n\n") {
+                    let event: Vec<u8> = buffer.drain(..end + 2).collect();
+                    if let Some(data) = event_data(&String::from_utf8_lossy(&event)) {
+                        receive(&received, &signal, data);
+                    }
+                }
+            }
+            received.lock().unwrap().closed = true;
+            signal.notify_one();
+        });
+        Ok(Self { outgoing: None, inbox, notify, handle })
+    }
+
+    /// send - send the text message, only the WebSocket can send
+    pub fn send(&self, message: String) -> EngineResult<()> {
+        let outgoing = self.outgoing.as_ref().ok_or_else(|| {
+            EngineError::Unsupported("Server-Sent Events connection can not send messages".to_string())
+        })?;
+        if self.inbox.lock().unwrap().closed || outgoing.send(message).is_err() {
+            return Err(EngineError::AssertionFailed("connection is closed".to_string()));
+        }
+        Ok(())
+    }
+
+    /// wait - wait up to the timeout in milliseconds for the message the matcher takes, the
+    /// message and the ones before it are dropped so the next wait only sees the newer messages
+    pub async fn wait(&self, timeout: i32, matcher: impl Fn(&str) -> Option<String>) -> EngineResult<String> {
+        let deadline = tokio::time::Instant::now() + std::time::Duration::from_millis(timeout.max(0) as u64);
+        loop {
+            {
+                let mut inbox = self.inbox.lock().unwrap();
+                let found = inbox.messages.iter().enumerate()
+                    .find_map(|(index, message)| matcher(message).map(|value| (index, value)));
+                if let Some((index, value)) = found {
+                    inbox.messages.drain(..=index);
+                    return Ok(value);
+                }
+                if inbox.closed {
+                    return Err(EngineError::AssertionFailed(
+                        "connection closed before a matching message was received".to_string()
+                    ));
+                }
+            }
+            if tokio::time::timeout_at(deadline, self.notify.notified()).await.is_err() {
+                return Err(EngineError::Timeout("Channel message".to_string(), timeout));
+            }
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // the WebSocket task sends the Close frame once the outgoing sender is dropped
+        if self.outgoing.take().is_none() {
+            self.handle.abort();
+        }
+    }
+}
+
+/// Channels - connections opened by the steps of the execution by the connection name,
+/// shared by all the steps so the connection stays open across the UI actions
+#[derive(Debug, Clone, Default)]
+pub struct Channels(Arc<Mutex<HashMap<String, Arc<Connection>>>>);
+
+impl Channels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// open - keep the connection by the name, the earlier connection with the name is closed
+    pub fn open(&self, name: impl Into<String>, connection: Connection) {
+        self.0.lock().unwrap().insert(name.into(), Arc::new(connection));
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<Connection>> {
+        self.0.lock().unwrap().get(name).cloned()
+    }
+
+    /// close - close the connection by the name, false when there is none
+    pub fn close(&self, name: &str) -> bool {
+        self.0.lock().unwrap().remove(name).is_some()
+    }
+
+    /// close_all - close all the connections once the execution is done
+    pub fn close_all(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
+
+/// Matcher - condition of the wait step, the value of the matching message is given back
+#[derive(Debug)]
+pub enum Matcher {
+    JsonPath { path: String, expected: Option<String> },
+    Regex(Regex),
+}
+
+impl Matcher {
+    pub fn new(kind: &ChannelMatch, expression: &str, expected: Option<String>) -> EngineResult<Self> {
+        let invalid = |err: String| EngineError::AssertionFailed(err);
+        match kind {
+            ChannelMatch::JsonPath => {
+                json_path(&Value::Null, expression).map_err(invalid)?;
+                Ok(Self::JsonPath { path: expression.to_string(), expected })
+            }
+            ChannelMatch::Regex => Regex::new(expression)
+                .map(Self::Regex)
+                .map_err(|err| invalid(format!("Invalid regex '{expression}' - {err}"))),
+        }
+    }
+
+    /// value - value of the message when it matches, the JSONPath value or the first
+    /// capture group of the regex (the whole match when it has no group)
+    pub fn value(&self, message: &str) -> Option<String> {
+        match self {
+            Self::JsonPath { path, expected } => {
+                let message = serde_json::from_str::<Value>(message).ok()?;
+                let value = json_path(&message, path).ok().flatten()?;
+                match expected {
+                    Some(expected) => compare(&MatchMode::Equals, Some(&value), Some(expected)).ok()?,
+                    None => compare(&MatchMode::Exists, Some(&value), None).ok()?,
+                }
+                Some(text(&value))
+            }
+            Self::Regex(regex) => {
+                let captures = regex.captures(message)?;
+                captures.get(1).or_else(|| captures.get(0)).map(|m| m.as_str().to_string())
+            }
+        }
+    }
+}
+
+pub struct ChannelController<'ccl> {
+    db: &'ccl DatabaseTransaction,
+    http: HttpClient,
+    variables: Variables,
+    channels: Channels,
+    token: ExecutionToken,
+}
+
+impl<'ccl> ChannelController<'ccl> {
+    pub fn new(db: &'ccl DatabaseTransaction, variables: Variables, channels: Channels,
+               token: ExecutionToken) -> ChannelController<'ccl> {
+        Self { db, http: HttpClient::new(), variables, channels, token }
+    }
+
+    /// connection - connection of the step opened by the earlier open step
+    fn connection(&self, step: &channel::Model) -> EngineResult<Arc<Connection>> {
+        self.channels.get(&step.connection)
+            .ok_or_else(|| EngineError::MissingParameter("channel.connection".to_string(), step.connection.clone()))
+    }
+
+    /// run - open, send, wait or close on the connection of the step
+    async fn run(&self, step: &channel::Model, er: &ExecutionRequest) -> EngineResult<()> {
+        step.check().map_err(EngineError::Unsupported)?;
+        let scope = self.variables.scope();
+        match step.action {
+            ChannelAction::Open => {
+                let url = fill(&scope, "channel_step.url", step.url.as_deref().unwrap_or_default())?;
+                let mut headers = vec![];
+                for (name, value) in step.headers() {
+                    headers.push((name, fill(&scope, "channel_step.headers", &value)?));
+                }
+                let open = async {
+                    match step.protocol {
+                        ChannelProtocol::WebSocket => Connection::websocket(&url, &headers).await,
+                        ChannelProtocol::Sse => Connection::sse(&self.http, &url, &headers).await,
+                    }
+                };
+                let connection = with_timeout("Channel Open", step.timeout, open).await?;
+                info!("[{er}] Opened {protocol:?} connection '{name}' to {url}", er = er.ref_id,
+                      protocol = step.protocol, name = step.connection);
+                self.channels.open(&step.connection, connection);
+            }
+            ChannelAction::Send => {
+                let message = fill(&scope, "channel_step.message", step.message.as_deref().unwrap_or_default())?;
+                self.connection(step)?.send(message)?;
+            }
+            ChannelAction::Wait => {
+                let expected = step.expected.as_deref()
+                    .map(|expected| fill(&scope, "channel_step.expected", expected))
+                    .transpose()?;
+                let kind = step.match_kind.as_ref().unwrap_or(&ChannelMatch::Regex);
+                let matcher = Matcher::new(kind, step.expression.as_deref().unwrap_or_default(), expected)?;
+                let timeout = step.timeout.filter(|timeout| *timeout > 0).unwrap_or(DEFAULT_WAIT);
+                let value = self.connection(step)?.wait(timeout, |message| matcher.value(message)).await?;
+                info!("[{er}] Message matched on connection '{name}'", er = er.ref_id, name = step.connection);
+                if let Some(variable) = step.variable.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+                    self.variables.set(variable, value);
+                }
+            }
+            ChannelAction::Close => {
+                self.channels.close(&step.connection);
+            }
+        }
+        Ok(())
+    }
+
+    /// execute - run the channel step by the step ID
+    pub async fn execute(&self, id: Uuid, er: &ExecutionRequest,
+                         log: Option<&ItemLog>) -> EngineResult<()> {
+        let step = channel::Entity::find_by_id(id).one(self.db).await?
+            .ok_or(EngineError::MissingParameter("ChannelStep".to_string(), id.into()))?;
+        let log_id = log.map(|l| l.id);
+        let mut log_am = new(er.ref_id, ItemLogType::ChannelStep, id, log_id).save(self.db).await?;
+        if self.token.is_cancelled() {
+            info!("[{er}] Skipping Channel Step {step_id} - execution cancelled", er=er.ref_id, step_id = id);
+            log_am.status = Set(ItemLogStatus::Skipped);
+            log_am.finished_at = Set(chrono::Utc::now().into());
+            log_am.save(self.db).await?;
+            return Ok(());
+        }
+        info!("[{er}] Trigger Channel Step {step_id}", er=er.ref_id, step_id = id);
+        let start = chrono::Utc::now();
+        let result = self.run(&step, er).await;
+
+        log_am.execution_time = Set((chrono::Utc::now() - start).num_milliseconds() as i32);
+        log_am.status = Set(match &result {
+            Ok(_) => ItemLogStatus::Success,
+            Err(err) => failed_status(err),
+        });
+        log_am.finished_at = Set(chrono::Utc::now().into());
+        log_am.save(self.db).await?;
+        result
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+use crate::error::{EngineError, EngineResult};
+
+/// placeholders - get all the `${key}` placeholder keys in the value
+pub fn placeholders(value: &str) -> Vec<String> {
+    let mut keys = vec![];
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let after = &rest[start + 2..];
+        match after.find('}') {
+            Some(end) => {
+                keys.push(after[..end].trim().to_string());
+                rest = &after[end + 1..];
+            }
+            None => break,
+        }
+    }
+    keys
+}
+
+/// fill - resolve the `${key}` placeholders of the step field with the runtime variables
+pub(crate) fn fill(scope: &DataScope, field: &str, value: &str) -> EngineResult<String> {
+    scope.resolve(value).map_err(|missing| {
+        EngineError::MissingParameter(format!("{field} placeholder(s)"), missing.join(", "))
+    })
+}
+
+/// DataScope - values visible for the step while resolving the `${key}` placeholders,
+/// the inner block scope will shadow the outer one
+#[derive(Debug, Clone, Default)]
+pub struct DataScope {
+    values: HashMap<String, String>,
+    /// keys of the values stored as json by `insert_json`
+    json: HashSet<String>,
+}
+
+impl DataScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        self.json.remove(&key);
+        self.values.insert(key, value.into());
+    }
+
+    /// insert_json - store the json value, the string goes in as the plain text
+    pub fn insert_json(&mut self, key: impl Into<String>, value: &Value) {
+        let key = key.into();
+        match value {
+            Value::String(text) => self.insert(key, text.clone()),
+            value => {
+                self.values.insert(key.clone(), value.to_string());
+                self.json.insert(key);
+            }
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.values.get(key)
+    }
+
+    /// get_json - json value of the key, only for the value stored by `insert_json`
+    pub fn get_json(&self, key: &str) -> Option<Value> {
+        if !self.json.contains(key) {
+            return None;
+        }
+        self.get(key).and_then(|value| serde_json::from_str(value).ok())
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    /// missing - get the placeholder keys in the value that are not in the scope
+    pub fn missing(&self, value: &str) -> Vec<String> {
+        placeholders(value)
+            .into_iter()
+            .filter(|key| !self.contains(key))
+            .collect()
+    }
+
+    /// resolve - replace all the placeholders in the value, the unresolved keys are sent back as error
+    pub fn resolve(&self, value: &str) -> Result<String, Vec<String>> {
+        let missing = self.missing(value);
+        if !missing.is_empty() {
+            return Err(missing);
+        }
+        let mut result = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(start) = rest.find("${") {
+            let after = &rest[start + 2..];
+            let Some(end) = after.find('}') else { break };
+            result.push_str(&rest[..start]);
+            if let Some(resolved) = self.get(after[..end].trim()) {
+                result.push_str(resolved);
+            }
+            rest = &after[end + 1..];
+        }
+        result.push_str(rest);
+        Ok(result)
+    }
+}
+
+/// Variables - runtime values extracted by the steps, shared by all the steps of the
+/// execution so the later steps can use them as `${key}`
+#[derive(Debug, Clone, Default)]
+pub struct Variables(Arc<Mutex<DataScope>>);
+
+impl Variables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.lock().unwrap().insert(key, value);
+    }
+
+    /// set_json - set the value extracted from the json, see [`DataScope::insert_json`]
+    pub fn set_json(&self, key: impl Into<String>, value: &Value) {
+        self.0.lock().unwrap().insert_json(key, value);
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.0.lock().unwrap().get(key).cloned()
+    }
+
+    /// scope - snapshot of the variables to resolve the placeholders with
+    pub fn scope(&self) -> DataScope {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+impl From<DataScope> for Variables {
+    fn from(scope: DataScope) -> Self {
+        Self(Arc::new(Mutex::new(scope)))
+    }
+}
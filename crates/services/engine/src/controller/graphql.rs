@@ -0,0 +1,76 @@
+use serde_json::{json, Value};
+
+use entity::api::step::GraphQl;
+
+use crate::controller::data::DataScope;
+
+/// variables - resolve the placeholders in the GraphQL variables, the value that is only the
+/// placeholder (`"${id}"`) of the variable stored as json keeps the json type so the numbers,
+/// booleans and objects go out as they are, the other values stay the strings
+pub fn variables(scope: &DataScope, value: &Value) -> Result<Value, Vec<String>> {
+    let resolved = match value {
+        Value::String(text) => {
+            let key = text.trim().strip_prefix("${").and_then(|key| key.strip_suffix('}'))
+                .filter(|key| !key.contains("${") && !key.contains('}'));
+            match key.and_then(|key| scope.get_json(key.trim())) {
+                Some(value) => value,
+                None => Value::String(scope.resolve(text)?),
+            }
+        }
+        Value::Array(items) => {
+            let mut resolved = Vec::with_capacity(items.len());
+            for item in items {
+                resolved.push(variables(scope, item)?);
+            }
+            Value::Array(resolved)
+        }
+        Value::Object(map) => {
+            let mut resolved = serde_json::Map::new();
+            for (key, item) in map {
+                resolved.insert(key.clone(), variables(scope, item)?);
+            }
+            Value::Object(resolved)
+        }
+        value => value.clone(),
+    };
+    Ok(resolved)
+}
+
+/// payload - json body of the GraphQL request
+pub fn payload(graphql: &GraphQl, variables: Option<Value>) -> Value {
+    let mut payload = json!({"query": graphql.query});
+    if let Some(variables) = variables {
+        payload["variables"] = variables;
+    }
+    if let Some(name) = graphql.operation_name.as_ref().filter(|name| !name.is_empty()) {
+        payload["operationName"] = json!(name);
+    }
+    payload
+}
+
+/// errors - messages of the `errors` array of the GraphQL response, the GraphQL server
+/// reports the failure with the `200 OK` status so the array decides the step result
+pub fn errors(body: &str) -> Option<String> {
+    let response: Value = match serde_json::from_str(body) {
+        Ok(response) => response,
+        Err(err) => return Some(format!("GraphQL response is not json - {err}")),
+    };
+    let errors = response.get("errors").and_then(Value::as_array).filter(|errors| !errors.is_empty())?;
+    let messages: Vec<String> = errors.iter()
+        .map(|error| {
+            let message = error.get("message").and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| error.to_string());
+            match error.get("path").and_then(Value::as_array) {
+                Some(path) => {
+                    let path: Vec<String> = path.iter()
+                        .map(|p| p.as_str().map(str::to_string).unwrap_or_else(|| p.to_string()))
+                        .collect();
+                    format!("{message} at '{}'", path.join("."))
+                }
+                None => message,
+            }
+        })
+        .collect();
+    Some(format!("GraphQL errors: {}", messages.join("; ")))
+}
@@ -0,0 +1,353 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use futures_util::future::join_all;
+use sea_orm::{ColumnTrait, DatabaseTransaction, EntityTrait, ModelTrait, QueryFilter, QueryOrder};
+use sea_orm::prelude::Uuid;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::info;
+
+use entity::api::{assertion, spec, step};
+use entity::prelude::case_block;
+use entity::prelude::case_block::{BlockKind, BlockType};
+use entity::test::ui::ExecutionRequest;
+
+use crate::controller::api::{ApiController, ApiResponse};
+use crate::controller::data::Variables;
+use crate::controller::load_profile;
+use crate::error::{EngineError, EngineResult};
+use crate::server::registry::ExecutionToken;
+
+/// MAX_USERS - upper limit of the virtual users of a single load test
+pub const MAX_USERS: u32 = 500;
+
+/// LoadConfig - load test of the API test case from the `load` of the request args,
+/// `"load": {"users": 10, "duration": 60000, "ramp_up": 5000}`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LoadConfig {
+    /// virtual users sending the steps of the test case at the same time
+    pub users: u32,
+    /// iterations of the test case for every virtual user, a single one when neither
+    /// the iterations nor the duration is given
+    #[serde(default)]
+    pub iterations: Option<u32>,
+    /// duration of the load test in milliseconds, the users keep iterating till the time is up
+    #[serde(default)]
+    pub duration: Option<u64>,
+    /// time in milliseconds to start all the virtual users, all start at once when missing
+    #[serde(default)]
+    pub ramp_up: Option<u64>,
+    /// error rate (0 to 1) above which the load test fails
+    #[serde(default)]
+    pub max_error_rate: Option<f64>,
+}
+
+impl LoadConfig {
+    pub fn from_request(er: &ExecutionRequest) -> EngineResult<Option<Self>> {
+        match er.args.as_ref().and_then(|args| args.get("load")) {
+            Some(load) if !load.is_null() => Ok(Some(serde_json::from_value(load.clone())?)),
+            _ => Ok(None),
+        }
+    }
+
+    /// check - the config has the users in the limit and the numbers make sense together
+    pub fn check(&self) -> Result<(), String> {
+        if self.users == 0 || self.users > MAX_USERS {
+            return Err(format!("users should be between 1 and {MAX_USERS}"));
+        }
+        if self.iterations == Some(0) {
+            return Err("iterations should be at least 1".to_string());
+        }
+        if self.duration == Some(0) {
+            return Err("duration should be at least 1ms".to_string());
+        }
+        if let (Some(ramp_up), Some(duration)) = (self.ramp_up, self.duration) {
+            if ramp_up >= duration {
+                return Err("ramp_up should be shorter than the duration".to_string());
+            }
+        }
+        if let Some(rate) = self.max_error_rate {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err("max_error_rate should be between 0 and 1".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// iterations - iterations every user runs, no limit when the load test runs for the duration
+    fn iterations(&self) -> Option<u64> {
+        match (self.iterations, self.duration) {
+            (Some(iterations), _) => Some(iterations as u64),
+            (None, Some(_)) => None,
+            (None, None) => Some(1),
+        }
+    }
+}
+
+/// percentile - nearest rank percentile of the sorted latencies
+pub fn percentile(sorted: &[u64], percent: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (percent / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Latency - latency distribution of the requests in milliseconds
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct Latency {
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub p50: u64,
+    pub p95: u64,
+    pub p99: u64,
+}
+
+impl Latency {
+    fn of(mut latencies: Vec<u64>) -> Self {
+        latencies.sort_unstable();
+        let mean = match latencies.len() {
+            0 => 0.0,
+            count => latencies.iter().sum::<u64>() as f64 / count as f64,
+        };
+        Self {
+            min: latencies.first().copied().unwrap_or_default(),
+            max: latencies.last().copied().unwrap_or_default(),
+            mean,
+            p50: percentile(&latencies, 50.0),
+            p95: percentile(&latencies, 95.0),
+            p99: percentile(&latencies, 99.0),
+        }
+    }
+}
+
+/// StepReport - requests of a single API step over all the virtual users
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StepReport {
+    pub step_id: Uuid,
+    pub name: String,
+    pub requests: u64,
+    pub errors: u64,
+    pub latency: Latency,
+}
+
+/// LoadReport - result of the load test, saved as the report of the execution request
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LoadReport {
+    pub users: u32,
+    /// iterations of the test case finished by all the users
+    pub iterations: u64,
+    pub requests: u64,
+    pub errors: u64,
+    /// failed requests over all the requests (0 to 1)
+    pub error_rate: f64,
+    /// requests per second
+    pub throughput: f64,
+    /// time taken by the load test in milliseconds
+    pub duration: u64,
+    pub latency: Latency,
+    pub steps: Vec<StepReport>,
+    /// count of the failed requests by the kind of the failure, the status for the unexpected
+    /// status (`status 404`) and the error kind for the others (`assertion`, `timeout`, ..)
+    pub failures: BTreeMap<String, u64>,
+    /// the users were stopped by the timeout of the execution, the report is partial
+    #[serde(default)]
+    pub timed_out: bool,
+}
+
+impl LoadReport {
+    /// check - the load test passes unless the error rate is above the max error rate
+    pub fn check(&self, max_error_rate: Option<f64>) -> Result<(), String> {
+        match max_error_rate {
+            Some(max) if self.error_rate > max => {
+                Err(format!("error rate {:.4} is above the max error rate {max}", self.error_rate))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// LoadStep - API step of the test case with the assertions and the OpenAPI document loaded once
+struct LoadStep {
+    step: step::Model,
+    assertions: Vec<assertion::Model>,
+    document: Option<Value>,
+}
+
+/// Sample - single request sent by a virtual user
+struct Sample {
+    step: usize,
+    latency: u64,
+    /// kind of the failure, see [`failure`]
+    error: Option<String>,
+}
+
+/// failure - kind of the failure the failed requests are counted by
+fn failure(step: &step::Model, res: Option<&ApiResponse>, err: &EngineError) -> String {
+    if let Some(res) = res.filter(|res| step.expected_status.is_some_and(|expected| expected != res.status)) {
+        return format!("status {}", res.status);
+    }
+    let kind = match err {
+        EngineError::AssertionFailed(_) => "assertion",
+        EngineError::Timeout(..) => "timeout",
+        EngineError::HttpError(err) if err.is_timeout() => "timeout",
+        EngineError::HttpError(err) if err.is_connect() => "connection",
+        EngineError::HttpError(_) => "http",
+        EngineError::MissingParameter(..) => "missing parameter",
+        _ => "error",
+    };
+    kind.to_string()
+}
+
+pub struct LoadController<'lcl> {
+    db: &'lcl DatabaseTransaction,
+    variables: Variables,
+    token: ExecutionToken,
+}
+
+impl<'lcl> LoadController<'lcl> {
+    pub fn new(db: &'lcl DatabaseTransaction, variables: Variables,
+               token: ExecutionToken) -> LoadController<'lcl> {
+        Self { db, variables, token }
+    }
+
+    /// steps - API steps of the test case in the execution order, the other blocks can't be
+    /// sent by the virtual users
+    async fn steps(&self, case_id: Uuid) -> EngineResult<Vec<LoadStep>> {
+        let blocks = case_block::Entity::find()
+            .filter(case_block::Column::CaseId.eq(case_id))
+            .order_by_asc(case_block::Column::ExecutionOrder)
+            .all(self.db)
+            .await?;
+        let mut steps = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            if block.kind != BlockKind::Reference || block.type_field != BlockType::ApiStep {
+                return Err(EngineError::Unsupported(
+                    format!("block {} in the load test, only the API steps can be load tested", block.id),
+                ));
+            }
+            let step_id = block.reference
+                .ok_or(EngineError::MissingParameter("block.reference".to_string(), block.id.into()))?;
+            let step = step::Entity::find_by_id(step_id).one(self.db).await?
+                .ok_or(EngineError::MissingParameter("ApiStep".to_string(), step_id.into()))?;
+            let assertions = step.find_related(assertion::Entity)
+                .order_by_asc(assertion::Column::ExecutionOrder)
+                .all(self.db).await?;
+            let document = match step.spec_id {
+                Some(spec_id) => spec::Entity::find_by_id(spec_id).one(self.db).await?.map(|spec| spec.document),
+                None => None,
+            };
+            steps.push(LoadStep { step, assertions, document });
+        }
+        if steps.is_empty() {
+            return Err(EngineError::MissingParameter("load.steps".to_string(), case_id.into()));
+        }
+        Ok(steps)
+    }
+
+    /// user - send the steps of the test case over and over as a single virtual user till the
+    /// iterations are done or the time is up, the values extracted by the steps are kept for
+    /// the next iterations of the same user, the request in flight at the timeout of the
+    /// execution is dropped
+    async fn user(&self, index: u32, steps: &[LoadStep], config: &LoadConfig, start: Instant,
+                  timeout: Option<Instant>) -> (u64, Vec<Sample>) {
+        let delay = config.ramp_up.unwrap_or_default() * index as u64 / config.users as u64;
+        tokio::time::sleep(Duration::from_millis(delay)).await;
+        let variables = Variables::from(self.variables.scope());
+        variables.set("vu", (index + 1).to_string());
+        let api = ApiController::new(self.db, variables.clone(), self.token.clone());
+        let deadline = config.duration.map(|ms| start + Duration::from_millis(ms));
+        let iterations = config.iterations();
+        let (mut done, mut samples) = (0, vec![]);
+        'iterations: while iterations.map_or(true, |iterations| done < iterations)
+            && deadline.map_or(true, |deadline| Instant::now() < deadline)
+            && timeout.map_or(true, |timeout| Instant::now() < timeout)
+            && !self.token.is_cancelled() {
+            variables.set("iteration", (done + 1).to_string());
+            for (step, load) in steps.iter().enumerate() {
+                let sent = Instant::now();
+                let probe = api.probe(&load.step);
+                let result = match timeout {
+                    Some(timeout) => match tokio::time::timeout_at(timeout.into(), probe).await {
+                        Ok(result) => result,
+                        Err(_) => break 'iterations,
+                    },
+                    None => probe.await,
+                };
+                let error = match result {
+                    Ok(res) => api.verify(&load.step, &load.assertions, load.document.as_ref(), &res)
+                        .err().map(|err| failure(&load.step, Some(&res), &err)),
+                    Err(err) => Some(failure(&load.step, None, &err)),
+                };
+                let failed = error.is_some();
+                samples.push(Sample { step, latency: sent.elapsed().as_millis() as u64, error });
+                // the next steps of the iteration may need the values of the failed step
+                if failed {
+                    break;
+                }
+            }
+            done += 1;
+        }
+        (done, samples)
+    }
+
+    /// run - send the API steps of the test case with the virtual users of the config
+    /// and give back the report of the requests, the users are stopped at the timeout of
+    /// the execution with the report of the requests done so far
+    pub async fn run(&self, case_id: Uuid, er: &ExecutionRequest, config: &LoadConfig) -> EngineResult<LoadReport> {
+        config.check().map_err(|err| EngineError::Unsupported(format!("load test - {err}")))?;
+        load_profile(self.db, er, &self.variables).await?;
+        let steps = self.steps(case_id).await?;
+        info!("[{er}] Starting Load Test for {case_id} with {users} virtual user(s)", er = er.ref_id,
+              users = config.users);
+        let start = Instant::now();
+        let timeout = er.timeout.filter(|ms| *ms > 0).map(|ms| start + Duration::from_millis(ms as u64));
+        let users = join_all((0..config.users).map(|index| self.user(index, &steps, config, start, timeout))).await;
+        let mut report = Self::report(config, &steps, users, start.elapsed());
+        report.timed_out = timeout.is_some_and(|timeout| Instant::now() >= timeout);
+        info!("[{er}] Load Test finished with {requests} request(s), {errors} error(s) and p95 {p95}ms",
+              er = er.ref_id, requests = report.requests, errors = report.errors, p95 = report.latency.p95);
+        Ok(report)
+    }
+
+    /// report - aggregate the samples of all the users
+    fn report(config: &LoadConfig, steps: &[LoadStep], users: Vec<(u64, Vec<Sample>)>,
+              elapsed: Duration) -> LoadReport {
+        let iterations = users.iter().map(|(done, _)| done).sum();
+        let samples: Vec<Sample> = users.into_iter().flat_map(|(_, samples)| samples).collect();
+        let mut failures = BTreeMap::new();
+        for error in samples.iter().filter_map(|sample| sample.error.clone()) {
+            *failures.entry(error).or_insert(0) += 1;
+        }
+        let step_reports = steps.iter().enumerate()
+            .map(|(index, load)| {
+                let step_samples: Vec<&Sample> = samples.iter().filter(|sample| sample.step == index).collect();
+                StepReport {
+                    step_id: load.step.id,
+                    name: load.step.name.clone(),
+                    requests: step_samples.len() as u64,
+                    errors: step_samples.iter().filter(|sample| sample.error.is_some()).count() as u64,
+                    latency: Latency::of(step_samples.iter().map(|sample| sample.latency).collect()),
+                }
+            })
+            .collect();
+        let requests = samples.len() as u64;
+        let errors = samples.iter().filter(|sample| sample.error.is_some()).count() as u64;
+        let seconds = elapsed.as_secs_f64();
+        LoadReport {
+            users: config.users,
+            iterations,
+            requests,
+            errors,
+            error_rate: if requests == 0 { 0.0 } else { errors as f64 / requests as f64 },
+            throughput: if seconds > 0.0 { requests as f64 / seconds } else { 0.0 },
+            duration: elapsed.as_millis() as u64,
+            latency: Latency::of(samples.iter().map(|sample| sample.latency).collect()),
+            steps: step_reports,
+            failures,
+            timed_out: false,
+        }
+    }
+}
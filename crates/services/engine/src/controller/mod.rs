@@ -1,3 +1,97 @@
+use std::future::Future;
+use std::time::Duration;
+
+use sea_orm::{ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter};
+use sea_orm::prelude::{DateTimeWithTimeZone, Uuid};
+use sea_orm::sea_query::Expr;
+
+use entity::test::profile::data as profile_data;
+use entity::test::ui::ExecutionRequest;
+use entity::test::ui::log::item_log;
+use entity::test::ui::log::item_log::ItemLogStatus;
+
+use crate::controller::data::Variables;
+use crate::error::{EngineError, EngineResult};
+
 pub mod action;
+#[cfg(feature = "api-automation")]
+pub mod api;
+#[cfg(feature = "api-automation")]
+pub mod assertion;
 pub mod case;
+#[cfg(feature = "api-automation")]
+pub mod channel;
+pub mod data;
+#[cfg(feature = "api-automation")]
+pub mod graphql;
+#[cfg(feature = "api-automation")]
+pub mod load;
+#[cfg(feature = "api-automation")]
+pub mod sql;
 pub mod suite;
+pub mod validator;
+
+/// with_timeout - run the future with the timeout in milliseconds if there is one,
+/// the future is dropped once the time is up
+pub(crate) async fn with_timeout<T>(
+    item: &str,
+    timeout: Option<i32>,
+    fut: impl Future<Output = EngineResult<T>>,
+) -> EngineResult<T> {
+    match timeout {
+        Some(ms) if ms > 0 => tokio::time::timeout(Duration::from_millis(ms as u64), fut)
+            .await
+            .map_err(|_| EngineError::Timeout(item.to_string(), ms))?,
+        _ => fut.await,
+    }
+}
+
+/// close_running - close the item logs of the execution created since `since` and left
+/// running by a future dropped on the timeout
+pub(crate) async fn close_running(db: &DatabaseTransaction, er: &ExecutionRequest,
+                                  since: DateTimeWithTimeZone, status: ItemLogStatus) -> EngineResult<u64> {
+    let result = item_log::Entity::update_many()
+        .col_expr(item_log::Column::Status, Expr::value(status))
+        .col_expr(item_log::Column::FinishedAt, Expr::value(DateTimeWithTimeZone::from(chrono::Utc::now())))
+        .filter(item_log::Column::RefId.eq(er.ref_id))
+        .filter(item_log::Column::Status.eq(ItemLogStatus::Running))
+        .filter(item_log::Column::CreatedAt.gte(since))
+        .exec(db)
+        .await?;
+    Ok(result.rows_affected)
+}
+
+/// profile_id - profile of the execution from the `profile_id` of the request args
+pub(crate) fn profile_id(er: &ExecutionRequest) -> Option<Uuid> {
+    er.args.as_ref()
+        .and_then(|args| args.get("profile_id"))
+        .and_then(|id| id.as_str())
+        .and_then(|id| Uuid::parse_str(id).ok())
+}
+
+/// load_profile - seed the runtime variables with the profile data from the request args,
+/// the values extracted by the earlier steps are kept
+pub(crate) async fn load_profile(db: &DatabaseTransaction, er: &ExecutionRequest,
+                                 variables: &Variables) -> EngineResult<()> {
+    let Some(profile_id) = profile_id(er) else {
+        return Ok(());
+    };
+    let data = profile_data::Entity::find()
+        .filter(profile_data::Column::ProfileId.eq(profile_id))
+        .all(db)
+        .await?;
+    for item in data {
+        if variables.get(&item.key).is_none() {
+            variables.set(item.key, item.value);
+        }
+    }
+    Ok(())
+}
+
+/// failed_status - log status for the item that ended with the error
+pub(crate) fn failed_status(err: &EngineError) -> ItemLogStatus {
+    match err {
+        EngineError::Timeout(..) => ItemLogStatus::TimedOut,
+        _ => ItemLogStatus::Failed,
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, Database, DatabaseConnection, DatabaseTransaction,
+              DbBackend, EntityTrait, FromQueryResult, QueryFilter, Statement};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::Uuid;
+use serde_json::Value;
+use tokio::sync::Mutex;
+use tracing::{error, info};
+
+use entity::api::assertion::MatchMode;
+use entity::test::sql::{connection, step};
+use entity::test::ui::ExecutionRequest;
+use entity::test::ui::log::item_log::{ItemLogStatus, ItemLogType, new};
+use entity::test::ui::log::ItemLog;
+
+use crate::controller::{failed_status, profile_id, with_timeout};
+use crate::controller::assertion::{compare, text};
+use crate::controller::data::{DataScope, fill, Variables};
+use crate::error::{EngineError, EngineResult};
+use crate::server::registry::ExecutionToken;
+
+/// SqlResult - rows returned by the query, or the number of rows the statement affected
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlResult {
+    pub rows: Vec<Value>,
+    pub count: u64,
+}
+
+/// parameter - bound value of the placeholder, always the text so the value is not guessed,
+/// the query casts it when the column needs another type (`${id}::int`)
+fn parameter(value: &str) -> sea_orm::Value {
+    sea_orm::Value::String(Some(Box::new(value.to_string())))
+}
+
+/// bind - statement of the query with the `${key}` placeholders sent as the bound parameters
+/// (`$1` for Postgres and `?` for the others), the unresolved keys are sent back as error
+pub fn bind(scope: &DataScope, query: &str, backend: DbBackend) -> Result<Statement, Vec<String>> {
+    let missing = scope.missing(query);
+    if !missing.is_empty() {
+        return Err(missing);
+    }
+    let mut sql = String::with_capacity(query.len());
+    let mut values = vec![];
+    let mut rest = query;
+    while let Some(start) = rest.find("${") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else { break };
+        sql.push_str(&rest[..start]);
+        values.push(parameter(scope.get(after[..end].trim()).map(String::as_str).unwrap_or_default()));
+        match backend {
+            DbBackend::Postgres => sql.push_str(&format!("${}", values.len())),
+            _ => sql.push('?'),
+        }
+        rest = &after[end + 1..];
+    }
+    sql.push_str(rest);
+    Ok(Statement::from_sql_and_values(backend, sql, values))
+}
+
+/// returns_rows - the query gives back the rows, the other statements only the affected count
+pub fn returns_rows(query: &str) -> bool {
+    let query = query.trim_start().to_lowercase();
+    let keyword = query.split_whitespace().next().unwrap_or_default();
+    ["select", "with", "values", "show", "explain"].contains(&keyword)
+        || query.split_whitespace().any(|word| word == "returning")
+}
+
+/// check - run the checks of the step on the result, the values of the passed checks are
+/// stored in the runtime variables
+pub fn check(step: &step::Model, result: &SqlResult, variables: &Variables) -> EngineResult<()> {
+    let scope = variables.scope();
+    let mut failures = vec![];
+    if let Some(expected) = step.expected_rows {
+        if expected as u64 != result.count {
+            failures.push(format!("expected {expected} row(s) but got {}", result.count));
+        }
+    }
+    for check in step.checks()? {
+        let expected = check.expected.as_deref()
+            .map(|expected| fill(&scope, "sql_step.checks", expected))
+            .transpose()?;
+        let mode = check.match_mode.clone().unwrap_or(if expected.is_some() {
+            MatchMode::Equals
+        } else {
+            MatchMode::Exists
+        });
+        let actual = result.rows.get(check.row).and_then(|row| row.get(&check.column));
+        match compare(&mode, actual, expected.as_deref()) {
+            Ok(()) => {
+                let variable = check.variable.as_deref().map(str::trim).filter(|v| !v.is_empty());
+                if let (Some(variable), Some(actual)) = (variable, actual) {
+                    variables.set(variable, text(actual));
+                }
+            }
+            Err(err) => failures.push(format!("row {} column '{}' {err}", check.row, check.column)),
+        }
+    }
+    if !failures.is_empty() {
+        return Err(EngineError::AssertionFailed(failures.join("; ")));
+    }
+    Ok(())
+}
+
+/// SqlConnections - database connections opened by the SQL steps of the execution by the
+/// connection url, shared by all the steps so the database is connected once per execution
+#[derive(Debug, Clone, Default)]
+pub struct SqlConnections(Arc<Mutex<HashMap<String, DatabaseConnection>>>);
+
+impl SqlConnections {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// connect - connection of the url, connected on the first use
+    pub async fn connect(&self, url: &str) -> EngineResult<DatabaseConnection> {
+        let mut connections = self.0.lock().await;
+        if let Some(db) = connections.get(url) {
+            return Ok(db.clone());
+        }
+        let db = Database::connect(url).await?;
+        connections.insert(url.to_string(), db.clone());
+        Ok(db)
+    }
+
+    /// close_all - close all the connections once the execution is done
+    pub async fn close_all(&self) {
+        let connections: Vec<DatabaseConnection> = self.0.lock().await.drain().map(|(_, db)| db).collect();
+        for db in connections {
+            if let Err(err) = db.close().await {
+                error!("Unable to close the SQL connection - {:?}", err);
+            }
+        }
+    }
+}
+
+pub struct SqlController<'scl> {
+    db: &'scl DatabaseTransaction,
+    variables: Variables,
+    connections: SqlConnections,
+    token: ExecutionToken,
+}
+
+impl<'scl> SqlController<'scl> {
+    pub fn new(db: &'scl DatabaseTransaction, variables: Variables, connections: SqlConnections,
+               token: ExecutionToken) -> SqlController<'scl> {
+        Self { db, variables, connections, token }
+    }
+
+    /// connection - connection of the step by the name, the one for the profile of the
+    /// execution comes before the one of the application
+    async fn connection(&self, step: &step::Model, er: &ExecutionRequest) -> EngineResult<connection::Model> {
+        let profile_id = profile_id(er);
+        let connections = connection::Entity::find()
+            .filter(connection::Column::AppId.eq(step.app_id))
+            .filter(connection::Column::Name.eq(step.connection.as_str()))
+            .all(self.db)
+            .await?;
+        let found = connections.iter().find(|c| c.profile_id.is_some() && c.profile_id == profile_id)
+            .or_else(|| connections.iter().find(|c| c.profile_id.is_none()))
+            .cloned();
+        found.ok_or(EngineError::MissingParameter("sql.connection".to_string(), step.connection.clone()))
+    }
+
+    /// query - run the query of the step on its database connection, the connection is made
+    /// within the timeout of the step
+    pub async fn query(&self, step: &step::Model, er: &ExecutionRequest) -> EngineResult<SqlResult> {
+        let connection = self.connection(step, er).await?;
+        let scope = self.variables.scope();
+        let url = fill(&scope, "sql_connection.url", &connection.url)?;
+        let run = async {
+            let db = self.connections.connect(&url).await?;
+            let statement = bind(&scope, &step.query, db.get_database_backend()).map_err(|missing| {
+                EngineError::MissingParameter("sql_step.query placeholder(s)".to_string(), missing.join(", "))
+            })?;
+            if returns_rows(&step.query) {
+                let rows = Value::find_by_statement(statement).all(&db).await?;
+                Ok::<_, EngineError>(SqlResult { count: rows.len() as u64, rows })
+            } else {
+                let res = db.execute(statement).await?;
+                Ok(SqlResult { rows: vec![], count: res.rows_affected() })
+            }
+        };
+        with_timeout("SQL Step", step.timeout, run).await
+    }
+
+    /// execute - run the SQL step by the step ID
+    pub async fn execute(&self, id: Uuid, er: &ExecutionRequest,
+                         log: Option<&ItemLog>) -> EngineResult<()> {
+        let step = step::Entity::find_by_id(id).one(self.db).await?
+            .ok_or(EngineError::MissingParameter("SqlStep".to_string(), id.into()))?;
+        let log_id = log.map(|l| l.id);
+        let mut log_am = new(er.ref_id, ItemLogType::SqlStep, id, log_id).save(self.db).await?;
+        if self.token.is_cancelled() {
+            info!("[{er}] Skipping SQL Step {step_id} - execution cancelled", er=er.ref_id, step_id = id);
+            log_am.status = Set(ItemLogStatus::Skipped);
+            log_am.finished_at = Set(chrono::Utc::now().into());
+            log_am.save(self.db).await?;
+            return Ok(());
+        }
+        info!("[{er}] Trigger SQL Step {step_id}", er=er.ref_id, step_id = id);
+        let start = chrono::Utc::now();
+        let result = match self.query(&step, er).await {
+            Ok(res) => {
+                info!("[{er}] SQL Step {step_id} gave {count} row(s)", er=er.ref_id, step_id = id,
+                      count = res.count);
+                check(&step, &res, &self.variables)
+            }
+            Err(err) => Err(err),
+        };
+
+        log_am.execution_time = Set((chrono::Utc::now() - start).num_milliseconds() as i32);
+        log_am.status = Set(match &result {
+            Ok(_) => ItemLogStatus::Success,
+            Err(err) => failed_status(err),
+        });
+        log_am.finished_at = Set(chrono::Utc::now().into());
+        log_am.save(self.db).await?;
+        result
+    }
+}
@@ -1 +1,121 @@
+use std::sync::Arc;
 
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, PaginatorTrait, QueryFilter,
+              QueryOrder};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::Uuid;
+use tracing::info;
+
+use cerium::client::Client;
+use cerium::client::driver::Driver;
+use entity::test::ui::ExecutionRequest;
+use entity::test::ui::log::item_log::{ItemLogStatus, ItemLogType, new};
+use entity::test::ui::suit::{suite, suite_block};
+use entity::test::ui::suit::suite_block::SuiteBlockType;
+
+use crate::controller::case::CaseController;
+#[cfg(feature = "api-automation")]
+use crate::controller::channel::Channels;
+use crate::controller::data::Variables;
+#[cfg(feature = "api-automation")]
+use crate::controller::sql::SqlConnections;
+use crate::error::{EngineError, EngineResult};
+use crate::server::registry::ExecutionToken;
+
+pub struct SuiteController<'scl> {
+    db: &'scl DatabaseTransaction,
+    cli: Client,
+    drive: Arc<dyn Driver>,
+    variables: Variables,
+    #[cfg(feature = "api-automation")]
+    channels: Channels,
+    #[cfg(feature = "api-automation")]
+    connections: SqlConnections,
+    token: ExecutionToken,
+}
+
+impl<'scl> SuiteController<'scl> {
+    pub fn new(
+        db: &'scl DatabaseTransaction,
+        drive: Arc<dyn Driver>,
+        cli: Client,
+        token: ExecutionToken,
+    ) -> SuiteController<'scl> {
+        Self {
+            db,
+            drive,
+            cli,
+            variables: Variables::new(),
+            #[cfg(feature = "api-automation")]
+            channels: Channels::new(),
+            #[cfg(feature = "api-automation")]
+            connections: SqlConnections::new(),
+            token,
+        }
+    }
+
+    /// with_variables - share the runtime variables of the execution with the test cases
+    pub fn with_variables(mut self, variables: Variables) -> Self {
+        self.variables = variables;
+        self
+    }
+
+    /// with_channels - share the connections of the execution with the test cases
+    #[cfg(feature = "api-automation")]
+    pub fn with_channels(mut self, channels: Channels) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// with_connections - share the SQL connections of the execution with the test cases
+    #[cfg(feature = "api-automation")]
+    pub fn with_connections(mut self, connections: SqlConnections) -> Self {
+        self.connections = connections;
+        self
+    }
+
+    /// run - will execute all the test cases in the suite in the execution order, the browser
+    /// session is reset (cookies, storage and about:blank) before every test case, the test
+    /// cases left once the execution is cancelled are logged as skipped
+    pub async fn run(&self, id: Uuid, er: &ExecutionRequest) -> EngineResult<()> {
+        info!("[{er}] Trigger Test Suite {suite_id}", er = er.ref_id, suite_id = id);
+        suite::Entity::find_by_id(id).one(self.db).await?
+            .ok_or(EngineError::MissingParameter("TestSuite".to_string(), id.into()))?;
+        let controller = CaseController::new(self.db, self.drive.clone(), self.cli.clone(),
+                                             self.token.clone())
+            .with_variables(self.variables.clone());
+        #[cfg(feature = "api-automation")]
+        let controller = controller.with_channels(self.channels.clone())
+            .with_connections(self.connections.clone());
+        let mut block_page = suite_block::Entity::find()
+            .filter(suite_block::Column::SuiteId.eq(id))
+            .order_by_asc(suite_block::Column::ExecutionOrder)
+            .paginate(self.db, 10);
+        while let Some(blocks) = block_page.fetch_and_next().await? {
+            for block in blocks.into_iter() {
+                match (&block.type_field, block.reference) {
+                    (SuiteBlockType::TestCase, Some(case_id)) if self.token.is_cancelled() => {
+                        info!("[{er}] Skipping Test Case {case_id} - execution cancelled", er = er.ref_id);
+                        self.skip(case_id, er).await?;
+                    }
+                    (SuiteBlockType::TestCase, Some(case_id)) => {
+                        // start every test case on a clean session, nothing is carried over from the last one
+                        self.drive.reset().await?;
+                        controller.run(case_id, er, None).await?
+                    }
+                    _ => info!("Skipping the suite block without reference - {:?}", block.id),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// skip - skipped log of the test case, logged the same way as the test case run
+    async fn skip(&self, case_id: Uuid, er: &ExecutionRequest) -> EngineResult<()> {
+        let mut log_am = new(er.ref_id, ItemLogType::ActionGroup, case_id, None);
+        log_am.status = Set(ItemLogStatus::Skipped);
+        log_am.finished_at = Set(chrono::Utc::now().into());
+        log_am.insert(self.db).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,547 @@
+use async_recursion::async_recursion;
+use sea_orm::{ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder};
+use sea_orm::prelude::Uuid;
+use scraper::Selector;
+use serde::{Deserialize, Serialize};
+use sxd_xpath::Factory;
+use tracing::info;
+
+use cerium::client::driver::SHADOW_SEPARATOR;
+use entity::api::assertion::{self as api_assertion, AssertionKind};
+use entity::api::channel::{self as channel_step, ChannelAction};
+use entity::api::step as api_step;
+use entity::prelude::{case, case_block, data_binding};
+use entity::prelude::case_block::{BlockKind, BlockType};
+use entity::prelude::data_binding::DataBindingKind;
+use entity::prelude::target::ActionTargetKind;
+use entity::test::{datatable, field};
+use entity::test::profile::data as profile_data;
+use entity::test::profile::profile;
+use entity::test::sql::{connection as sql_connection, step as sql_step};
+use entity::test::ui::action::action;
+use entity::test::ui::action::data::ActionDataKind;
+use entity::test::ui::action::group as action_group;
+use entity::test::ui::{elements, locator};
+use entity::test::ui::ExecutionRequest;
+use entity::test::ui::request::ExecutionType;
+use entity::test::ui::suit::{suite, suite_block};
+
+use crate::controller::data::DataScope;
+use crate::error::EngineResult;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum ReportItem {
+    TestSuite,
+    TestCase,
+    Block,
+    DataBinding,
+    ActionGroup,
+    Action,
+    ApiStep,
+    Assertion,
+    ChannelStep,
+    SqlStep,
+    Profile,
+}
+
+/// ReportIssue - single problem found while validating the execution
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ReportIssue {
+    pub item: ReportItem,
+    pub item_id: String,
+    /// case block the item belongs to, to highlight the issue in the editor
+    pub block_id: Option<Uuid>,
+    pub message: String,
+}
+
+/// ExecutionReport - result of the dry run, count of the items walked and the issues found
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct ExecutionReport {
+    pub cases: i32,
+    pub blocks: i32,
+    pub actions: i32,
+    pub issues: Vec<ReportIssue>,
+}
+
+impl ExecutionReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn issue(&mut self, item: ReportItem, item_id: impl ToString, block_id: Option<Uuid>,
+             message: impl Into<String>) {
+        self.issues.push(ReportIssue {
+            item,
+            item_id: item_id.to_string(),
+            block_id,
+            message: message.into(),
+        });
+    }
+}
+
+/// check_selector - check the selector of the action can be parsed for the locator kind
+pub fn check_selector(kind: &ActionTargetKind, selector: &str) -> Result<(), String> {
+    match kind {
+        ActionTargetKind::Css => Selector::parse(selector)
+            .map(|_| ())
+            .map_err(|err| format!("Invalid CSS selector '{selector}' - {err:?}")),
+        ActionTargetKind::Xpath => match Factory::new().build(selector) {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(format!("Empty XPath selector '{selector}'")),
+            Err(err) => Err(format!("Invalid XPath selector '{selector}' - {err}")),
+        },
+        ActionTargetKind::Tag => check_selector(&ActionTargetKind::Css, selector),
+        ActionTargetKind::ClassName if selector.trim().contains(char::is_whitespace) => {
+            Err(format!("Class name '{selector}' has to be a single class"))
+        }
+        ActionTargetKind::Shadow => selector
+            .split(SHADOW_SEPARATOR)
+            .try_for_each(|part| check_selector(&ActionTargetKind::Css, part.trim())),
+        _ if selector.trim().is_empty() => Err(format!("Empty {kind:?} selector")),
+        _ => Ok(()),
+    }
+}
+
+/// Validator - walk the full block and action tree without the browser and report
+/// every step that can not be executed
+pub struct Validator<'vl> {
+    db: &'vl DatabaseTransaction,
+    runtime: DataScope,
+}
+
+impl<'vl> Validator<'vl> {
+    pub fn new(db: &'vl DatabaseTransaction) -> Validator<'vl> {
+        Self { db, runtime: DataScope::new() }
+    }
+
+    /// with_runtime - values the engine gives to the execution (`${mock_url}`),
+    /// the profile data does not shadow them
+    pub fn with_runtime(mut self, runtime: DataScope) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// validate - validate the execution request with the profile from the request args
+    pub async fn validate(&self, er: &ExecutionRequest) -> EngineResult<ExecutionReport> {
+        let profile_id = er
+            .args
+            .as_ref()
+            .and_then(|args| args.get("profile_id"))
+            .and_then(|id| id.as_str())
+            .and_then(|id| Uuid::parse_str(id).ok());
+        match er.ref_type {
+            ExecutionType::TestCase => self.validate_case(er.ref_id, profile_id).await,
+            ExecutionType::TestSuite => self.validate_suite(er.ref_id, profile_id).await,
+        }
+    }
+
+    /// validate_case - validate the test case
+    pub async fn validate_case(&self, case_id: Uuid, profile_id: Option<Uuid>) -> EngineResult<ExecutionReport> {
+        let mut report = ExecutionReport::default();
+        let scope = self.profile_scope(profile_id, &mut report).await?;
+        self.walk_case(case_id, &scope, &mut report).await?;
+        info!("Validated Test Case {:?} with {} issue(s)", case_id, report.issues.len());
+        Ok(report)
+    }
+
+    /// validate_suite - validate all the test cases in the test suite
+    pub async fn validate_suite(&self, suite_id: Uuid, profile_id: Option<Uuid>) -> EngineResult<ExecutionReport> {
+        let mut report = ExecutionReport::default();
+        if suite::Entity::find_by_id(suite_id).one(self.db).await?.is_none() {
+            report.issue(ReportItem::TestSuite, suite_id, None, "Test Suite not found");
+            return Ok(report);
+        }
+        let mut scope = self.profile_scope(profile_id, &mut report).await?;
+        let blocks = suite_block::Entity::find()
+            .filter(suite_block::Column::SuiteId.eq(suite_id))
+            .order_by_asc(suite_block::Column::ExecutionOrder)
+            .all(self.db)
+            .await?;
+        for block in blocks {
+            match block.reference {
+                Some(case_id) => {
+                    for variable in self.walk_case(case_id, &scope, &mut report).await? {
+                        scope.insert(variable.clone(), format!("<{variable}>"));
+                    }
+                }
+                None => report.issue(ReportItem::Block, block.id, None, "Suite block has no test case reference"),
+            }
+        }
+        info!("Validated Test Suite {:?} with {} issue(s)", suite_id, report.issues.len());
+        Ok(report)
+    }
+
+    /// profile_scope - load the profile data as the outer most scope
+    async fn profile_scope(&self, profile_id: Option<Uuid>, report: &mut ExecutionReport) -> EngineResult<DataScope> {
+        let mut scope = self.runtime.clone();
+        let Some(profile_id) = profile_id else {
+            return Ok(scope);
+        };
+        if profile::Entity::find_by_id(profile_id).one(self.db).await?.is_none() {
+            report.issue(ReportItem::Profile, profile_id, None, "Profile not found");
+            return Ok(scope);
+        }
+        let data = profile_data::Entity::find()
+            .filter(profile_data::Column::ProfileId.eq(profile_id))
+            .all(self.db)
+            .await?;
+        for item in data.into_iter().filter(|item| !self.runtime.contains(&item.key)) {
+            scope.insert(item.key, item.value);
+        }
+        Ok(scope)
+    }
+
+    /// walk_case - walk the blocks of the case, the runtime variables extracted by the case are sent back
+    async fn walk_case(&self, case_id: Uuid, scope: &DataScope,
+                       report: &mut ExecutionReport) -> EngineResult<Vec<String>> {
+        report.cases += 1;
+        if case::Entity::find_by_id(case_id).one(self.db).await?.is_none() {
+            report.issue(ReportItem::TestCase, case_id, None, "Test Case not found");
+            return Ok(vec![]);
+        }
+        self.walk_blocks(case_id, None, scope, report).await
+    }
+
+    /// walk_blocks - walk the blocks under the parent in the execution order, the runtime
+    /// variables extracted by the API steps are visible to the blocks after them
+    #[async_recursion]
+    async fn walk_blocks(&self, case_id: Uuid, parent_id: Option<Uuid>, scope: &DataScope,
+                         report: &mut ExecutionReport) -> EngineResult<Vec<String>> {
+        let mut scope = scope.clone();
+        let mut variables = vec![];
+        let filter = match parent_id {
+            Some(parent_id) => case_block::Column::ParentId.eq(parent_id),
+            None => case_block::Column::ParentId.is_null(),
+        };
+        let blocks = case_block::Entity::find()
+            .filter(case_block::Column::CaseId.eq(case_id))
+            .filter(filter)
+            .order_by_asc(case_block::Column::ExecutionOrder)
+            .all(self.db)
+            .await?;
+        for block in blocks {
+            report.blocks += 1;
+            let block_scope = self.block_scope(&block, &scope, report).await?;
+            let mut extracted = vec![];
+            if block.kind == BlockKind::Reference {
+                match (&block.type_field, block.reference) {
+                    (_, None) => report.issue(ReportItem::Block, block.id, Some(block.id),
+                                              "Reference block has no reference"),
+                    (BlockType::ActionGroup | BlockType::Assertion, Some(group_id)) => {
+                        self.walk_action_group(block.id, group_id, &block_scope, report).await?
+                    }
+                    (BlockType::ApiStep, Some(step_id)) => {
+                        extracted = self.check_api_step(block.id, step_id, &block_scope, report).await?
+                    }
+                    (BlockType::ChannelStep, Some(step_id)) => {
+                        extracted = self.check_channel_step(block.id, step_id, &block_scope, report).await?
+                    }
+                    (BlockType::SqlStep, Some(step_id)) => {
+                        extracted = self.check_sql_step(block.id, step_id, &block_scope, report).await?
+                    }
+                    _ => {}
+                }
+            }
+            extracted.extend(self.walk_blocks(case_id, Some(block.id), &block_scope, report).await?);
+            for variable in extracted {
+                scope.insert(variable.clone(), format!("<{variable}>"));
+                variables.push(variable);
+            }
+        }
+        Ok(variables)
+    }
+
+    /// block_scope - add the data bindings of the block on top of the parent scope
+    async fn block_scope(&self, block: &case_block::Model, scope: &DataScope,
+                         report: &mut ExecutionReport) -> EngineResult<DataScope> {
+        let mut block_scope = scope.clone();
+        let bindings = data_binding::Entity::find()
+            .filter(data_binding::Column::BlockId.eq(block.id))
+            .all(self.db)
+            .await?;
+        if block.type_field == BlockType::DataTable
+            && !bindings.iter().any(|b| b.kind == DataBindingKind::Field)
+        {
+            report.issue(ReportItem::Block, block.id, Some(block.id), "DataTable block has no field binding");
+        }
+        for binding in bindings {
+            match binding.kind {
+                DataBindingKind::Data => {
+                    let missing = scope.missing(&binding.value);
+                    if !missing.is_empty() {
+                        report.issue(ReportItem::DataBinding, binding.id, Some(block.id),
+                                     format!("Unresolved placeholder(s) {missing:?} in '{}'", binding.key));
+                    }
+                    block_scope.insert(binding.key, binding.value);
+                }
+                DataBindingKind::Field => {
+                    if let Err(message) = self.check_field(&binding.value).await? {
+                        report.issue(ReportItem::DataBinding, binding.id, Some(block.id), message);
+                    }
+                    let value = format!("<{}>", binding.value);
+                    block_scope.insert(binding.key, value);
+                }
+            }
+        }
+        Ok(block_scope)
+    }
+
+    /// check_field - check the datatable field reference in `<table_id>.<field_id>` format
+    async fn check_field(&self, reference: &str) -> EngineResult<Result<(), String>> {
+        let Some((table_id, field_id)) = reference.split_once('.') else {
+            return Ok(Err(format!("Invalid datatable field reference '{reference}'")));
+        };
+        let Ok(table_id) = table_id.trim().parse::<i32>() else {
+            return Ok(Err(format!("Invalid datatable id in '{reference}'")));
+        };
+        if datatable::Entity::find_by_id(table_id).one(self.db).await?.is_none() {
+            return Ok(Err(format!("DataTable {table_id} not found")));
+        }
+        let field = field::Entity::find()
+            .filter(field::Column::TableId.eq(table_id))
+            .filter(field::Column::FieldId.eq(field_id.trim()))
+            .one(self.db)
+            .await?;
+        if field.is_none() {
+            return Ok(Err(format!("Field '{field_id}' not found in DataTable {table_id}")));
+        }
+        Ok(Ok(()))
+    }
+
+    async fn walk_action_group(&self, block_id: Uuid, group_id: Uuid, scope: &DataScope,
+                               report: &mut ExecutionReport) -> EngineResult<()> {
+        if action_group::Entity::find_by_id(group_id).one(self.db).await?.is_none() {
+            report.issue(ReportItem::ActionGroup, group_id, Some(block_id), "Action Group not found");
+            return Ok(());
+        }
+        let actions = action::Entity::find()
+            .filter(action::Column::ActionGroupId.eq(group_id))
+            .order_by_asc(action::Column::ExecutionOrder)
+            .all(self.db)
+            .await?;
+        for action in actions {
+            report.actions += 1;
+            if let Some(element_id) = action.element_id {
+                self.check_element(block_id, &action, element_id, report).await?;
+            }
+            self.check_action(block_id, &action, scope, report);
+        }
+        Ok(())
+    }
+
+    /// check_api_step - check the API step exists, has the url and every value resolves,
+    /// the runtime variables extracted by the assertions of the step are sent back
+    async fn check_api_step(&self, block_id: Uuid, step_id: Uuid, scope: &DataScope,
+                            report: &mut ExecutionReport) -> EngineResult<Vec<String>> {
+        let Some(step) = api_step::Entity::find_by_id(step_id).one(self.db).await? else {
+            report.issue(ReportItem::ApiStep, step_id, Some(block_id), "API Step not found");
+            return Ok(vec![]);
+        };
+        if step.url.trim().is_empty() {
+            report.issue(ReportItem::ApiStep, step.id, Some(block_id), "API Step is missing url");
+        }
+        if let Err(err) = step.auth() {
+            report.issue(ReportItem::ApiStep, step.id, Some(block_id), format!("Invalid auth - {err}"));
+        }
+        let graphql = match step.graphql() {
+            Ok(graphql) => graphql,
+            Err(err) => {
+                report.issue(ReportItem::ApiStep, step.id, Some(block_id), format!("Invalid GraphQL document - {err}"));
+                None
+            }
+        };
+        match graphql.as_ref().map(|graphql| graphql.operation()) {
+            Some(None) => report.issue(ReportItem::ApiStep, step.id, Some(block_id),
+                                       "GraphQL document has no query or mutation"),
+            Some(Some("subscription")) => report.issue(ReportItem::ApiStep, step.id, Some(block_id),
+                                                       "GraphQL subscriptions are not supported"),
+            _ => {}
+        }
+        let values = std::iter::once(("url".to_string(), step.url.clone()))
+            .chain(step.body.clone().map(|body| ("body".to_string(), body)))
+            .chain(graphql.and_then(|graphql| graphql.variables)
+                .map(|variables| ("GraphQL variables".to_string(), variables.to_string())))
+            .chain(step.headers().into_iter().map(|(name, value)| (format!("header '{name}'"), value)))
+            .chain(step.query().into_iter().map(|(name, value)| (format!("query '{name}'"), value)));
+        for (field, value) in values {
+            let missing = scope.missing(&value);
+            if !missing.is_empty() {
+                report.issue(ReportItem::ApiStep, step.id, Some(block_id),
+                             format!("Unresolved placeholder(s) {missing:?} in {field}"));
+            }
+        }
+        let assertions = api_assertion::Entity::find()
+            .filter(api_assertion::Column::StepId.eq(step.id))
+            .order_by_asc(api_assertion::Column::ExecutionOrder)
+            .all(self.db)
+            .await?;
+        let mut variables = vec![];
+        for assertion in assertions {
+            let needs_expression = matches!(assertion.kind,
+                AssertionKind::JsonPath | AssertionKind::JsonPointer | AssertionKind::Header);
+            if needs_expression && assertion.expression.as_deref().map_or(true, |e| e.trim().is_empty()) {
+                report.issue(ReportItem::Assertion, assertion.id, Some(block_id),
+                             format!("{:?} assertion is missing the expression", assertion.kind));
+            }
+            let missing = scope.missing(assertion.expected.as_deref().unwrap_or_default());
+            if !missing.is_empty() {
+                report.issue(ReportItem::Assertion, assertion.id, Some(block_id),
+                             format!("Unresolved placeholder(s) {missing:?} in expected value"));
+            }
+            if let Some(variable) = assertion.variable.filter(|v| !v.trim().is_empty()) {
+                variables.push(variable.trim().to_string());
+            }
+        }
+        Ok(variables)
+    }
+
+    /// check_channel_step - check the channel step has what its action needs and the
+    /// placeholders it uses resolve, the variable of the wait step is given back
+    async fn check_channel_step(&self, block_id: Uuid, step_id: Uuid, scope: &DataScope,
+                                report: &mut ExecutionReport) -> EngineResult<Vec<String>> {
+        let Some(step) = channel_step::Entity::find_by_id(step_id).one(self.db).await? else {
+            report.issue(ReportItem::ChannelStep, step_id, Some(block_id), "Channel Step not found");
+            return Ok(vec![]);
+        };
+        if let Err(message) = step.check() {
+            report.issue(ReportItem::ChannelStep, step.id, Some(block_id), message);
+        }
+        let values = [("url", step.url.clone()), ("message", step.message.clone()), ("expected", step.expected.clone())]
+            .into_iter()
+            .filter_map(|(field, value)| value.map(|value| (field.to_string(), value)))
+            .chain(step.headers().into_iter().map(|(name, value)| (format!("header '{name}'"), value)));
+        for (field, value) in values {
+            let missing = scope.missing(&value);
+            if !missing.is_empty() {
+                report.issue(ReportItem::ChannelStep, step.id, Some(block_id),
+                             format!("Unresolved placeholder(s) {missing:?} in {field}"));
+            }
+        }
+        let variable = step.variable.filter(|v| step.action == ChannelAction::Wait && !v.trim().is_empty());
+        Ok(variable.map(|v| v.trim().to_string()).into_iter().collect())
+    }
+
+    /// check_sql_step - check the SQL step has its connection, valid checks and the
+    /// placeholders it uses resolve, the variables of the checks are given back
+    async fn check_sql_step(&self, block_id: Uuid, step_id: Uuid, scope: &DataScope,
+                            report: &mut ExecutionReport) -> EngineResult<Vec<String>> {
+        let Some(step) = sql_step::Entity::find_by_id(step_id).one(self.db).await? else {
+            report.issue(ReportItem::SqlStep, step_id, Some(block_id), "SQL Step not found");
+            return Ok(vec![]);
+        };
+        if step.query.trim().is_empty() {
+            report.issue(ReportItem::SqlStep, step.id, Some(block_id), "SQL Step is missing the query");
+        }
+        let connection = sql_connection::Entity::find()
+            .filter(sql_connection::Column::AppId.eq(step.app_id))
+            .filter(sql_connection::Column::Name.eq(step.connection.as_str()))
+            .one(self.db)
+            .await?;
+        match connection {
+            Some(connection) => {
+                let missing = scope.missing(&connection.url);
+                if !missing.is_empty() {
+                    report.issue(ReportItem::SqlStep, step.id, Some(block_id),
+                                 format!("Unresolved placeholder(s) {missing:?} in connection '{}'", step.connection));
+                }
+            }
+            None => report.issue(ReportItem::SqlStep, step.id, Some(block_id),
+                                 format!("Database connection '{}' not found", step.connection)),
+        }
+        let missing = scope.missing(&step.query);
+        if !missing.is_empty() {
+            report.issue(ReportItem::SqlStep, step.id, Some(block_id),
+                         format!("Unresolved placeholder(s) {missing:?} in query"));
+        }
+        let checks = match step.checks() {
+            Ok(checks) => checks,
+            Err(err) => {
+                report.issue(ReportItem::SqlStep, step.id, Some(block_id), format!("Invalid checks - {err}"));
+                vec![]
+            }
+        };
+        let mut variables = vec![];
+        for check in checks {
+            let missing = scope.missing(check.expected.as_deref().unwrap_or_default());
+            if !missing.is_empty() {
+                report.issue(ReportItem::SqlStep, step.id, Some(block_id),
+                             format!("Unresolved placeholder(s) {missing:?} in the check of '{}'", check.column));
+            }
+            if let Some(variable) = check.variable.filter(|v| !v.trim().is_empty()) {
+                variables.push(variable.trim().to_string());
+            }
+        }
+        Ok(variables)
+    }
+
+    /// check_element - check the object repository element of the action exists
+    /// and its primary and fallback locators are valid selectors
+    async fn check_element(&self, block_id: Uuid, action: &action::Model, element_id: Uuid,
+                           report: &mut ExecutionReport) -> EngineResult<()> {
+        match elements::Entity::find_by_id(element_id).one(self.db).await? {
+            None => report.issue(ReportItem::Action, action.id, Some(block_id),
+                                 format!("Element {element_id} not found in the object repository")),
+            Some(element) => {
+                let fallbacks = locator::Entity::find()
+                    .filter(locator::Column::ElementId.eq(element_id))
+                    .all(self.db)
+                    .await?;
+                let selectors = std::iter::once((element.locator_type, element.locator_value))
+                    .chain(fallbacks.into_iter().map(|l| (l.locator_type, l.locator_value)));
+                for (kind, selector) in selectors {
+                    if let Err(message) = check_selector(&kind, &selector) {
+                        report.issue(ReportItem::Action, action.id, Some(block_id), message);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// check_action - check the action has all the required fields and every value resolves
+    fn check_action(&self, block_id: Uuid, action: &action::Model, scope: &DataScope,
+                    report: &mut ExecutionReport) {
+        let is_empty = |value: &Option<String>| value.as_ref().map_or(true, |v| v.trim().is_empty());
+        if action.kind.need_target() && action.element_id.is_none() {
+            if action.target_kind.is_none() {
+                report.issue(ReportItem::Action, action.id, Some(block_id), format!("{:?} is missing target_kind", action.kind));
+            }
+            if is_empty(&action.target_value) {
+                report.issue(ReportItem::Action, action.id, Some(block_id), format!("{:?} is missing target_value", action.kind));
+            }
+        }
+        if action.kind.need_data() && is_empty(&action.data_value) {
+            report.issue(ReportItem::Action, action.id, Some(block_id), format!("{:?} is missing data_value", action.kind));
+        }
+        if let (Some(target_kind), Some(target_value)) = (&action.target_kind, &action.target_value) {
+            if let Ok(selector) = scope.resolve(target_value) {
+                if let Err(message) = check_selector(target_kind, &selector) {
+                    report.issue(ReportItem::Action, action.id, Some(block_id), message);
+                }
+            }
+        }
+        if let Some(target_value) = &action.target_value {
+            let missing = scope.missing(target_value);
+            if !missing.is_empty() {
+                report.issue(ReportItem::Action, action.id, Some(block_id),
+                             format!("Unresolved placeholder(s) {missing:?} in target_value"));
+            }
+        }
+        if let Some(data_value) = &action.data_value {
+            match action.data_kind {
+                Some(ActionDataKind::Runtime) if !scope.contains(data_value.trim()) => {
+                    report.issue(ReportItem::Action, action.id, Some(block_id),
+                                 format!("Runtime data '{data_value}' is not bound"));
+                }
+                Some(ActionDataKind::Runtime) => {}
+                _ => {
+                    let missing = scope.missing(data_value);
+                    if !missing.is_empty() {
+                        report.issue(ReportItem::Action, action.id, Some(block_id),
+                                     format!("Unresolved placeholder(s) {missing:?} in data_value"));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,6 @@
 use cerium::error::CeriumError;
 use sea_orm::DbErr;
 use serde_json::Error as SerdeJsonError;
-use thirtyfour::error::WebDriverError;
 use thiserror::Error;
 
 pub type EngineResult<T> = Result<T, EngineError>;
@@ -13,12 +12,28 @@ pub enum EngineError {
     Forbidden,
     #[error("Missing Parameter: field - {0}, {1}")]
     MissingParameter(String, String),
-    #[error("Webdriver error: {0}")]
-    WebdriverError(#[from] WebDriverError),
+    #[error("Unsupported execution: {0}")]
+    Unsupported(String),
+    #[error("Invalid schedule: {0}")]
+    InvalidSchedule(String),
+    #[error("Timed out: {0} after {1}ms")]
+    Timeout(String, i32),
+    #[error("Validation failed with {0} issue(s)")]
+    ValidationFailed(usize),
+    #[error("Assertion failed: {0}")]
+    AssertionFailed(String),
     #[error("Database error: {0}")]
     DatabaseError(#[from] DbErr),
     #[error("Json Serialization error: {0}")]
     SerializerError(#[from] SerdeJsonError),
+    #[error("Io error: {0}")]
+    IoError(#[from] std::io::Error),
     #[error("CeriumError: {0}")]
     CeriumError(#[from] CeriumError),
+    #[cfg(feature = "api-automation")]
+    #[error("Http error: {0}")]
+    HttpError(#[from] reqwest::Error),
+    #[cfg(feature = "api-automation")]
+    #[error("WebSocket error: {0}")]
+    WebSocketError(#[from] tokio_tungstenite::tungstenite::Error),
 }
@@ -0,0 +1,19 @@
+use crate::error::{EngineError, EngineResult};
+
+pub mod rust;
+
+/// ExportFormat - code the test case can be exported to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    /// standalone `#[tokio::test]` driving the browser with thirtyfour
+    Rust,
+}
+
+impl ExportFormat {
+    pub fn parse(format: &str) -> EngineResult<Self> {
+        match format.to_lowercase().as_str() {
+            "rust" | "rs" => Ok(ExportFormat::Rust),
+            _ => Err(EngineError::Unsupported(format!("export format '{format}'"))),
+        }
+    }
+}
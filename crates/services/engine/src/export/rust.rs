@@ -0,0 +1,187 @@
+use async_recursion::async_recursion;
+use sea_orm::{ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder};
+use sea_orm::prelude::Uuid;
+
+use cerium::client::driver::Locator;
+use entity::prelude::case;
+use entity::prelude::case_block::{self, BlockKind, BlockType};
+use entity::test::ui::action::action::{self, ActionKind};
+use entity::test::ui::action::group;
+use entity::test::ui::elements;
+
+use crate::controller::action::locator;
+use crate::error::{EngineError, EngineResult};
+
+const HEADER: &str = "use thirtyfour::prelude::*;
+
+/// webdriver_url - webdriver endpoint from `WEBDRIVER_URL`, the local selenium by default
+fn webdriver_url() -> String {
+    std::env::var(\"WEBDRIVER_URL\").unwrap_or_else(|_| \"http://localhost:4444\".to_string())
+}
+";
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "static", "struct",
+    "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while",
+    "yield",
+];
+
+/// fn_name - snake case rust identifier for the test case name, the keywords get the `case_` prefix
+pub fn fn_name(name: &str) -> String {
+    let mut ident = String::new();
+    for c in name.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            ident.push(c.to_ascii_lowercase());
+        } else if !ident.ends_with('_') {
+            ident.push('_');
+        }
+    }
+    let ident = ident.trim_matches('_').to_string();
+    match ident.chars().next() {
+        None => "exported_case".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("case_{ident}"),
+        Some(_) if KEYWORDS.contains(&ident.as_str()) => format!("case_{ident}"),
+        Some(_) => ident,
+    }
+}
+
+/// find - expression finding the element, the shadow hosts are walked one by one
+fn find(locator: &Locator) -> String {
+    let by = |kind: &str, value: &str| format!("By::{kind}({value:?})");
+    let Some(hosts) = locator.shadow_hosts() else {
+        let by = match locator {
+            Locator::Css(value) => by("Css", value),
+            Locator::Id(value) => by("Id", value),
+            Locator::XPath(value) => by("XPath", value),
+            Locator::Name(value) => by("Name", value),
+            Locator::LinkText(value) => by("LinkText", value),
+            Locator::PartialLinkText(value) => by("PartialLinkText", value),
+            Locator::ClassName(value) => by("ClassName", value),
+            Locator::Tag(value) => by("Tag", value),
+            Locator::Text(_) => by("XPath", &locator.xpath().unwrap_or_default()),
+            Locator::Shadow(_) => by("Css", locator.shadow_target().unwrap_or_default()),
+        };
+        return format!("driver.find({by}).await?");
+    };
+    let mut expr = "driver".to_string();
+    for host in hosts {
+        expr.push_str(&format!(".find({}).await?.get_shadow_root().await?", by("Css", &host)));
+    }
+    format!("{expr}.find({}).await?", by("Css", locator.shadow_target().unwrap_or_default()))
+}
+
+/// RustExporter - flatten the block tree of the test case and the referenced action
+/// groups into a standalone thirtyfour test, the control flow blocks can not be flattened
+/// and fail the export, the other blocks Orca does not execute are left as comments and
+/// the lines with the `${...}` placeholders get a comment to fill them in
+pub struct RustExporter<'el> {
+    db: &'el DatabaseTransaction,
+    lines: Vec<String>,
+}
+
+impl<'el> RustExporter<'el> {
+    pub fn new(db: &'el DatabaseTransaction) -> RustExporter<'el> {
+        Self { db, lines: Vec::new() }
+    }
+
+    /// export - generate the test for the case with the block tree of `query_case`
+    pub async fn export(mut self, case: &case::Model, blocks: &[case_block::Model]) -> EngineResult<String> {
+        self.blocks(blocks, 1).await?;
+        let name = fn_name(&case.name);
+        let mut code = format!("//! {} - exported from Orca test case {}\n\n{HEADER}\n", case.name, case.id);
+        code.push_str(&format!(
+            "#[tokio::test]\nasync fn {name}() -> WebDriverResult<()> {{\n    \
+             let driver = WebDriver::new(&webdriver_url(), DesiredCapabilities::firefox()).await?;\n    \
+             let result = steps(&driver).await;\n    \
+             driver.quit().await?;\n    \
+             result\n}}\n\n"
+        ));
+        code.push_str("async fn steps(driver: &WebDriver) -> WebDriverResult<()> {\n");
+        for line in self.lines.iter() {
+            code.push_str(line);
+            code.push('\n');
+        }
+        code.push_str("    Ok(())\n}\n");
+        Ok(code)
+    }
+
+    fn line(&mut self, depth: usize, line: impl AsRef<str>) {
+        let line = line.as_ref();
+        let note = if line.contains("${") { " // TODO: replace the Orca placeholder" } else { "" };
+        self.lines.push(format!("{}{line}{note}", "    ".repeat(depth)));
+    }
+
+    /// blocks - walk the block tree in the execution order
+    #[async_recursion]
+    async fn blocks(&mut self, blocks: &[case_block::Model], depth: usize) -> EngineResult<()> {
+        for block in blocks {
+            match (&block.kind, &block.type_field, block.reference) {
+                (BlockKind::Reference, BlockType::ActionGroup | BlockType::Assertion, Some(group_id)) => {
+                    self.action_group(group_id, depth).await?;
+                }
+                (_, BlockType::Loop | BlockType::Condition | BlockType::YesCase | BlockType::NoCase
+                    | BlockType::Parallel, _) => {
+                    return Err(EngineError::Unsupported(
+                        format!("{:?} block {} in the Rust export", block.type_field, block.id)));
+                }
+                (_, BlockType::Block, _) => {}
+                (_, type_field, _) => {
+                    self.line(depth, format!("// {:?} block {} can not be exported, skipped", type_field, block.id));
+                }
+            }
+            if let Some(children) = &block.children {
+                self.blocks(children, depth).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn action_group(&mut self, group_id: Uuid, depth: usize) -> EngineResult<()> {
+        let group = group::Entity::find_by_id(group_id).one(self.db).await?
+            .ok_or(EngineError::MissingParameter("ActionGroup".to_string(), group_id.into()))?;
+        self.line(depth, format!("// Action Group: {}", group.name));
+        let actions = action::Entity::find()
+            .filter(action::Column::ActionGroupId.eq(group_id))
+            .order_by_asc(action::Column::ExecutionOrder)
+            .all(self.db)
+            .await?;
+        for action in actions {
+            self.action(&action, depth).await?;
+        }
+        Ok(())
+    }
+
+    async fn action(&mut self, action: &action::Model, depth: usize) -> EngineResult<()> {
+        let data = action.data_value.clone().unwrap_or_default();
+        if action.kind == ActionKind::Open {
+            self.line(depth, format!("driver.goto({data:?}).await?;"));
+            return Ok(());
+        }
+        let Some(target) = self.target(action).await? else {
+            self.line(depth, format!("// {:?} action {} has no target, skipped", action.kind, action.id));
+            return Ok(());
+        };
+        let element = find(&target);
+        match action.kind {
+            ActionKind::Enter => self.line(depth, format!("{element}.send_keys({data:?}).await?;")),
+            ActionKind::Click => self.line(depth, format!("{element}.click().await?;")),
+            ActionKind::VerifyText => {
+                self.line(depth, format!("assert_eq!({element}.inner_html().await?, {data:?});"));
+            }
+            _ => self.line(depth, format!("// {:?} action {} is not executed by Orca, skipped", action.kind, action.id)),
+        }
+        Ok(())
+    }
+
+    /// target - locator of the action, the object repository element takes the place
+    /// of the inline target
+    async fn target(&self, action: &action::Model) -> EngineResult<Option<Locator>> {
+        if let Some(element_id) = action.element_id {
+            let element = elements::Entity::find_by_id(element_id).one(self.db).await?;
+            return Ok(element.map(|e| locator(e.locator_type, e.locator_value)));
+        }
+        Ok(action.target_kind.clone().zip(action.target_value.clone()).map(|(kind, value)| locator(kind, value)))
+    }
+}
@@ -0,0 +1,41 @@
+use serde::Serialize;
+
+pub mod openapi;
+pub mod postman;
+pub mod side;
+
+/// ImportIssue - item of the imported file that could not be mapped to Orca
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportIssue {
+    /// name of the test / request the item belongs to
+    pub source: String,
+    /// position of the item in the source, starting from 1
+    pub index: usize,
+    pub item: String,
+    pub reason: String,
+}
+
+/// ImportReport - what got created by the import and what was left out
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ImportReport {
+    pub cases: usize,
+    pub action_groups: usize,
+    pub actions: usize,
+    pub suites: usize,
+    pub api_steps: usize,
+    /// existing API steps updated by the re-import of the same operation
+    pub updated_api_steps: usize,
+    pub profiles: usize,
+    pub unsupported: Vec<ImportIssue>,
+}
+
+impl ImportReport {
+    fn unsupported(&mut self, source: &str, index: usize, item: &str, reason: impl Into<String>) {
+        self.unsupported.push(ImportIssue {
+            source: source.to_string(),
+            index,
+            item: item.to_string(),
+            reason: reason.into(),
+        });
+    }
+}
@@ -0,0 +1,341 @@
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, QueryFilter};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::Uuid;
+use serde_json::{json, Map, Value};
+use tracing::info;
+
+use entity::api::component::{Components, ExternalDocs, Info, Parameter, Path, Root, Schema, Server, Tag};
+use entity::api::spec;
+use entity::api::step::{self, ApiMethod};
+
+use crate::error::{EngineError, EngineResult};
+use crate::import::ImportReport;
+
+const METHODS: [&str; 8] = ["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
+/// text - string value of the key, empty when missing
+fn text(value: &Value, key: &str) -> String {
+    value.get(key).and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+/// resolve - follow the local `$ref` of the value (`#/components/...`) in the document
+pub(crate) fn resolve<'v>(document: &'v Value, value: &'v Value) -> &'v Value {
+    let mut value = value;
+    for _ in 0..8 {
+        let Some(pointer) = value.get("$ref").and_then(Value::as_str) else { break };
+        match pointer.strip_prefix('#').and_then(|p| document.pointer(p)) {
+            Some(target) => value = target,
+            None => break,
+        }
+    }
+    value
+}
+
+/// example - example value for the schema, the declared example wins over the generated one
+pub fn example(document: &Value, schema: &Value) -> Value {
+    fn generate(document: &Value, schema: &Value, depth: usize) -> Value {
+        let schema = resolve(document, schema);
+        if let Some(example) = schema.get("example").or_else(|| schema.get("default")) {
+            return example.clone();
+        }
+        if let Some(first) = schema.get("enum").and_then(Value::as_array).and_then(|e| e.first()) {
+            return first.clone();
+        }
+        if depth > 6 {
+            return Value::Null;
+        }
+        if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+            let mut object = Map::new();
+            for part in all_of {
+                if let Value::Object(part) = generate(document, part, depth + 1) {
+                    object.extend(part);
+                }
+            }
+            return Value::Object(object);
+        }
+        if let Some(first) = ["oneOf", "anyOf"].iter()
+            .find_map(|key| schema.get(*key).and_then(Value::as_array).and_then(|s| s.first())) {
+            return generate(document, first, depth + 1);
+        }
+        match schema.get("type").and_then(Value::as_str) {
+            Some("integer") | Some("number") => json!(0),
+            Some("boolean") => json!(true),
+            Some("array") => match schema.get("items") {
+                Some(items) => json!([generate(document, items, depth + 1)]),
+                None => json!([]),
+            },
+            Some("string") => match schema.get("format").and_then(Value::as_str) {
+                Some("date-time") => json!("2024-01-01T00:00:00Z"),
+                Some("date") => json!("2024-01-01"),
+                Some("email") => json!("user@example.com"),
+                Some("uuid") => json!(Uuid::nil().to_string()),
+                _ => json!("string"),
+            },
+            _ => match schema.get("properties").and_then(Value::as_object) {
+                Some(properties) => Value::Object(properties.iter()
+                    .map(|(key, property)| (key.clone(), generate(document, property, depth + 1)))
+                    .collect()),
+                None => Value::Null,
+            },
+        }
+    }
+    generate(document, schema, 0)
+}
+
+/// plain - value of the example as it goes into the url, query or header
+fn plain(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+/// parameter - map the operation parameter, the schema keeps the declared default only
+fn parameter(document: &Value, value: &Value) -> Parameter {
+    let value = resolve(document, value);
+    let schema = value.get("schema").map(|s| resolve(document, s)).unwrap_or(&Value::Null);
+    let sample = value.get("example").cloned().unwrap_or_else(|| example(document, schema));
+    Parameter {
+        name: text(value, "name"),
+        in_field: text(value, "in"),
+        description: text(value, "description"),
+        required: value.get("required").and_then(Value::as_bool).unwrap_or(false),
+        explode: value.get("explode").and_then(Value::as_bool).unwrap_or(false),
+        schema: Schema {
+            type_field: text(schema, "type"),
+            default: schema.get("default").map(plain),
+            format: schema.get("format").and_then(Value::as_str).map(str::to_string),
+            enum_field: schema.get("enum").and_then(Value::as_array)
+                .map(|e| e.iter().map(plain).collect()),
+        },
+        example: Some(plain(&sample)),
+    }
+}
+
+/// parse - map the OpenAPI 3 document to the component model, one path entry per operation
+pub fn parse(document: &Value) -> EngineResult<Root> {
+    let openapi = text(document, "openapi");
+    if !openapi.starts_with('3') {
+        return Err(EngineError::Unsupported(format!("OpenAPI version '{openapi}', only 3.x is supported")));
+    }
+    let info = document.get("info").unwrap_or(&Value::Null);
+    let mut root = Root {
+        openapi,
+        info: Info {
+            title: text(info, "title"),
+            description: text(info, "description"),
+            terms_of_service: text(info, "termsOfService"),
+            version: text(info, "version"),
+            ..Default::default()
+        },
+        external_docs: ExternalDocs {
+            description: text(document.get("externalDocs").unwrap_or(&Value::Null), "description"),
+            url: text(document.get("externalDocs").unwrap_or(&Value::Null), "url"),
+        },
+        servers: document.get("servers").and_then(Value::as_array).into_iter().flatten()
+            .map(|server| Server { url: text(server, "url") })
+            .collect(),
+        tags: document.get("tags").and_then(Value::as_array).into_iter().flatten()
+            .map(|tag| Tag { name: text(tag, "name"), description: text(tag, "description"), external_docs: None })
+            .collect(),
+        components: Components {
+            schemas: document.pointer("/components/schemas").cloned().unwrap_or(Value::Null),
+        },
+        ..Default::default()
+    };
+    let Some(paths) = document.get("paths").and_then(Value::as_object) else {
+        return Ok(root);
+    };
+    for (path, item) in paths {
+        let shared = item.get("parameters").and_then(Value::as_array).cloned().unwrap_or_default();
+        for method in METHODS {
+            let Some(operation) = item.get(method) else { continue };
+            let mut parameters: Vec<Parameter> = shared.iter().map(|p| parameter(document, p)).collect();
+            for own in operation.get("parameters").and_then(Value::as_array).into_iter().flatten() {
+                let own = parameter(document, own);
+                parameters.retain(|p| !(p.name == own.name && p.in_field == own.in_field));
+                parameters.push(own);
+            }
+            let operation_id = match text(operation, "operationId") {
+                id if id.is_empty() => format!("{method} {path}"),
+                id => id,
+            };
+            root.paths.push(Path {
+                id: root.paths.len() as i32 + 1,
+                path: path.clone(),
+                method: method.to_string(),
+                tags: operation.get("tags").and_then(Value::as_array).into_iter().flatten()
+                    .map(plain)
+                    .collect(),
+                summary: text(operation, "summary"),
+                description: text(operation, "description"),
+                operation_id,
+                parameters,
+                responses: operation.get("responses").cloned().unwrap_or(Value::Null),
+                request_body: operation.get("requestBody")
+                    .map(|body| resolve(document, body).clone())
+                    .unwrap_or(Value::Null),
+                security: operation.get("security").and_then(Value::as_array).cloned().unwrap_or_default(),
+            });
+        }
+    }
+    Ok(root)
+}
+
+/// api_method - method of the API step, none for the method Orca can not send
+fn api_method(method: &str) -> Option<ApiMethod> {
+    let method = match method {
+        "get" => ApiMethod::Get,
+        "post" => ApiMethod::Post,
+        "put" => ApiMethod::Put,
+        "patch" => ApiMethod::Patch,
+        "delete" => ApiMethod::Delete,
+        "head" => ApiMethod::Head,
+        "options" => ApiMethod::Options,
+        _ => return None,
+    };
+    Some(method)
+}
+
+/// expected_status - lowest success status code declared for the operation
+pub fn expected_status(responses: &Value) -> Option<i32> {
+    responses.as_object()?
+        .keys()
+        .filter_map(|code| code.parse::<i32>().ok())
+        .filter(|code| (200..300).contains(code))
+        .min()
+}
+
+/// is_absolute - url with the scheme and the host
+fn is_absolute(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+/// OpenApiImporter - store the OpenAPI 3 document of the application and generate an
+/// API step per operation with the example parameters and the expected status code,
+/// the steps of the operations imported before are updated in place
+pub struct OpenApiImporter<'il> {
+    db: &'il DatabaseTransaction,
+    app_id: Uuid,
+}
+
+impl<'il> OpenApiImporter<'il> {
+    pub fn new(db: &'il DatabaseTransaction, app_id: Uuid) -> OpenApiImporter<'il> {
+        Self { db, app_id }
+    }
+
+    /// import - import the document, the first server of the document is the base url
+    /// unless the base url is given, a relative server needs the base url
+    pub async fn import(&self, document: Value, base_url: Option<String>) -> EngineResult<ImportReport> {
+        let root = parse(&document)?;
+        let mut report = ImportReport::default();
+        let base_url = match (base_url, root.servers.first()) {
+            (Some(base_url), _) => base_url,
+            (None, Some(server)) if is_absolute(&server.url) => server.url.clone(),
+            (None, server) => return Err(EngineError::MissingParameter(
+                "base_url".to_string(),
+                format!("server url '{}' of the document is relative", server.map(|s| s.url.as_str()).unwrap_or("/")),
+            )),
+        };
+        let spec = self.spec(&root.info.title, &root.info.version, &root.openapi, &document).await?;
+
+        for (index, path) in root.paths.iter().enumerate() {
+            let Some(method) = api_method(&path.method) else {
+                report.unsupported(&path.operation_id, index + 1, &path.method, "Unsupported method");
+                continue;
+            };
+            let mut url = format!("{}{}", base_url.trim_end_matches('/'), path.path);
+            let mut query = Map::new();
+            let mut headers = Map::new();
+            for param in path.parameters.iter() {
+                let value = param.example.clone().unwrap_or_default();
+                match param.in_field.as_str() {
+                    "path" => url = url.replace(&format!("{{{}}}", param.name), &value),
+                    "query" => {
+                        query.insert(param.name.clone(), Value::String(value));
+                    }
+                    "header" => {
+                        headers.insert(param.name.clone(), Value::String(value));
+                    }
+                    _ => report.unsupported(&path.operation_id, index + 1, &param.name,
+                                            format!("Unsupported parameter location '{}'", param.in_field)),
+                }
+            }
+            let body = self.body(&document, &path.request_body, &mut headers);
+            let existing = step::Entity::find()
+                .filter(step::Column::AppId.eq(self.app_id))
+                .filter(step::Column::OperationId.eq(path.operation_id.clone()))
+                .one(self.db).await?;
+            let (mut item, imported) = match existing {
+                Some(existing) => (existing.into_active_model(), true),
+                None => (step::ActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    auth: Set(None),
+                    timeout: Set(None),
+                    operation_id: Set(Some(path.operation_id.clone())),
+                    graphql: Set(None),
+                    app_id: Set(self.app_id),
+                    ..Default::default()
+                }, false),
+            };
+            item.name = Set(if path.summary.is_empty() { path.operation_id.clone() } else { path.summary.clone() });
+            item.description = Set((!path.description.is_empty()).then(|| path.description.clone()));
+            item.method = Set(method);
+            item.url = Set(url);
+            item.headers = Set((!headers.is_empty()).then_some(Value::Object(headers)));
+            item.query = Set((!query.is_empty()).then_some(Value::Object(query)));
+            item.body = Set(body);
+            item.expected_status = Set(expected_status(&path.responses));
+            item.spec_id = Set(Some(spec.id));
+            if imported {
+                item.update(self.db).await?;
+                report.updated_api_steps += 1;
+            } else {
+                item.insert(self.db).await?;
+                report.api_steps += 1;
+            }
+        }
+        info!("Imported OpenAPI document {} - {:?}", spec.title, report);
+        Ok(report)
+    }
+
+    /// spec - store the document, the document imported before under the same title is replaced
+    async fn spec(&self, title: &str, version: &str, openapi: &str, document: &Value) -> EngineResult<spec::Model> {
+        let existing = spec::Entity::find()
+            .filter(spec::Column::AppId.eq(self.app_id))
+            .filter(spec::Column::Title.eq(title))
+            .one(self.db).await?;
+        let (mut spec, imported) = match existing {
+            Some(existing) => (existing.into_active_model(), true),
+            None => (spec::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                title: Set(title.to_string()),
+                app_id: Set(self.app_id),
+                ..Default::default()
+            }, false),
+        };
+        spec.version = Set(version.to_string());
+        spec.openapi = Set(openapi.to_string());
+        spec.document = Set(document.clone());
+        spec.created_at = Set(chrono::Utc::now().into());
+        let spec = if imported { spec.update(self.db).await? } else { spec.insert(self.db).await? };
+        Ok(spec)
+    }
+
+    /// body - example of the json request body, the content type goes into the headers
+    fn body(&self, document: &Value, request_body: &Value, headers: &mut Map<String, Value>) -> Option<String> {
+        let content = request_body.get("content")?.as_object()?;
+        let (content_type, media) = content.iter()
+            .find(|(content_type, _)| content_type.contains("json"))?;
+        let value = media.get("example").cloned()
+            .or_else(|| media.get("examples")
+                .and_then(Value::as_object)
+                .and_then(|examples| examples.values().next())
+                .map(|e| resolve(document, e))
+                .and_then(|e| e.get("value").cloned()))
+            .unwrap_or_else(|| example(document, media.get("schema").unwrap_or(&Value::Null)));
+        headers.entry("content-type").or_insert_with(|| Value::String(content_type.clone()));
+        Some(value.to_string())
+    }
+}
@@ -0,0 +1,399 @@
+use sea_orm::{ActiveModelTrait, DatabaseTransaction};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::Uuid;
+use serde_json::{json, Map, Value};
+use tracing::info;
+
+use entity::api::step::{self, ApiAuth, ApiMethod};
+use entity::prelude::case_block::{self, BlockKind, BlockType};
+use entity::test::profile::{data as profile_data, profile};
+use entity::test::ui::case::case;
+
+use crate::controller::data::placeholders;
+use crate::error::{EngineError, EngineResult};
+use crate::import::ImportReport;
+
+/// PostmanGrouping - how the requests of the collection are grouped into the test cases
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum PostmanGrouping {
+    /// test case per top level folder, the requests outside the folders go in the collection case
+    #[default]
+    Folder,
+    /// single test case with all the requests of the collection
+    Collection,
+}
+
+impl PostmanGrouping {
+    pub fn parse(grouping: Option<&str>) -> EngineResult<Self> {
+        match grouping.map(str::to_lowercase).as_deref() {
+            None | Some("folder") => Ok(Self::Folder),
+            Some("collection") => Ok(Self::Collection),
+            Some(other) => Err(EngineError::Unsupported(format!("Postman grouping '{other}'"))),
+        }
+    }
+}
+
+/// text - string value of the key, empty when missing
+fn text(value: &Value, key: &str) -> String {
+    value.get(key).and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+/// enabled - the Postman key value entries that are not disabled
+fn enabled(value: Option<&Value>) -> impl Iterator<Item = &Value> {
+    value.and_then(Value::as_array).into_iter().flatten()
+        .filter(|entry| !entry.get("disabled").and_then(Value::as_bool).unwrap_or(false))
+        .filter(|entry| entry.get("enabled").and_then(Value::as_bool).unwrap_or(true))
+}
+
+/// translate - replace the Postman `{{var}}` variables with the Orca `${var}` placeholders
+pub fn translate(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else { break };
+        result.push_str(&rest[..start]);
+        result.push_str(&format!("${{{}}}", after[..end].trim()));
+        rest = &after[end + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// dynamic - Postman dynamic variables (`{{$guid}}`) used in the value, Orca has no value for them
+fn dynamic(value: &str) -> Vec<String> {
+    placeholders(&translate(value))
+        .into_iter()
+        .filter(|key| key.starts_with('$'))
+        .collect()
+}
+
+/// api_method - method of the API step, none for the method Orca can not send
+fn api_method(method: &str) -> Option<ApiMethod> {
+    let method = match method.to_uppercase().as_str() {
+        "GET" => ApiMethod::Get,
+        "POST" => ApiMethod::Post,
+        "PUT" => ApiMethod::Put,
+        "PATCH" => ApiMethod::Patch,
+        "DELETE" => ApiMethod::Delete,
+        "HEAD" => ApiMethod::Head,
+        "OPTIONS" => ApiMethod::Options,
+        _ => return None,
+    };
+    Some(method)
+}
+
+/// url - url without the query string and the query parameters of the Postman request url
+fn url(url: &Value) -> (String, Map<String, Value>) {
+    let mut query = Map::new();
+    let raw = match url {
+        Value::String(raw) => raw.clone(),
+        url => match url.get("raw").and_then(Value::as_str) {
+            Some(raw) => raw.to_string(),
+            None => {
+                let host = match url.get("host") {
+                    Some(Value::Array(parts)) => parts.iter().filter_map(Value::as_str).collect::<Vec<_>>().join("."),
+                    Some(host) => host.as_str().unwrap_or_default().to_string(),
+                    None => String::new(),
+                };
+                let path = match url.get("path") {
+                    Some(Value::Array(parts)) => parts.iter().filter_map(Value::as_str).collect::<Vec<_>>().join("/"),
+                    Some(path) => path.as_str().unwrap_or_default().trim_start_matches('/').to_string(),
+                    None => String::new(),
+                };
+                let protocol = url.get("protocol").and_then(Value::as_str)
+                    .map(|protocol| format!("{protocol}://"))
+                    .unwrap_or_default();
+                format!("{protocol}{host}/{path}")
+            }
+        },
+    };
+    let (base, raw_query) = match raw.split_once('?') {
+        Some((base, raw_query)) => (base.to_string(), Some(raw_query.to_string())),
+        None => (raw, None),
+    };
+    if let Some(entries) = url.get("query").filter(|q| q.is_array()) {
+        for entry in enabled(Some(entries)) {
+            query.insert(translate(&text(entry, "key")), Value::String(translate(&text(entry, "value"))));
+        }
+    } else if let Some(raw_query) = raw_query {
+        for pair in raw_query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            query.insert(translate(key), Value::String(translate(value)));
+        }
+    }
+    (translate(&base), query)
+}
+
+/// auth - Orca auth of the Postman auth, the `noauth` and the missing auth are none
+fn auth(auth: &Value) -> Result<Option<Value>, String> {
+    let kind = text(auth, "type");
+    let field = |name: &str| {
+        enabled(auth.get(&kind))
+            .find(|entry| text(entry, "key") == name)
+            .map(|entry| translate(&match entry.get("value") {
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+                None => String::new(),
+            }))
+            .unwrap_or_default()
+    };
+    let auth = match kind.as_str() {
+        "" | "noauth" => return Ok(None),
+        "basic" => ApiAuth::Basic { username: field("username"), password: Some(field("password")) },
+        "bearer" => ApiAuth::Bearer { token: field("token") },
+        "apikey" => ApiAuth::ApiKey { name: field("key"), value: field("value"), in_query: field("in") == "query" },
+        other => return Err(format!("Unsupported auth '{other}'")),
+    };
+    let auth = serde_json::to_value(auth).map_err(|err| err.to_string())?;
+    Ok(Some(auth))
+}
+
+/// graphql - GraphQL document of the Postman graphql body mode, the variables are kept as json
+fn graphql(body: &Value) -> Result<Value, String> {
+    let graphql = body.get("graphql").unwrap_or(&Value::Null);
+    let variables = match translate(&text(graphql, "variables")).trim() {
+        "" => None,
+        variables => Some(serde_json::from_str::<Value>(variables)
+            .map_err(|_| "GraphQL variables are not json".to_string())?),
+    };
+    Ok(json!({"query": translate(&text(graphql, "query")), "variables": variables}))
+}
+
+/// Group - test case the requests are imported into, with the auth inherited from the folders
+struct Group {
+    name: String,
+    requests: Vec<(Value, Value)>,
+}
+
+/// PostmanImporter - import the Postman v2.1 collection as the API steps grouped in the test
+/// cases and the Postman environment as the profile, the `{{var}}` variables become `${var}`
+pub struct PostmanImporter<'il> {
+    db: &'il DatabaseTransaction,
+    app_id: Uuid,
+}
+
+impl<'il> PostmanImporter<'il> {
+    pub fn new(db: &'il DatabaseTransaction, app_id: Uuid) -> PostmanImporter<'il> {
+        Self { db, app_id }
+    }
+
+    /// import - import the collection, the collection variables end up in a profile
+    pub async fn import(&self, collection: Value, grouping: PostmanGrouping) -> EngineResult<ImportReport> {
+        let info = collection.get("info").unwrap_or(&Value::Null);
+        let schema = text(info, "schema");
+        if !schema.contains("v2.1") && !schema.contains("v2.0") {
+            return Err(EngineError::Unsupported(format!(
+                "Postman collection schema '{schema}', only v2.1 is supported"
+            )));
+        }
+        let name = text(info, "name");
+        let mut report = ImportReport::default();
+        let collection_auth = collection.get("auth").cloned().unwrap_or(Value::Null);
+        let mut groups = vec![Group { name: name.clone(), requests: vec![] }];
+        for item in collection.get("item").and_then(Value::as_array).into_iter().flatten() {
+            match (&grouping, item.get("item")) {
+                (PostmanGrouping::Folder, Some(_)) => {
+                    let mut group = Group { name: text(item, "name"), requests: vec![] };
+                    Self::collect(item, &collection_auth, &mut group.requests);
+                    groups.push(group);
+                }
+                _ => Self::collect(item, &collection_auth, &mut groups[0].requests),
+            }
+        }
+        for group in groups.into_iter().filter(|group| !group.requests.is_empty()) {
+            self.import_group(&name, group, &mut report).await?;
+        }
+
+        let variables: Vec<&Value> = enabled(collection.get("variable")).collect();
+        if !variables.is_empty() {
+            self.profile(&format!("{name} variables"), &format!("Imported from {name}"), variables).await?;
+            report.profiles += 1;
+        }
+        info!("Imported Postman collection {} - {:?}", name, report);
+        Ok(report)
+    }
+
+    /// import_environment - import the Postman environment as the profile with the profile data
+    pub async fn import_environment(&self, environment: Value) -> EngineResult<ImportReport> {
+        let name = text(&environment, "name");
+        if environment.get("values").and_then(Value::as_array).is_none() {
+            return Err(EngineError::Unsupported("Postman environment without the values".to_string()));
+        }
+        let mut report = ImportReport::default();
+        let values: Vec<&Value> = enabled(environment.get("values")).collect();
+        self.profile(&name, "Imported from the Postman environment", values).await?;
+        report.profiles += 1;
+        info!("Imported Postman environment {} - {:?}", name, report);
+        Ok(report)
+    }
+
+    /// collect - requests of the item in the order of the collection with the auth they inherit
+    fn collect(item: &Value, auth: &Value, requests: &mut Vec<(Value, Value)>) {
+        let auth = item.get("auth").unwrap_or(auth);
+        match item.get("item").and_then(Value::as_array) {
+            Some(items) => items.iter().for_each(|item| Self::collect(item, auth, requests)),
+            None => requests.push((item.clone(), auth.clone())),
+        }
+    }
+
+    /// profile - create the profile with the variables as the profile data
+    async fn profile(&self, name: &str, description: &str, variables: Vec<&Value>) -> EngineResult<()> {
+        let profile = profile::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(name.to_string()),
+            description: Set(Some(description.to_string())),
+            app_id: Set(self.app_id),
+        }.insert(self.db).await?;
+        for variable in variables {
+            let value = match variable.get("value") {
+                Some(Value::String(value)) => value.clone(),
+                Some(Value::Null) | None => String::new(),
+                Some(value) => value.to_string(),
+            };
+            profile_data::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                key: Set(text(variable, "key")),
+                value_type: Set(match text(variable, "type").as_str() {
+                    "" | "default" | "any" => "string".to_string(),
+                    kind => kind.to_string(),
+                }),
+                value: Set(translate(&value)),
+                description: Set(None),
+                profile_id: Set(profile.id),
+            }.insert(self.db).await?;
+        }
+        Ok(())
+    }
+
+    /// import_group - create the API step of every request and the test case running them in order
+    async fn import_group(&self, collection: &str, group: Group, report: &mut ImportReport) -> EngineResult<()> {
+        let mut steps = vec![];
+        for (index, (item, inherited)) in group.requests.iter().enumerate() {
+            let name = text(item, "name");
+            if item.get("event").and_then(Value::as_array).map_or(false, |events| !events.is_empty()) {
+                report.unsupported(&group.name, index + 1, &name, "Pre-request and test scripts are not imported");
+            }
+            let request = match item.get("request") {
+                Some(Value::String(url)) => json!({"method": "GET", "url": url}),
+                Some(request) => request.clone(),
+                None => continue,
+            };
+            let method_name = text(&request, "method");
+            let Some(method) = api_method(if method_name.is_empty() { "GET" } else { &method_name }) else {
+                report.unsupported(&group.name, index + 1, &name, format!("Unsupported method '{method_name}'"));
+                continue;
+            };
+            let (url, query) = url(request.get("url").unwrap_or(&Value::Null));
+            let mut headers = Map::new();
+            for header in enabled(request.get("header")) {
+                headers.insert(translate(&text(header, "key")), Value::String(translate(&text(header, "value"))));
+            }
+            let converted = match request.get("body") {
+                Some(body) if text(body, "mode") == "graphql" => graphql(body).map(|graphql| (None, Some(graphql))),
+                body => self.body(body, &mut headers).map(|body| (body, None)),
+            };
+            let (body, graphql) = match converted {
+                Ok(converted) => converted,
+                Err(reason) => {
+                    report.unsupported(&group.name, index + 1, &name, reason);
+                    continue;
+                }
+            };
+            let auth = match auth(request.get("auth").unwrap_or(inherited)) {
+                Ok(auth) => auth,
+                Err(reason) => {
+                    report.unsupported(&group.name, index + 1, &name, reason);
+                    None
+                }
+            };
+            let raw = request.to_string();
+            for variable in dynamic(&raw) {
+                report.unsupported(&group.name, index + 1, &name, format!("Postman dynamic variable '{variable}'"));
+            }
+            let description = match request.get("description") {
+                Some(Value::String(description)) => Some(description.clone()),
+                Some(description) => description.get("content").and_then(Value::as_str).map(str::to_string),
+                None => None,
+            };
+            let step = step::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                name: Set(name),
+                description: Set(description.filter(|d| !d.is_empty())),
+                method: Set(method),
+                url: Set(url),
+                headers: Set((!headers.is_empty()).then_some(Value::Object(headers))),
+                query: Set((!query.is_empty()).then_some(Value::Object(query))),
+                body: Set(body),
+                auth: Set(auth),
+                expected_status: Set(None),
+                timeout: Set(None),
+                spec_id: Set(None),
+                operation_id: Set(None),
+                graphql: Set(graphql),
+                app_id: Set(self.app_id),
+            }.insert(self.db).await?;
+            report.api_steps += 1;
+            steps.push(step);
+        }
+        if steps.is_empty() {
+            return Ok(());
+        }
+        let case = case::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(group.name.clone()),
+            description: Set(Some(format!("Imported from {collection}"))),
+            app_id: Set(self.app_id),
+        }.insert(self.db).await?;
+        for (order, step) in steps.iter().enumerate() {
+            case_block::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                execution_order: Set(order as i32 + 1),
+                kind: Set(BlockKind::Reference),
+                name: Set(Some(step.name.clone())),
+                desc: Set(None),
+                type_field: Set(BlockType::ApiStep),
+                reference: Set(Some(step.id)),
+                parent_id: Set(None),
+                timeout: Set(None),
+                case_id: Set(case.id),
+            }.insert(self.db).await?;
+        }
+        report.cases += 1;
+        Ok(())
+    }
+
+    /// body - request body of the raw and url encoded modes, the content type goes
+    /// into the headers when the request does not have one
+    fn body(&self, body: Option<&Value>, headers: &mut Map<String, Value>) -> Result<Option<String>, String> {
+        let Some(body) = body.filter(|body| !body.is_null()) else {
+            return Ok(None);
+        };
+        let has_content_type = headers.keys().any(|name| name.eq_ignore_ascii_case("content-type"));
+        let (content_type, value) = match text(body, "mode").as_str() {
+            "" | "none" => return Ok(None),
+            "raw" => {
+                let language = body.pointer("/options/raw/language").and_then(Value::as_str).unwrap_or("text");
+                let content_type = match language {
+                    "json" => "application/json",
+                    "xml" => "application/xml",
+                    "html" => "text/html",
+                    "javascript" => "application/javascript",
+                    _ => "text/plain",
+                };
+                (content_type, translate(&text(body, "raw")))
+            }
+            "urlencoded" => {
+                let pairs: Vec<String> = enabled(body.get("urlencoded"))
+                    .map(|entry| format!("{}={}", translate(&text(entry, "key")), translate(&text(entry, "value"))))
+                    .collect();
+                ("application/x-www-form-urlencoded", pairs.join("&"))
+            }
+            mode => return Err(format!("Unsupported body mode '{mode}'")),
+        };
+        if !has_content_type {
+            headers.insert("content-type".to_string(), Value::String(content_type.to_string()));
+        }
+        Ok(Some(value))
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+
+use sea_orm::{ActiveModelTrait, DatabaseTransaction};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::Uuid;
+use serde::Deserialize;
+use tracing::info;
+
+use entity::prelude::case_block::{self, BlockKind, BlockType};
+use entity::prelude::target::ActionTargetKind;
+use entity::test::ui::action::action::{self, ActionKind};
+use entity::test::ui::action::data::ActionDataKind;
+use entity::test::ui::action::group::{self, ActionGroupKind};
+use entity::test::ui::case::case;
+use entity::test::ui::suit::suite;
+use entity::test::ui::suit::suite_block::{self, SuiteBlockType};
+
+use crate::error::EngineResult;
+use crate::import::ImportReport;
+
+/// SideProject - Selenium IDE project (`.side` file)
+#[derive(Debug, Clone, Deserialize)]
+pub struct SideProject {
+    pub name: String,
+    /// base url the relative `open` targets are resolved against
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub tests: Vec<SideTest>,
+    #[serde(default)]
+    pub suites: Vec<SideSuite>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SideTest {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub commands: Vec<SideCommand>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SideCommand {
+    pub command: String,
+    #[serde(default)]
+    pub target: String,
+    #[serde(default)]
+    pub value: String,
+    #[serde(default)]
+    pub comment: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SideSuite {
+    pub name: String,
+    /// ids of the tests in the suite
+    #[serde(default)]
+    pub tests: Vec<String>,
+}
+
+/// side_target - split the SIDE target (`id=login`, `css=.btn`, `//a`) into the target kind
+/// and the selector
+pub fn side_target(target: &str) -> Option<(ActionTargetKind, String)> {
+    if target.starts_with("//") || target.starts_with("(//") {
+        return Some((ActionTargetKind::Xpath, target.to_string()));
+    }
+    let (strategy, selector) = target.split_once('=')?;
+    let kind = match strategy {
+        "id" => ActionTargetKind::Id,
+        "css" => ActionTargetKind::Css,
+        "xpath" => ActionTargetKind::Xpath,
+        "name" => ActionTargetKind::Name,
+        "linkText" | "link" => ActionTargetKind::LinkText,
+        "partialLinkText" => ActionTargetKind::PartialLinkText,
+        _ => return None,
+    };
+    Some((kind, selector.to_string()))
+}
+
+/// side_kind - action kind of the SIDE command, none for the unsupported command
+fn side_kind(command: &str) -> Option<ActionKind> {
+    let kind = match command {
+        "open" => ActionKind::Open,
+        "click" | "clickAt" => ActionKind::Click,
+        "type" | "sendKeys" => ActionKind::Enter,
+        "assertText" | "verifyText" => ActionKind::VerifyText,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// side_key - WebDriver code point of the SIDE `${KEY_*}` key
+fn side_key(name: &str) -> Option<char> {
+    let key = match name {
+        "KEY_BACKSPACE" | "KEY_BKSP" => '\u{E003}',
+        "KEY_TAB" => '\u{E004}',
+        "KEY_ENTER" => '\u{E007}',
+        "KEY_SHIFT" => '\u{E008}',
+        "KEY_CONTROL" | "KEY_CTRL" => '\u{E009}',
+        "KEY_ALT" => '\u{E00A}',
+        "KEY_ESC" | "KEY_ESCAPE" => '\u{E00C}',
+        "KEY_SPACE" => '\u{E00D}',
+        "KEY_PAGE_UP" | "KEY_PGUP" => '\u{E00E}',
+        "KEY_PAGE_DOWN" | "KEY_PGDN" => '\u{E00F}',
+        "KEY_END" => '\u{E010}',
+        "KEY_HOME" => '\u{E011}',
+        "KEY_LEFT" => '\u{E012}',
+        "KEY_UP" => '\u{E013}',
+        "KEY_RIGHT" => '\u{E014}',
+        "KEY_DOWN" => '\u{E015}',
+        "KEY_INSERT" | "KEY_INS" => '\u{E016}',
+        "KEY_DELETE" | "KEY_DEL" => '\u{E017}',
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// side_keys - replace the SIDE `${KEY_*}` tokens of the typed value with the WebDriver keys,
+/// so they are not taken for the runtime placeholders, the unknown key is sent back as error
+pub fn side_keys(value: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${KEY_") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else { break };
+        let name = &after[..end];
+        result.push_str(&rest[..start]);
+        result.push(side_key(name).ok_or_else(|| name.to_string())?);
+        rest = &after[end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// url - resolve the relative `open` target against the project url
+fn url(base: &str, target: &str) -> String {
+    if target.contains("://") || base.is_empty() {
+        return target.to_string();
+    }
+    format!("{}/{}", base.trim_end_matches('/'), target.trim_start_matches('/'))
+}
+
+/// SideImporter - map the SIDE tests to the action groups wrapped in a test case and the
+/// SIDE suites to the test suites, the commands that can not be mapped end up in the report
+pub struct SideImporter<'il> {
+    db: &'il DatabaseTransaction,
+    app_id: Uuid,
+}
+
+impl<'il> SideImporter<'il> {
+    pub fn new(db: &'il DatabaseTransaction, app_id: Uuid) -> SideImporter<'il> {
+        Self { db, app_id }
+    }
+
+    pub async fn import(&self, project: SideProject) -> EngineResult<ImportReport> {
+        let mut report = ImportReport::default();
+        let mut cases: HashMap<String, Uuid> = HashMap::new();
+        for test in project.tests.iter() {
+            let case_id = self.import_test(&project, test, &mut report).await?;
+            cases.insert(test.id.clone(), case_id);
+        }
+        for side_suite in project.suites.iter() {
+            let suite = suite::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                name: Set(side_suite.name.clone()),
+                description: Set(Some(format!("Imported from {}", project.name))),
+                app_id: Set(self.app_id),
+            }.insert(self.db).await?;
+            for (order, test_id) in side_suite.tests.iter().enumerate() {
+                let Some(case_id) = cases.get(test_id) else {
+                    report.unsupported(&side_suite.name, order + 1, test_id, "Test not found in the project");
+                    continue;
+                };
+                suite_block::ActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    execution_order: Set(order as i32 + 1),
+                    type_field: Set(SuiteBlockType::TestCase),
+                    reference: Set(Some(*case_id)),
+                    suite_id: Set(suite.id),
+                }.insert(self.db).await?;
+            }
+            report.suites += 1;
+        }
+        info!("Imported SIDE project {} - {:?}", project.name, report);
+        Ok(report)
+    }
+
+    /// import_test - create the action group of the test and the case referencing it
+    async fn import_test(&self, project: &SideProject, test: &SideTest,
+                         report: &mut ImportReport) -> EngineResult<Uuid> {
+        let group = group::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(test.name.clone()),
+            description: Set(Some(format!("Imported from {}", project.name))),
+            type_field: Set(ActionGroupKind::ActionGroup),
+            app_id: Set(self.app_id),
+        }.insert(self.db).await?;
+        let mut order = 0;
+        for (index, command) in test.commands.iter().enumerate() {
+            if command.command.is_empty() {
+                continue;
+            }
+            let Some(kind) = side_kind(&command.command) else {
+                report.unsupported(&test.name, index + 1, &command.command, "Unsupported command");
+                continue;
+            };
+            let (target, data) = match kind {
+                ActionKind::Open => (None, Some(url(&project.url, &command.target))),
+                ActionKind::Click => (Some(&command.target), None),
+                ActionKind::Enter => match side_keys(&command.value) {
+                    Ok(value) => (Some(&command.target), Some(value)),
+                    Err(key) => {
+                        report.unsupported(&test.name, index + 1, &command.command,
+                                           format!("Unsupported key '{key}'"));
+                        continue;
+                    }
+                },
+                _ => (Some(&command.target), Some(command.value.clone())),
+            };
+            let target = match target.map(|t| (t, side_target(t))) {
+                Some((_, Some(target))) => Some(target),
+                Some((raw, None)) => {
+                    report.unsupported(&test.name, index + 1, &command.command,
+                                       format!("Unsupported target '{raw}'"));
+                    continue;
+                }
+                None => None,
+            };
+            order += 1;
+            action::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                execution_order: Set(order),
+                description: Set((!command.comment.is_empty()).then(|| command.comment.clone())),
+                kind: Set(kind),
+                data_kind: Set(data.as_ref().map(|_| ActionDataKind::Static)),
+                data_value: Set(data),
+                target_kind: Set(target.as_ref().map(|(kind, _)| kind.clone())),
+                target_value: Set(target.map(|(_, selector)| selector)),
+                element_id: Set(None),
+                timeout: Set(None),
+                action_group_id: Set(group.id),
+            }.insert(self.db).await?;
+            report.actions += 1;
+        }
+        report.action_groups += 1;
+
+        let case = case::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(test.name.clone()),
+            description: Set(Some(format!("Imported from {}", project.name))),
+            app_id: Set(self.app_id),
+        }.insert(self.db).await?;
+        case_block::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            execution_order: Set(1),
+            kind: Set(BlockKind::Reference),
+            name: Set(Some(test.name.clone())),
+            desc: Set(None),
+            type_field: Set(BlockType::ActionGroup),
+            reference: Set(Some(group.id)),
+            parent_id: Set(None),
+            timeout: Set(None),
+            case_id: Set(case.id),
+        }.insert(self.db).await?;
+        report.cases += 1;
+        Ok(case.id)
+    }
+}
@@ -2,5 +2,7 @@ extern crate entity;
 
 pub mod controller;
 pub mod error;
+pub mod export;
+pub mod import;
 pub mod server;
 pub mod ui;
@@ -0,0 +1,323 @@
+use std::sync::Arc;
+
+use sea_orm::{ActiveModelTrait, IntoActiveModel, TransactionTrait};
+#[cfg(feature = "cdp")]
+use sea_orm::EntityTrait;
+use sea_orm::ActiveValue::Set;
+use tracing::{error, info};
+
+use cerium::client::Client;
+use cerium::client::driver::Driver;
+use cerium::client::driver::pool::SessionPool;
+use cerium::client::driver::service::{DriverKind, DriverService};
+use cerium::client::driver::web::WebDriver;
+#[cfg(feature = "cdp")]
+use cerium::client::driver::chrome::ChromeDriver;
+use entity::test::ui::ExecutionRequest;
+use entity::test::ui::log::item_log::ItemLogStatus;
+#[cfg(feature = "cdp")]
+use entity::test::ui::request;
+use entity::test::ui::request::{ExecutionStatus, ExecutionType};
+
+use crate::controller::case::CaseController;
+#[cfg(feature = "api-automation")]
+use crate::controller::channel::Channels;
+use crate::controller::data::{DataScope, Variables};
+#[cfg(feature = "api-automation")]
+use crate::controller::load::{LoadConfig, LoadController};
+#[cfg(feature = "api-automation")]
+use crate::controller::sql::SqlConnections;
+use crate::controller::{close_running, with_timeout};
+use crate::controller::suite::SuiteController;
+use crate::controller::validator::Validator;
+use crate::error::{EngineError, EngineResult};
+use crate::server::mock::{MockConfig, MockServer};
+use crate::server::registry::{ExecutionRegistry, ExecutionToken};
+
+/// Executor - run the execution request in the background and keep the
+/// execution request status up to date
+pub struct Executor {
+    cli: Client,
+    registry: ExecutionRegistry,
+    pool: Option<SessionPool>,
+}
+
+impl Executor {
+    pub fn new(cli: Client, registry: ExecutionRegistry) -> Self {
+        Self { cli, registry, pool: None }
+    }
+
+    /// with_pool - lease the default browser session from the pool instead of
+    /// opening a new one for every execution
+    pub fn with_pool(mut self, pool: Option<SessionPool>) -> Self {
+        self.pool = pool;
+        self
+    }
+
+    /// trigger - spawn the execution request in the background,
+    /// the execution request has to be committed before triggering.
+    /// The run is supervised so the execution is finalized even when it panics
+    pub fn trigger(&self, er: ExecutionRequest) {
+        let token = self.registry.register(er.id);
+        let cli = self.cli.clone();
+        let registry = self.registry.clone();
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            let run = {
+                let (cli, er, token) = (cli.clone(), er.clone(), token.clone());
+                tokio::spawn(async move { Self::execute(&cli, pool.as_ref(), &er, &token).await })
+            };
+            let status = match run.await {
+                Ok(Ok(_)) if token.is_cancelled() => ExecutionStatus::Cancelled,
+                Ok(Ok(_)) => ExecutionStatus::Completed,
+                Ok(Err(EngineError::Timeout(item, ms))) => {
+                    error!("[{er}] {item} Timed out after {ms}ms", er = er.id);
+                    ExecutionStatus::TimedOut
+                }
+                Ok(Err(err)) => {
+                    error!("[{er}] Execution Failed - {:?}", err, er = er.id);
+                    ExecutionStatus::Failed
+                }
+                Err(err) => {
+                    error!("[{er}] Execution Panicked - {:?}", err, er = er.id);
+                    ExecutionStatus::Failed
+                }
+            };
+            registry.remove(er.id);
+            if let Err(err) = Self::finalize(&cli, er, status).await {
+                error!("Unable to update the Execution Request - {:?}", err);
+            }
+        });
+    }
+
+    /// execute - run the execution request with a new browser session on the
+    /// requested backend (selenium hub by default, local for the geckodriver / chromedriver /
+    /// msedgedriver process, cdp for the local chrome)
+    async fn execute(cli: &Client, pool: Option<&SessionPool>, er: &ExecutionRequest,
+                     token: &ExecutionToken) -> EngineResult<()> {
+        let mut er_am = er.clone().into_active_model();
+        er_am.status = Set(ExecutionStatus::Running);
+        er_am.updated_at = Set(chrono::Utc::now().into());
+        er_am.save(cli.db()).await?;
+
+        if er.is_dry_run {
+            return Self::dry_run(cli, er).await;
+        }
+        #[cfg(feature = "api-automation")]
+        if let Some(config) = LoadConfig::from_request(er)? {
+            return Self::load_test(cli, er, token, &config).await;
+        }
+        info!("[{er}] Starting Execution for {ref_id}", er = er.id, ref_id = er.ref_id);
+        match Self::backend(er) {
+            #[cfg(feature = "cdp")]
+            Some("cdp") => {
+                let chrome = Arc::new(ChromeDriver::launch(Self::headless(er)).await?);
+                let result = Self::run_and_quit(cli, er, token, chrome.clone()).await;
+                if let Err(err) = Self::save_devtools(cli, er, &chrome).await {
+                    error!("[{er}] Unable to save the devtools logs - {:?}", err, er = er.id);
+                }
+                result
+            }
+            Some("local") => {
+                let kind = DriverKind::for_browser(Self::browser(er).unwrap_or_default());
+                let service = DriverService::start(kind).await?;
+                let result = match WebDriver::local(&service).await {
+                    Ok(driver) => Self::run_and_quit(cli, er, token, Arc::new(driver)).await,
+                    Err(err) => Err(err.into()),
+                };
+                // tear down the local driver process even if the session failed
+                if let Err(err) = service.stop().await {
+                    error!("[{er}] Unable to stop the local driver - {:?}", err, er = er.id);
+                }
+                result
+            }
+            #[cfg(not(feature = "cdp"))]
+            Some("cdp") => Err(EngineError::Unsupported(
+                "cdp backend, engine is built without the cdp feature".to_string(),
+            )),
+            _ => match (pool, Self::browser(er)) {
+                (Some(pool), None) => {
+                    let lease = pool.lease().await?;
+                    let result = Self::run(cli, er, token, lease.driver()).await;
+                    lease.release().await;
+                    result
+                }
+                (_, Some(browser)) => {
+                    let driver = Arc::new(WebDriver::with_browser(browser).await?);
+                    Self::run_and_quit(cli, er, token, driver).await
+                }
+                (None, None) => {
+                    let driver = Arc::new(WebDriver::default().await?);
+                    Self::run_and_quit(cli, er, token, driver).await
+                }
+            },
+        }
+    }
+
+    /// run - run the execution request on the driver with the mock server if requested,
+    /// logs are committed even when the execution fails
+    async fn run(cli: &Client, er: &ExecutionRequest, token: &ExecutionToken,
+                 driver: Arc<dyn Driver>) -> EngineResult<()> {
+        let mock = MockServer::for_execution(cli.db(), er).await?;
+        let variables = Variables::new();
+        if let Some(mock) = &mock {
+            variables.set("mock_url", mock.url());
+        }
+        #[cfg(feature = "api-automation")]
+        let (channels, connections) = (Channels::new(), SqlConnections::new());
+        let trx = cli.db().begin().await?;
+        let start = chrono::Utc::now();
+        let run = async {
+            match er.ref_type {
+                ExecutionType::TestCase => {
+                    let controller = CaseController::new(&trx, driver.clone(), cli.clone(), token.clone())
+                        .with_variables(variables.clone());
+                    #[cfg(feature = "api-automation")]
+                    let controller = controller.with_channels(channels.clone())
+                        .with_connections(connections.clone());
+                    controller.run(er.ref_id, er, None).await
+                }
+                ExecutionType::TestSuite => {
+                    let controller = SuiteController::new(&trx, driver.clone(), cli.clone(), token.clone())
+                        .with_variables(variables.clone());
+                    #[cfg(feature = "api-automation")]
+                    let controller = controller.with_channels(channels.clone())
+                        .with_connections(connections.clone());
+                    controller.run(er.ref_id, er).await
+                }
+            }
+        };
+        let result = with_timeout("Execution", er.timeout, run).await;
+        if let Err(EngineError::Timeout(..)) = &result {
+            if let Err(err) = close_running(&trx, er, start.into(), ItemLogStatus::TimedOut).await {
+                error!("[{er}] Unable to close the running logs - {:?}", err, er = er.id);
+            }
+        }
+        // stop the mock server and close the connections once the execution is done
+        drop(mock);
+        #[cfg(feature = "api-automation")]
+        {
+            channels.close_all();
+            connections.close_all().await;
+        }
+        // keep the logs even if the execution failed or timed out
+        let commit = trx.commit().await;
+        result?;
+        commit?;
+        Ok(())
+    }
+
+    /// run_and_quit - run the execution request and close the browser session
+    /// even if the execution failed
+    async fn run_and_quit(cli: &Client, er: &ExecutionRequest, token: &ExecutionToken,
+                          driver: Arc<dyn Driver>) -> EngineResult<()> {
+        let result = Self::run(cli, er, token, driver.clone()).await;
+        let quit = driver.quit().await;
+        result?;
+        quit?;
+        Ok(())
+    }
+
+    /// save_devtools - save the console logs and network events captured over the
+    /// devtools protocol on the execution request report
+    #[cfg(feature = "cdp")]
+    async fn save_devtools(cli: &Client, er: &ExecutionRequest, chrome: &ChromeDriver) -> EngineResult<()> {
+        // merge into the latest report, the execution may have saved its own already
+        let er = request::Entity::find_by_id(er.id).one(cli.db()).await?
+            .ok_or(EngineError::MissingParameter("ExecutionRequest".to_string(), er.id.to_string()))?;
+        let mut report = match er.report.clone() {
+            Some(serde_json::Value::Object(report)) => report,
+            _ => serde_json::Map::new(),
+        };
+        report.insert("console_logs".to_string(), serde_json::to_value(chrome.console_logs())?);
+        report.insert("network_events".to_string(), serde_json::to_value(chrome.network_events())?);
+        let mut er_am = er.into_active_model();
+        er_am.report = Set(Some(serde_json::Value::Object(report)));
+        er_am.updated_at = Set(chrono::Utc::now().into());
+        er_am.save(cli.db()).await?;
+        Ok(())
+    }
+
+    /// dry_run - validate the execution request without the browser and save the report
+    /// on the execution request
+    async fn dry_run(cli: &Client, er: &ExecutionRequest) -> EngineResult<()> {
+        info!("[{er}] Starting Dry Run for {ref_id}", er = er.id, ref_id = er.ref_id);
+        let trx = cli.db().begin().await?;
+        let mut runtime = DataScope::new();
+        if MockConfig::from_request(er).is_some() {
+            runtime.insert("mock_url", "<mock_url>");
+        }
+        let validator = Validator::new(&trx).with_runtime(runtime);
+        let report = with_timeout("Execution", er.timeout, validator.validate(er)).await;
+        trx.rollback().await?;
+        let report = report?;
+
+        let mut er_am = er.clone().into_active_model();
+        er_am.report = Set(Some(serde_json::to_value(&report)?));
+        er_am.updated_at = Set(chrono::Utc::now().into());
+        er_am.save(cli.db()).await?;
+        if !report.is_valid() {
+            return Err(EngineError::ValidationFailed(report.issues.len()));
+        }
+        Ok(())
+    }
+
+    /// load_test - send the API steps of the test case with the virtual users and save the
+    /// report on the execution request, the load test fails above the max error rate, the
+    /// partial report is saved when the execution times out
+    #[cfg(feature = "api-automation")]
+    async fn load_test(cli: &Client, er: &ExecutionRequest, token: &ExecutionToken,
+                       config: &LoadConfig) -> EngineResult<()> {
+        if er.ref_type != ExecutionType::TestCase {
+            return Err(EngineError::Unsupported("load test of the test suite, only a test case can be load tested".to_string()));
+        }
+        let mock = MockServer::for_execution(cli.db(), er).await?;
+        let variables = Variables::new();
+        if let Some(mock) = &mock {
+            variables.set("mock_url", mock.url());
+        }
+        let trx = cli.db().begin().await?;
+        let controller = LoadController::new(&trx, variables, token.clone());
+        let report = controller.run(er.ref_id, er, config).await;
+        drop(mock);
+        trx.rollback().await?;
+        let report = report?;
+
+        let mut er_am = er.clone().into_active_model();
+        er_am.report = Set(Some(serde_json::to_value(&report)?));
+        er_am.updated_at = Set(chrono::Utc::now().into());
+        er_am.save(cli.db()).await?;
+        if report.timed_out {
+            return Err(EngineError::Timeout("Execution".to_string(), er.timeout.unwrap_or_default()));
+        }
+        report.check(config.max_error_rate).map_err(EngineError::AssertionFailed)
+    }
+
+    /// browser - get the browser name from the execution request args if requested
+    fn browser(er: &ExecutionRequest) -> Option<&str> {
+        er.args.as_ref()?.get("browser")?.get("name")?.as_str()
+    }
+
+    /// backend - get the driver backend from the execution request args if requested
+    fn backend(er: &ExecutionRequest) -> Option<&str> {
+        er.args.as_ref()?.get("browser")?.get("backend")?.as_str()
+    }
+
+    /// headless - run the local chrome without the window unless requested
+    #[cfg(feature = "cdp")]
+    fn headless(er: &ExecutionRequest) -> bool {
+        er.args.as_ref()
+            .and_then(|args| args.get("browser")?.get("headless")?.as_bool())
+            .unwrap_or(true)
+    }
+
+    async fn finalize(cli: &Client, er: ExecutionRequest, status: ExecutionStatus) -> EngineResult<()> {
+        info!("[{er}] Execution finished with {:?}", status, er = er.id);
+        let mut er_am = er.into_active_model();
+        er_am.status = Set(status);
+        er_am.finished_at = Set(chrono::Utc::now().into());
+        er_am.updated_at = Set(chrono::Utc::now().into());
+        er_am.save(cli.db()).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::{Json, Router};
+use axum::body::{Body, Bytes};
+use axum::extract::State;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri};
+use axum::http::header::{CONNECTION, CONTENT_LENGTH};
+use axum::middleware::map_response;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use futures_util::stream;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, NotSet, QueryFilter};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::Uuid;
+use serde_json::{Map, Value};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tracing::{error, info};
+
+use entity::common::attachment::{self, StorageCategory};
+use entity::test::mock::endpoint::{self, MockFault};
+use entity::test::mock::request as mock_request;
+use entity::test::ui::case::case;
+use entity::test::ui::ExecutionRequest;
+use entity::test::ui::request::ExecutionType;
+use entity::test::ui::suit::suite;
+
+use crate::error::{EngineError, EngineResult};
+
+/// RECORDED_PATH - path the mock server answers with the requests it received so far
+pub const RECORDED_PATH: &str = "/__orca/requests";
+
+/// MockRoute - endpoint with the canned response body of the attachment
+#[derive(Debug, Clone)]
+struct MockRoute {
+    endpoint: endpoint::Model,
+    body: Vec<u8>,
+}
+
+/// MockState - state shared by the requests of the mock server
+#[derive(Clone)]
+struct MockState {
+    db: DatabaseConnection,
+    execution_id: i32,
+    routes: Arc<Vec<MockRoute>>,
+    recorded: Arc<Mutex<Vec<mock_request::Model>>>,
+}
+
+/// MockConfig - mock server of the execution from the request args,
+/// `"mock": true` or `"mock": {"host": "0.0.0.0", "port": 8089}`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockConfig {
+    pub host: String,
+    pub port: u16,
+}
+
+impl MockConfig {
+    pub fn from_request(er: &ExecutionRequest) -> Option<Self> {
+        let mock = er.args.as_ref()?.get("mock")?;
+        let config = match mock {
+            Value::Bool(true) => Self { host: "127.0.0.1".to_string(), port: 0 },
+            Value::Object(mock) => Self {
+                host: mock.get("host").and_then(Value::as_str).unwrap_or("127.0.0.1").to_string(),
+                port: mock.get("port").and_then(Value::as_u64).unwrap_or(0) as u16,
+            },
+            _ => return None,
+        };
+        Some(config)
+    }
+}
+
+/// MockServer - http server hosting the mock endpoints of the application for a single
+/// execution, every request received is recorded against the execution, the server shuts
+/// down with its connections once dropped
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<mock_request::Model>>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// for_execution - start the mock server if the execution request asks for one
+    pub async fn for_execution(db: &DatabaseConnection, er: &ExecutionRequest) -> EngineResult<Option<Self>> {
+        let Some(config) = MockConfig::from_request(er) else {
+            return Ok(None);
+        };
+        let app_id = match er.ref_type {
+            ExecutionType::TestCase => case::Entity::find_by_id(er.ref_id).one(db).await?.map(|c| c.app_id),
+            ExecutionType::TestSuite => suite::Entity::find_by_id(er.ref_id).one(db).await?.map(|s| s.app_id),
+        }.ok_or(EngineError::MissingParameter("mock.app".to_string(), er.ref_id.into()))?;
+        let server = Self::start(db.clone(), er.id, app_id, &config).await?;
+        info!("[{er}] Mock server listening on {url}", er = er.id, url = server.url());
+        Ok(Some(server))
+    }
+
+    /// start - load the mock endpoints of the application and start listening
+    pub async fn start(db: DatabaseConnection, execution_id: i32, app_id: Uuid,
+                       config: &MockConfig) -> EngineResult<Self> {
+        let endpoints = endpoint::Entity::find()
+            .filter(endpoint::Column::AppId.eq(app_id))
+            .all(&db)
+            .await?;
+        let mut routes = Vec::with_capacity(endpoints.len());
+        for endpoint in endpoints {
+            let body = match endpoint.attachment_id {
+                Some(attachment_id) => Self::body(&db, attachment_id).await?,
+                None => vec![],
+            };
+            routes.push(MockRoute { endpoint, body });
+        }
+        let listener = TcpListener::bind((config.host.as_str(), config.port)).await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let requests = Arc::new(Mutex::new(vec![]));
+        let state = MockState { db, execution_id, routes: Arc::new(routes), recorded: requests.clone() };
+        let app = Router::new()
+            .route(RECORDED_PATH, get(recorded).fallback(answer))
+            .fallback(answer)
+            .layer(map_response(close))
+            .with_state(state);
+        let (shutdown, signal) = oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let server = axum::serve(listener, app).with_graceful_shutdown(async {
+                let _ = signal.await;
+            });
+            if let Err(err) = server.await {
+                error!("Mock server stopped - {:?}", err);
+            }
+        });
+        Ok(Self { url, requests, shutdown: Some(shutdown) })
+    }
+
+    /// body - canned response body from the `MockData` attachment
+    async fn body(db: &DatabaseConnection, attachment_id: i32) -> EngineResult<Vec<u8>> {
+        let attachment = attachment::Entity::find_by_id(attachment_id).one(db).await?
+            .ok_or(EngineError::MissingParameter("mock.attachment".to_string(), attachment_id.to_string()))?;
+        if attachment.category != StorageCategory::MockData {
+            return Err(EngineError::Unsupported(format!(
+                "attachment {attachment_id} as the mock response, only the MockData attachment can be used"
+            )));
+        }
+        Ok(attachment.attachment.unwrap_or_default())
+    }
+
+    /// url - base url of the mock server, also given to the steps as `${mock_url}`
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// requests - requests received by the mock server so far
+    pub fn requests(&self) -> Vec<mock_request::Model> {
+        self.requests.lock().unwrap().clone()
+    }
+
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// close - answer every request on its own connection
+async fn close(mut response: Response) -> Response {
+    response.headers_mut().insert(CONNECTION, HeaderValue::from_static("close"));
+    response
+}
+
+/// recorded - requests received by the mock server so far
+async fn recorded(State(state): State<MockState>) -> Json<Vec<mock_request::Model>> {
+    Json(state.recorded.lock().unwrap().clone())
+}
+
+/// answer - answer the request with the first matching endpoint
+async fn answer(State(state): State<MockState>, method: Method, uri: Uri, headers: HeaderMap,
+                body: Bytes) -> Response {
+    match state.answer(method, uri, headers, body).await {
+        Ok(response) => response,
+        Err(err) => {
+            error!("Mock server unable to answer the request - {:?}", err);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// aborted - response dropping the connection after `body`, before any byte when empty
+fn aborted(body: Vec<u8>) -> Response {
+    let length = body.len() + 1;
+    let chunks = (!body.is_empty()).then(|| Ok(Bytes::from(body)));
+    let reset = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "mock fault");
+    let stream = stream::iter(chunks.into_iter().chain([Err(reset)]));
+    let mut response = Response::new(Body::from_stream(stream));
+    response.headers_mut().insert(CONTENT_LENGTH, HeaderValue::from(length));
+    response
+}
+
+impl MockState {
+    async fn answer(&self, method: Method, uri: Uri, headers: HeaderMap, body: Bytes) -> EngineResult<Response> {
+        let (method, path) = (method.as_str(), uri.path());
+        let route = self.routes.iter().find(|route| route.endpoint.matches(method, path));
+        let status = match route.map(|route| (&route.endpoint.fault, route.endpoint.status)) {
+            None => Some(404),
+            Some((Some(MockFault::ServerError), _)) => Some(503),
+            Some((Some(_), _)) => None,
+            Some((None, status)) => Some(status),
+        };
+        let headers: Map<String, Value> = headers.iter()
+            .map(|(name, value)| (name.to_string(), Value::String(String::from_utf8_lossy(value.as_bytes()).to_string())))
+            .collect();
+        let record = mock_request::ActiveModel {
+            id: NotSet,
+            execution_id: Set(self.execution_id),
+            endpoint_id: Set(route.map(|route| route.endpoint.id)),
+            method: Set(method.to_string()),
+            path: Set(path.to_string()),
+            query: Set(uri.query().map(str::to_string)),
+            headers: Set(Value::Object(headers)),
+            body: Set((!body.is_empty()).then(|| String::from_utf8_lossy(&body).to_string())),
+            status: Set(status),
+            created_at: Set(chrono::Utc::now().into()),
+        }.insert(&self.db).await?;
+        self.recorded.lock().unwrap().push(record);
+
+        let Some(route) = route else {
+            return Ok((StatusCode::NOT_FOUND, format!("No mock endpoint for {method} {path}")).into_response());
+        };
+        if let Some(delay) = route.endpoint.delay.filter(|delay| *delay > 0) {
+            tokio::time::sleep(Duration::from_millis(delay as u64)).await;
+        }
+        let response = match route.endpoint.fault {
+            Some(MockFault::Reset) => aborted(vec![]),
+            Some(MockFault::Malformed) => aborted(b"ORCA MALFORMED RESPONSE".to_vec()),
+            Some(MockFault::ServerError) => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+            None => {
+                let status = u16::try_from(route.endpoint.status).ok()
+                    .and_then(|status| StatusCode::from_u16(status).ok())
+                    .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                let mut response = (status, route.body.clone()).into_response();
+                for (name, value) in route.endpoint.headers() {
+                    let (Ok(name), Ok(value)) = (HeaderName::try_from(name), HeaderValue::try_from(value)) else {
+                        continue;
+                    };
+                    if name != CONTENT_LENGTH && name != CONNECTION {
+                        response.headers_mut().insert(name, value);
+                    }
+                }
+                response
+            }
+        };
+        Ok(response)
+    }
+}
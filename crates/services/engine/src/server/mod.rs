@@ -1 +1,5 @@
-
+pub mod executor;
+pub mod mock;
+pub mod recorder;
+pub mod registry;
+pub mod scheduler;
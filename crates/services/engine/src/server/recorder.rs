@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::Uuid;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use entity::prelude::target::ActionTargetKind;
+use entity::test::ui::action::action::{self, ActionKind};
+use entity::test::ui::action::data::ActionDataKind;
+use entity::test::ui::action::group::{self, ActionGroupKind};
+use entity::test::ui::elements::{self, ElementCreateType};
+use entity::test::ui::{locator, screen};
+
+use crate::error::{EngineError, EngineResult};
+
+/// CandidateLocator - locator the recorder script found for the element,
+/// the candidates are sent in the priority order
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CandidateLocator {
+    pub kind: ActionTargetKind,
+    pub value: String,
+}
+
+/// RecordedElement - element the user interacted with while recording
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RecordedElement {
+    /// readable name of the element (label, aria-label, text), locator is used when missing
+    pub name: Option<String>,
+    pub tag: Option<String>,
+    pub locators: Vec<CandidateLocator>,
+}
+
+/// RecordedEvent - browser event captured by the recorder script or extension
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecordedEvent {
+    /// `typed` is set by the recorder for the navigation the user started outside of the
+    /// page (address bar, bookmark, reload), the rest follow from the last click
+    Navigate {
+        url: String,
+        #[serde(default)]
+        typed: bool,
+    },
+    Click { url: String, element: RecordedElement },
+    Input { url: String, element: RecordedElement, value: String },
+}
+
+/// RecordingSession - events captured so far for the application
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingSession {
+    pub id: Uuid,
+    pub name: String,
+    pub app_id: Uuid,
+    pub events: Vec<RecordedEvent>,
+}
+
+/// RECORDING_TTL - idle time after which the abandoned recording session is dropped
+pub const RECORDING_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// MAX_RECORDINGS - recording sessions kept at the same time, the least recently used
+/// one is dropped to make room for the new one
+pub const MAX_RECORDINGS: usize = 100;
+
+/// RecordingRegistry - keep track of the recording sessions in progress by the session id
+/// along with the time the session was last used
+#[derive(Debug, Clone)]
+pub struct RecordingRegistry {
+    sessions: Arc<Mutex<HashMap<Uuid, (RecordingSession, Instant)>>>,
+    ttl: Duration,
+    max_sessions: usize,
+}
+
+impl Default for RecordingRegistry {
+    fn default() -> Self {
+        Self {
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+            ttl: RECORDING_TTL,
+            max_sessions: MAX_RECORDINGS,
+        }
+    }
+}
+
+impl RecordingRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// with_limits - registry with the idle time and the number of the sessions to keep
+    pub fn with_limits(ttl: Duration, max_sessions: usize) -> Self {
+        Self { ttl, max_sessions: max_sessions.max(1), ..Self::default() }
+    }
+
+    /// start - open a new recording session for the application, the expired sessions
+    /// are dropped first
+    pub fn start(&self, app_id: Uuid, name: String) -> RecordingSession {
+        let session = RecordingSession { id: Uuid::new_v4(), name, app_id, events: Vec::new() };
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.retain(|_, (_, used)| used.elapsed() < self.ttl);
+        while sessions.len() >= self.max_sessions {
+            let Some(oldest) = sessions.iter().min_by_key(|(_, (_, used))| *used).map(|(id, _)| *id) else { break };
+            sessions.remove(&oldest);
+        }
+        sessions.insert(session.id, (session.clone(), Instant::now()));
+        session
+    }
+
+    /// push - append the captured events to the session,
+    /// return the number of the events recorded so far or none for the unknown session
+    pub fn push(&self, id: Uuid, events: Vec<RecordedEvent>) -> Option<usize> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let (session, used) = self.live(&mut sessions, id)?;
+        session.events.extend(events);
+        *used = Instant::now();
+        Some(session.events.len())
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<RecordingSession> {
+        let mut sessions = self.sessions.lock().unwrap();
+        self.live(&mut sessions, id).map(|(session, _)| session.clone())
+    }
+
+    /// remove - close the session and give back the recorded events
+    pub fn remove(&self, id: Uuid) -> Option<RecordingSession> {
+        let mut sessions = self.sessions.lock().unwrap();
+        self.live(&mut sessions, id)?;
+        sessions.remove(&id).map(|(session, _)| session)
+    }
+
+    /// live - session that is not expired yet, the expired one is dropped
+    fn live<'s>(&self, sessions: &'s mut HashMap<Uuid, (RecordingSession, Instant)>,
+                id: Uuid) -> Option<&'s mut (RecordingSession, Instant)> {
+        if sessions.get(&id).is_some_and(|(_, used)| used.elapsed() >= self.ttl) {
+            sessions.remove(&id);
+        }
+        sessions.get_mut(&id)
+    }
+}
+
+/// page - url of the page without the query and fragment, the screen of the element
+fn page(url: &str) -> &str {
+    url.split(['?', '#']).next().unwrap_or(url)
+}
+
+/// Recorder - turn the recorded events into the action group with the actions and
+/// the object repository elements
+pub struct Recorder<'rl> {
+    db: &'rl DatabaseTransaction,
+    app_id: Uuid,
+    screens: HashMap<String, Uuid>,
+    elements: HashMap<(String, String), Uuid>,
+}
+
+impl<'rl> Recorder<'rl> {
+    pub fn new(db: &'rl DatabaseTransaction, app_id: Uuid) -> Recorder<'rl> {
+        Self { db, app_id, screens: HashMap::new(), elements: HashMap::new() }
+    }
+
+    /// save - create the action group of the session, the navigation caused by the
+    /// recorded click and the keystrokes typed into the same element are folded, the
+    /// screens and elements already in the object repository are reused
+    pub async fn save(mut self, session: RecordingSession) -> EngineResult<group::Model> {
+        let group = group::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(session.name.clone()),
+            description: Set(Some(format!("Recorded with {} event(s)", session.events.len()))),
+            type_field: Set(ActionGroupKind::ActionGroup),
+            app_id: Set(self.app_id),
+        }.insert(self.db).await?;
+
+        let mut steps: Vec<action::ActiveModel> = Vec::new();
+        let mut previous: Option<&RecordedEvent> = None;
+        let mut typing: Option<Uuid> = None;
+        for event in session.events.iter() {
+            match event {
+                RecordedEvent::Navigate { typed: false, .. } if matches!(previous, Some(RecordedEvent::Click { .. })) => {}
+                RecordedEvent::Navigate { url, .. } => {
+                    steps.push(self.step(ActionKind::Open, None, Some(url.clone())));
+                }
+                RecordedEvent::Click { url, element } => {
+                    let element_id = self.element(url, element).await?;
+                    steps.push(self.step(ActionKind::Click, Some(element_id), None));
+                }
+                RecordedEvent::Input { url, element, value } => {
+                    let element_id = self.element(url, element).await?;
+                    if typing == Some(element_id) {
+                        steps.pop();
+                    }
+                    steps.push(self.step(ActionKind::Enter, Some(element_id), Some(value.clone())));
+                    typing = Some(element_id);
+                    previous = Some(event);
+                    continue;
+                }
+            }
+            typing = None;
+            previous = Some(event);
+        }
+
+        let count = steps.len();
+        for (order, mut step) in steps.into_iter().enumerate() {
+            step.execution_order = Set(order as i32 + 1);
+            step.action_group_id = Set(group.id);
+            step.insert(self.db).await?;
+        }
+        info!("Recorded Action Group {} with {count} action(s)", group.id);
+        Ok(group)
+    }
+
+    fn step(&self, kind: ActionKind, element_id: Option<Uuid>, data: Option<String>) -> action::ActiveModel {
+        action::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            execution_order: Set(0),
+            description: Set(None),
+            kind: Set(kind),
+            data_kind: Set(data.as_ref().map(|_| ActionDataKind::Static)),
+            data_value: Set(data),
+            target_kind: Set(None),
+            target_value: Set(None),
+            element_id: Set(element_id),
+            timeout: Set(None),
+            action_group_id: Set(Uuid::nil()),
+        }
+    }
+
+    /// screen - screen of the recorded page, the screen of the application with the same
+    /// page is reused and a new one is created on the first element of the page otherwise
+    async fn screen(&mut self, url: &str) -> EngineResult<Uuid> {
+        let page = page(url).to_string();
+        if let Some(screen_id) = self.screens.get(&page) {
+            return Ok(*screen_id);
+        }
+        let existing = screen::Entity::find()
+            .filter(screen::Column::AppId.eq(self.app_id))
+            .filter(screen::Column::Name.eq(page.as_str()))
+            .one(self.db)
+            .await?;
+        if let Some(screen) = existing {
+            self.screens.insert(page, screen.id);
+            return Ok(screen.id);
+        }
+        let screen = screen::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(page.clone()),
+            description: Set(Some("Recorded".to_string())),
+            app_id: Set(self.app_id),
+        }.insert(self.db).await?;
+        self.screens.insert(page, screen.id);
+        Ok(screen.id)
+    }
+
+    /// element - object repository element of the recorded element, the element of the
+    /// screen with the same primary locator is reused, otherwise the first candidate is the
+    /// primary locator of the new element and the rest are the fallbacks
+    async fn element(&mut self, url: &str, recorded: &RecordedElement) -> EngineResult<Uuid> {
+        let primary = recorded.locators.first().cloned().ok_or_else(|| {
+            EngineError::MissingParameter("event.element.locators".to_string(), url.to_string())
+        })?;
+        let key = (page(url).to_string(), format!("{:?}:{}", primary.kind, primary.value));
+        if let Some(element_id) = self.elements.get(&key) {
+            return Ok(*element_id);
+        }
+        let screen_id = self.screen(url).await?;
+        let existing = elements::Entity::find()
+            .filter(elements::Column::ScreenId.eq(screen_id))
+            .filter(elements::Column::LocatorType.eq(primary.kind.clone()))
+            .filter(elements::Column::LocatorValue.eq(primary.value.as_str()))
+            .one(self.db)
+            .await?;
+        if let Some(element) = existing {
+            self.elements.insert(key, element.id);
+            return Ok(element.id);
+        }
+        let element = elements::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(recorded.name.clone().unwrap_or_else(|| primary.value.clone())),
+            description: Set(None),
+            locator_value: Set(primary.value.clone()),
+            element_type: Set(recorded.tag.clone()),
+            created_type: Set(ElementCreateType::Record),
+            locator_type: Set(primary.kind.clone()),
+            proposed_locator_id: Set(None),
+            screen_id: Set(screen_id),
+        }.insert(self.db).await?;
+        for (priority, fallback) in recorded.locators.iter().skip(1).enumerate() {
+            locator::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                priority: Set(priority as i32 + 1),
+                locator_type: Set(fallback.kind.clone()),
+                locator_value: Set(fallback.value.clone()),
+                element_id: Set(element.id),
+            }.insert(self.db).await?;
+        }
+        self.elements.insert(key, element.id);
+        Ok(element.id)
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// ExecutionToken - shared flag the controllers check between the steps
+/// to find out if the running execution was cancelled
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionToken(Arc<AtomicBool>);
+
+impl ExecutionToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// cancel - ask the engine to stop the execution before the next step
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// ExecutionRegistry - keep track of the executions running in the background
+/// by the execution request id
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionRegistry(Arc<Mutex<HashMap<i32, ExecutionToken>>>);
+
+impl ExecutionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// register - register the execution request and give back the token for it
+    pub fn register(&self, id: i32) -> ExecutionToken {
+        let token = ExecutionToken::new();
+        self.0.lock().unwrap().insert(id, token.clone());
+        token
+    }
+
+    /// remove - remove the execution request once the execution is done
+    pub fn remove(&self, id: i32) {
+        self.0.lock().unwrap().remove(&id);
+    }
+
+    pub fn is_running(&self, id: i32) -> bool {
+        self.0.lock().unwrap().contains_key(&id)
+    }
+
+    /// cancel - cancel the running execution,
+    /// return false if there is no running execution for the id
+    pub fn cancel(&self, id: i32) -> bool {
+        match self.0.lock().unwrap().get(&id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
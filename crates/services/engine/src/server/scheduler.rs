@@ -0,0 +1,122 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::Utc;
+use chrono_tz::Tz;
+use cron::Schedule as CronSchedule;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, TryIntoModel};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde_json::json;
+use tracing::{error, info};
+
+use cerium::client::Client;
+use cerium::client::driver::pool::SessionPool;
+use entity::test::schedule;
+use entity::test::ui::request::{ExecutionKind, ExecutionStatus, new};
+
+use crate::error::{EngineError, EngineResult};
+use crate::server::executor::Executor;
+use crate::server::registry::ExecutionRegistry;
+
+/// next_run - get the next run time of the cron expression in the timezone after the given time,
+/// the cron expression has to have the seconds field (`sec min hour day month weekday [year]`)
+pub fn next_run(cron: &str, timezone: &str, after: DateTimeWithTimeZone) -> EngineResult<DateTimeWithTimeZone> {
+    let tz = Tz::from_str(timezone)
+        .map_err(|err| EngineError::InvalidSchedule(format!("timezone {timezone} - {err}")))?;
+    let schedule = CronSchedule::from_str(cron)
+        .map_err(|err| EngineError::InvalidSchedule(format!("cron {cron} - {err}")))?;
+    let next = schedule
+        .after(&after.with_timezone(&tz))
+        .next()
+        .ok_or_else(|| EngineError::InvalidSchedule(format!("cron {cron} has no upcoming run")))?;
+    Ok(next.with_timezone(&Utc).into())
+}
+
+/// Scheduler - poll the enabled schedules and enqueue the execution when they are due,
+/// the next run is stored on the schedule so the due runs are picked up after a restart
+pub struct Scheduler {
+    cli: Client,
+    registry: ExecutionRegistry,
+    pool: Option<SessionPool>,
+    interval: Duration,
+}
+
+impl Scheduler {
+    pub fn new(cli: Client, registry: ExecutionRegistry) -> Self {
+        Self { cli, registry, pool: None, interval: Duration::from_secs(30) }
+    }
+
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+    }
+
+    /// set_pool - scheduled executions will lease the browser session from the pool
+    pub fn set_pool(&mut self, pool: SessionPool) {
+        self.pool = Some(pool);
+    }
+
+    /// start - spawn the scheduler loop in the background
+    pub fn start(self) {
+        tokio::spawn(async move {
+            info!("Starting the Scheduler with interval {:?}", self.interval);
+            let mut ticker = tokio::time::interval(self.interval);
+            loop {
+                ticker.tick().await;
+                if let Err(err) = self.tick().await {
+                    error!("Scheduler tick failed - {:?}", err);
+                }
+            }
+        });
+    }
+
+    /// tick - enqueue all the schedules that are due now
+    pub async fn tick(&self) -> EngineResult<()> {
+        let now: DateTimeWithTimeZone = Utc::now().into();
+        let schedules = schedule::Entity::find()
+            .filter(schedule::Column::Enabled.eq(true))
+            .filter(schedule::Column::NextRunAt.lte(now))
+            .all(self.cli.db())
+            .await?;
+        for sch in schedules {
+            let schedule_id = sch.id;
+            if let Err(err) = self.enqueue(sch, now).await {
+                error!("Unable to enqueue the Schedule {:?} - {:?}", schedule_id, err);
+            }
+        }
+        Ok(())
+    }
+
+    /// enqueue - create the scheduled execution request, trigger it and move the schedule
+    /// to the next run
+    async fn enqueue(&self, sch: schedule::Model, now: DateTimeWithTimeZone) -> EngineResult<()> {
+        info!("Enqueue the Schedule {:?} for {:?}", sch.id, sch.ref_id);
+        let mut er_am = new(
+            sch.ref_id,
+            sch.ref_type.clone(),
+            ExecutionKind::Scheduled,
+            ExecutionStatus::Started,
+            0,
+            false,
+            Some(format!("Scheduled - {name}", name = sch.name)),
+        );
+        er_am.timeout = Set(sch.timeout);
+        er_am.args = Set(Some(json!({
+            "schedule_id": sch.id,
+            "profile_id": sch.profile_id,
+            "browser": sch.browser,
+        })));
+        let er = er_am.save(self.cli.db()).await?.try_into_model()?;
+
+        let next_run_at = next_run(&sch.cron, &sch.timezone, now)?;
+        let mut sch_am = sch.into_active_model();
+        sch_am.last_run_at = Set(Some(now));
+        sch_am.next_run_at = Set(Some(next_run_at));
+        sch_am.save(self.cli.db()).await?;
+
+        Executor::new(self.cli.clone(), self.registry.clone())
+            .with_pool(self.pool.clone())
+            .trigger(er);
+        Ok(())
+    }
+}
@@ -0,0 +1,238 @@
+mod common;
+
+#[cfg(all(test, feature = "api-automation"))]
+mod tests {
+    use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter};
+    use sea_orm::ActiveValue::Set;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use engine::controller::api::ApiController;
+    use engine::controller::data::Variables;
+    use engine::error::EngineError;
+    use engine::server::registry::ExecutionToken;
+    use entity::api::{assertion, response, spec, step};
+    use entity::api::assertion::{AssertionKind, MatchMode};
+    use entity::api::step::ApiMethod;
+    use entity::test::ui::log::item_log;
+    use entity::test::ui::log::item_log::{ItemLogStatus, ItemLogType};
+    use entity::test::ui::request;
+    use entity::test::ui::request::{ExecutionKind, ExecutionStatus, ExecutionType};
+
+    use crate::common::{self, serve, table};
+
+    async fn trx() -> DatabaseTransaction {
+        common::trx(vec![
+            table(request::Entity),
+            table(item_log::Entity),
+            table(spec::Entity),
+            table(step::Entity),
+            table(assertion::Entity),
+            table(response::Entity),
+        ]).await
+    }
+
+    async fn api_step(trx: &DatabaseTransaction, url: String, expected_status: Option<i32>) -> step::Model {
+        step::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set("Create user".to_string()),
+            description: Set(None),
+            method: Set(ApiMethod::Post),
+            url: Set(format!("{url}/users")),
+            headers: Set(Some(json!({"content-type": "application/json"}))),
+            query: Set(Some(json!({"notify": "false"}))),
+            body: Set(Some(r#"{"name":"orca"}"#.to_string())),
+            auth: Set(Some(json!({"type": "bearer", "token": "secret"}))),
+            expected_status: Set(expected_status),
+            timeout: Set(Some(5000)),
+            spec_id: Set(None),
+            operation_id: Set(None),
+            graphql: Set(None),
+            app_id: Set(Uuid::new_v4()),
+        }.insert(trx).await.expect("api step")
+    }
+
+    async fn execution(trx: &DatabaseTransaction) -> request::Model {
+        request::new(Uuid::new_v4(), ExecutionType::TestCase, ExecutionKind::Trigger,
+                     ExecutionStatus::Running, 0, false, None)
+            .insert(trx).await.expect("execution request")
+    }
+
+    #[tokio::test]
+    async fn api_step_response_is_logged() {
+        let trx = trx().await;
+        let (url, server) = serve("HTTP/1.1 201 Created\r\ncontent-type: application/json\r\n\
+                                   content-length: 11\r\nconnection: close\r\n\r\n{\"id\":\"42\"}").await;
+        let step = api_step(&trx, url, Some(201)).await;
+        let er = execution(&trx).await;
+
+        ApiController::new(&trx, Variables::new(), ExecutionToken::new())
+            .execute(step.id, &er, None).await.expect("api step");
+
+        let request = server.await.expect("request");
+        assert!(request.starts_with("POST /users?notify=false HTTP/1.1"));
+        assert!(request.to_lowercase().contains("authorization: bearer secret"));
+        assert!(request.ends_with(r#"{"name":"orca"}"#));
+
+        let log = item_log::Entity::find()
+            .filter(item_log::Column::StepId.eq(step.id))
+            .one(&trx).await.expect("item log").expect("item log of the step");
+        assert_eq!((log.ref_type, log.status), (ItemLogType::ApiStep, ItemLogStatus::Success));
+        let res = response::Entity::find()
+            .filter(response::Column::ItemLogId.eq(log.id))
+            .one(&trx).await.expect("api response").expect("response of the step");
+        assert_eq!(res.status, Some(201));
+        assert_eq!(res.method, "POST");
+        assert_eq!(res.body.as_deref(), Some(r#"{"id":"42"}"#));
+        assert_eq!(res.headers.and_then(|h| h.get("content-type").cloned()), Some(json!("application/json")));
+    }
+
+    #[tokio::test]
+    async fn unexpected_status_fails_the_step() {
+        let trx = trx().await;
+        let (url, _server) = serve("HTTP/1.1 500 Internal Server Error\r\ncontent-length: 0\r\n\
+                                    connection: close\r\n\r\n").await;
+        let step = api_step(&trx, url, Some(201)).await;
+        let er = execution(&trx).await;
+
+        let result = ApiController::new(&trx, Variables::new(), ExecutionToken::new()).execute(step.id, &er, None).await;
+
+        assert!(matches!(result, Err(EngineError::AssertionFailed(_))));
+        let log = item_log::Entity::find()
+            .filter(item_log::Column::StepId.eq(step.id))
+            .one(&trx).await.expect("item log").expect("item log of the step");
+        assert_eq!(log.status, ItemLogStatus::Failed);
+        let res = response::Entity::find()
+            .filter(response::Column::ItemLogId.eq(log.id))
+            .one(&trx).await.expect("api response").expect("response of the step");
+        assert_eq!(res.status, Some(500));
+    }
+
+    fn check(step_id: Uuid, order: i32, kind: AssertionKind, expression: &str) -> assertion::ActiveModel {
+        assertion::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            execution_order: Set(order),
+            kind: Set(kind),
+            expression: Set(Some(expression.to_string())),
+            match_mode: Set(None),
+            expected: Set(None),
+            variable: Set(None),
+            step_id: Set(step_id),
+        }
+    }
+
+    #[tokio::test]
+    async fn assertions_are_logged_and_extract_variables() {
+        let trx = trx().await;
+        let (url, _server) = serve("HTTP/1.1 201 Created\r\ncontent-type: application/json\r\n\
+                                    content-length: 11\r\nconnection: close\r\n\r\n{\"id\":\"42\"}").await;
+        let step = api_step(&trx, url, Some(201)).await;
+        let er = execution(&trx).await;
+        let mut id = check(step.id, 1, AssertionKind::JsonPath, "$.id");
+        id.variable = Set(Some("user_id".to_string()));
+        let id = id.insert(&trx).await.expect("assertion");
+        let mut header = check(step.id, 2, AssertionKind::Header, "Content-Type");
+        header.match_mode = Set(Some(MatchMode::Contains));
+        header.expected = Set(Some("json".to_string()));
+        let header = header.insert(&trx).await.expect("assertion");
+        let variables = Variables::new();
+
+        ApiController::new(&trx, variables.clone(), ExecutionToken::new())
+            .execute(step.id, &er, None).await.expect("api step");
+
+        assert_eq!(variables.get("user_id").as_deref(), Some("42"));
+        let step_log = item_log::Entity::find()
+            .filter(item_log::Column::StepId.eq(step.id))
+            .one(&trx).await.expect("item log").expect("item log of the step");
+        for assertion_id in [id.id, header.id] {
+            let log = item_log::Entity::find()
+                .filter(item_log::Column::StepId.eq(assertion_id))
+                .one(&trx).await.expect("item log").expect("item log of the assertion");
+            assert_eq!((log.ref_type, log.status), (ItemLogType::Assertion, ItemLogStatus::Success));
+            assert_eq!(log.log_id, Some(step_log.id));
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_assertion_fails_the_step() {
+        let trx = trx().await;
+        let (url, _server) = serve("HTTP/1.1 201 Created\r\ncontent-type: application/json\r\n\
+                                    content-length: 11\r\nconnection: close\r\n\r\n{\"id\":\"42\"}").await;
+        let step = api_step(&trx, url, Some(201)).await;
+        let er = execution(&trx).await;
+        let mut pointer = check(step.id, 1, AssertionKind::JsonPointer, "/id");
+        pointer.match_mode = Set(Some(MatchMode::Equals));
+        pointer.expected = Set(Some("7".to_string()));
+        let pointer = pointer.insert(&trx).await.expect("assertion");
+
+        let result = ApiController::new(&trx, Variables::new(), ExecutionToken::new())
+            .execute(step.id, &er, None).await;
+
+        assert!(matches!(result, Err(EngineError::AssertionFailed(_))));
+        let log = item_log::Entity::find()
+            .filter(item_log::Column::StepId.eq(pointer.id))
+            .one(&trx).await.expect("item log").expect("item log of the assertion");
+        assert_eq!(log.status, ItemLogStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn placeholders_are_resolved_with_variables() {
+        let trx = trx().await;
+        let (url, _server) = serve("HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n").await;
+        let mut step = api_step(&trx, url, Some(200)).await;
+        step.url = format!("{}/${{user_id}}", step.url);
+        step.body = Some(r#"{"token":"${token}"}"#.to_string());
+        let variables = Variables::new();
+        variables.set("user_id", "42");
+
+        let controller = ApiController::new(&trx, variables.clone(), ExecutionToken::new());
+        assert!(matches!(controller.request(&step), Err(EngineError::MissingParameter(_, key)) if key == "token"));
+        variables.set("token", "abc");
+        let request = controller.request(&step).expect("request");
+        assert_eq!(request.url().path(), "/users/42");
+    }
+
+    #[tokio::test]
+    async fn graphql_errors_fail_the_step() {
+        let trx = trx().await;
+        let (url, server) = serve("HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                                   content-length: 69\r\nconnection: close\r\n\r\n\
+                                   {\"data\":null,\"errors\":[{\"message\":\"User not found\",\"path\":[\"user\"]}]}").await;
+        let mut step: step::ActiveModel = api_step(&trx, url, Some(200)).await.into();
+        step.body = Set(None);
+        step.headers = Set(None);
+        step.graphql = Set(Some(json!({
+            "query": "query User($id: ID!) { user(id: $id) { name } }",
+            "variables": {"id": "${user_id}"}
+        })));
+        let step = step.update(&trx).await.expect("graphql step");
+        let er = execution(&trx).await;
+        let variables = Variables::new();
+        variables.set("user_id", "42");
+
+        let result = ApiController::new(&trx, variables, ExecutionToken::new()).execute(step.id, &er, None).await;
+
+        assert!(matches!(result, Err(EngineError::AssertionFailed(message))
+            if message == "GraphQL errors: User not found at 'user'"));
+        let request = server.await.expect("request");
+        assert!(request.to_lowercase().contains("content-type: application/json"));
+        assert!(request.ends_with(r#"{"query":"query User($id: ID!) { user(id: $id) { name } }","variables":{"id":42}}"#));
+    }
+
+    #[tokio::test]
+    async fn graphql_query_is_sent_in_the_url_for_get() {
+        let trx = trx().await;
+        let mut step = api_step(&trx, "http://graphql.test".to_string(), Some(200)).await;
+        step.method = ApiMethod::Get;
+        step.graphql = Some(json!({"query": "{ me { id } }", "operation_name": "Me"}));
+        let controller = ApiController::new(&trx, Variables::new(), ExecutionToken::new());
+
+        let request = controller.request(&step).expect("request");
+
+        let query: Vec<(String, String)> = request.url().query_pairs().into_owned().collect();
+        assert!(query.contains(&("query".to_string(), "{ me { id } }".to_string())));
+        assert!(query.contains(&("operationName".to_string(), "Me".to_string())));
+        step.graphql = Some(json!({"query": "subscription { onUser { id } }"}));
+        assert!(matches!(controller.request(&step), Err(EngineError::Unsupported(_))));
+    }
+}
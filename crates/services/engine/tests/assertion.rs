@@ -0,0 +1,95 @@
+#[cfg(all(test, feature = "api-automation"))]
+mod tests {
+    use serde_json::{json, Map, Value};
+    use uuid::Uuid;
+
+    use engine::controller::api::ApiResponse;
+    use engine::controller::assertion::{compare, evaluate, json_path, operation_schema};
+    use entity::api::assertion;
+    use entity::api::assertion::{AssertionKind, MatchMode};
+
+    fn response() -> ApiResponse {
+        let mut headers = Map::new();
+        headers.insert("content-type".to_string(), json!("application/json"));
+        ApiResponse {
+            status: 200,
+            headers,
+            body: json!({"data": {"id": 7, "tags": ["a", "b"]}, "items": [{"n": 1}, {"n": 2}]}).to_string(),
+            latency: 120,
+        }
+    }
+
+    fn assertion(kind: AssertionKind, expression: Option<&str>, match_mode: Option<MatchMode>,
+                 expected: Option<&str>) -> assertion::Model {
+        assertion::Model {
+            id: Uuid::new_v4(),
+            execution_order: 1,
+            kind,
+            expression: expression.map(str::to_string),
+            match_mode,
+            expected: expected.map(str::to_string),
+            variable: None,
+            step_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn values_are_compared_with_the_match_mode() {
+        let value = json!(7);
+        assert!(compare(&MatchMode::Equals, Some(&value), Some("7")).is_ok());
+        assert!(compare(&MatchMode::NotEquals, Some(&value), Some("8")).is_ok());
+        assert!(compare(&MatchMode::GreaterThan, Some(&value), Some("5")).is_ok());
+        assert!(compare(&MatchMode::LessThan, Some(&value), Some("5")).is_err());
+        assert!(compare(&MatchMode::Exists, None, None).is_err());
+        assert!(compare(&MatchMode::NotExists, None, None).is_ok());
+        assert!(compare(&MatchMode::Contains, Some(&json!(["a", "b"])), Some("b")).is_ok());
+        assert!(compare(&MatchMode::Regex, Some(&json!("orca-42")), Some(r"^orca-\d+$")).is_ok());
+        assert!(compare(&MatchMode::Regex, Some(&json!("orca")), Some("(")).is_err());
+    }
+
+    #[test]
+    fn json_path_gives_back_single_and_multiple_matches() {
+        let body: Value = serde_json::from_str(&response().body).unwrap();
+        assert_eq!(json_path(&body, "$.data.id").unwrap(), Some(json!(7)));
+        assert_eq!(json_path(&body, "$.items[*].n").unwrap(), Some(json!([1, 2])));
+        assert_eq!(json_path(&body, "$.missing").unwrap(), None);
+        assert!(json_path(&body, "data.id").is_err());
+    }
+
+    #[test]
+    fn assertions_are_evaluated_on_the_response() {
+        let res = response();
+        let pointer = assertion(AssertionKind::JsonPointer, Some("/data/tags/1"), None, Some("b"));
+        assert_eq!(evaluate(&pointer, &res, None), Ok(Some(json!("b"))));
+        let header = assertion(AssertionKind::Header, Some("Content-Type"), Some(MatchMode::Contains), Some("json"));
+        assert!(evaluate(&header, &res, None).is_ok());
+        let latency = assertion(AssertionKind::ResponseTime, None, None, Some("100"));
+        assert!(evaluate(&latency, &res, None).is_err());
+        let status = assertion(AssertionKind::Status, None, None, Some("200"));
+        assert!(evaluate(&status, &res, None).is_ok());
+        let schema = assertion(AssertionKind::JsonSchema, None, None,
+                               Some(r#"{"type": "object", "required": ["data", "meta"]}"#));
+        assert!(evaluate(&schema, &res, None).is_err());
+    }
+
+    #[test]
+    fn openapi_response_schema_is_used_for_the_schema_check() {
+        let document = json!({
+            "openapi": "3.0.3",
+            "paths": {"/user": {"get": {"operationId": "getUser", "responses": {
+                "200": {"content": {"application/json": {"schema": {"$ref": "#/components/schemas/User"}}}}
+            }}}},
+            "components": {"schemas": {"User": {
+                "type": "object", "required": ["data"],
+                "properties": {"data": {"type": "object", "properties": {"id": {"type": "string"}}}}
+            }}}
+        });
+        let schema = operation_schema(&document, "getUser", 200).expect("response schema");
+        assert!(operation_schema(&document, "getUser", 404).is_none());
+
+        let check = assertion(AssertionKind::JsonSchema, None, None, None);
+        let err = evaluate(&check, &response(), Some(&schema)).expect_err("id is not a string");
+        assert!(err.contains("/data/id"));
+        assert!(evaluate(&check, &response(), None).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+mod common;
+
+#[cfg(all(test, feature = "api-automation"))]
+mod tests {
+    use futures_util::{SinkExt, StreamExt};
+    use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter, TryIntoModel};
+    use sea_orm::ActiveValue::Set;
+    use tokio::io::AsyncWriteExt;
+    use tokio_tungstenite::accept_async;
+    use tokio_tungstenite::tungstenite::Message;
+    use uuid::Uuid;
+
+    use engine::controller::channel::{ChannelController, Channels, Matcher};
+    use engine::controller::data::Variables;
+    use engine::error::EngineError;
+    use engine::server::registry::ExecutionToken;
+    use entity::api::channel;
+    use entity::api::channel::{ChannelAction, ChannelMatch, ChannelProtocol};
+    use entity::test::ui::log::item_log;
+    use entity::test::ui::log::item_log::{ItemLogStatus, ItemLogType};
+    use entity::test::ui::request;
+    use entity::test::ui::request::{ExecutionKind, ExecutionStatus, ExecutionType};
+
+    use crate::common::{self, listen, read_request, table};
+
+    async fn trx() -> DatabaseTransaction {
+        common::trx(vec![
+            table(request::Entity),
+            table(item_log::Entity),
+            table(channel::Entity),
+        ]).await
+    }
+
+    async fn execution(trx: &DatabaseTransaction) -> request::Model {
+        request::new(Uuid::new_v4(), ExecutionType::TestCase, ExecutionKind::Trigger,
+                     ExecutionStatus::Running, 0, false, None)
+            .insert(trx).await.expect("execution request")
+    }
+
+    fn step(action: ChannelAction, protocol: ChannelProtocol) -> channel::ActiveModel {
+        channel::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(format!("{action:?}")),
+            description: Set(None),
+            action: Set(action),
+            protocol: Set(protocol),
+            connection: Set("updates".to_string()),
+            url: Set(None),
+            headers: Set(None),
+            message: Set(None),
+            match_kind: Set(None),
+            expression: Set(None),
+            expected: Set(None),
+            variable: Set(None),
+            timeout: Set(Some(2000)),
+            app_id: Set(Uuid::new_v4()),
+        }
+    }
+
+    #[test]
+    fn matcher_takes_the_value_of_the_message() {
+        let json = Matcher::new(&ChannelMatch::JsonPath, "$.id", None).expect("matcher");
+        assert_eq!(json.value(r#"{"event":"created","id":7}"#).as_deref(), Some("7"));
+        assert_eq!(json.value(r#"{"event":"ping"}"#), None);
+        assert_eq!(json.value("not json"), None);
+
+        let expected = Matcher::new(&ChannelMatch::JsonPath, "$.event", Some("created".to_string())).expect("matcher");
+        assert_eq!(expected.value(r#"{"event":"created"}"#).as_deref(), Some("created"));
+        assert_eq!(expected.value(r#"{"event":"deleted"}"#), None);
+
+        let regex = Matcher::new(&ChannelMatch::Regex, r"order (\d+) shipped", None).expect("matcher");
+        assert_eq!(regex.value("order 42 shipped").as_deref(), Some("42"));
+        assert!(Matcher::new(&ChannelMatch::Regex, "(", None).is_err());
+        assert!(Matcher::new(&ChannelMatch::JsonPath, "id[", None).is_err());
+    }
+
+    #[test]
+    fn step_is_checked_for_the_action() {
+        let model = |action, protocol| step(action, protocol).try_into_model().expect("model");
+        assert!(model(ChannelAction::Open, ChannelProtocol::WebSocket).check().is_err());
+        assert!(model(ChannelAction::Send, ChannelProtocol::Sse).check().is_err());
+        assert!(model(ChannelAction::Wait, ChannelProtocol::WebSocket).check().is_err());
+        assert!(model(ChannelAction::Close, ChannelProtocol::WebSocket).check().is_ok());
+    }
+
+    #[tokio::test]
+    async fn websocket_push_is_awaited_across_steps() {
+        let trx = trx().await;
+        let (listener, address) = listen().await;
+        let url = format!("ws://{address}/updates");
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let mut ws = accept_async(stream).await.expect("handshake");
+            let subscribe = ws.next().await.expect("message").expect("message");
+            ws.send(Message::Text(r#"{"event":"ping"}"#.to_string())).await.expect("send");
+            ws.send(Message::Text(r#"{"event":"created","id":7}"#.to_string())).await.expect("send");
+            let closing = ws.next().await.expect("close").expect("close");
+            (subscribe, closing)
+        });
+        let mut open = step(ChannelAction::Open, ChannelProtocol::WebSocket);
+        open.url = Set(Some("${ws_url}".to_string()));
+        let open = open.insert(&trx).await.expect("open step");
+        let mut send = step(ChannelAction::Send, ChannelProtocol::WebSocket);
+        send.message = Set(Some(r#"{"subscribe":"${topic}"}"#.to_string()));
+        let send = send.insert(&trx).await.expect("send step");
+        let mut wait = step(ChannelAction::Wait, ChannelProtocol::WebSocket);
+        wait.match_kind = Set(Some(ChannelMatch::JsonPath));
+        wait.expression = Set(Some("$.id".to_string()));
+        wait.variable = Set(Some("order_id".to_string()));
+        let wait = wait.insert(&trx).await.expect("wait step");
+        let close = step(ChannelAction::Close, ChannelProtocol::WebSocket).insert(&trx).await.expect("close step");
+        let er = execution(&trx).await;
+        let (variables, channels) = (Variables::new(), Channels::new());
+        variables.set("ws_url", url);
+        variables.set("topic", "orders");
+        let controller = ChannelController::new(&trx, variables.clone(), channels.clone(), ExecutionToken::new());
+
+        for id in [open.id, send.id, wait.id] {
+            controller.execute(id, &er, None).await.expect("channel step");
+        }
+        assert!(channels.get("updates").is_some());
+        controller.execute(close.id, &er, None).await.expect("close");
+
+        assert_eq!(variables.get("order_id").as_deref(), Some("7"));
+        assert!(channels.get("updates").is_none());
+        let (subscribe, closing) = server.await.expect("server");
+        assert_eq!(subscribe, Message::Text(r#"{"subscribe":"orders"}"#.to_string()));
+        assert!(matches!(closing, Message::Close(_)));
+        let logs = item_log::Entity::find()
+            .filter(item_log::Column::RefType.eq(ItemLogType::ChannelStep))
+            .all(&trx).await.expect("item logs");
+        assert_eq!(logs.len(), 4);
+        assert!(logs.iter().all(|log| log.status == ItemLogStatus::Success));
+    }
+
+    #[tokio::test]
+    async fn sse_events_are_matched_and_wait_times_out() {
+        let trx = trx().await;
+        let (listener, address) = listen().await;
+        let url = format!("http://{address}/events");
+        let _server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.expect("accept");
+            read_request(&mut stream).await;
+            stream.write_all(b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n\r\n\
+                               : comment\r\n\r\nevent: status\r\ndata: order 42\r\ndata: shipped\r\n\r\n")
+                .await.expect("write");
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+        });
+        let mut open = step(ChannelAction::Open, ChannelProtocol::Sse);
+        open.url = Set(Some(url));
+        let open = open.insert(&trx).await.expect("open step");
+        let mut wait = step(ChannelAction::Wait, ChannelProtocol::Sse);
+        wait.match_kind = Set(Some(ChannelMatch::Regex));
+        wait.expression = Set(Some(r"order (\d+)\nshipped".to_string()));
+        wait.variable = Set(Some("order_id".to_string()));
+        let wait = wait.insert(&trx).await.expect("wait step");
+        let mut never = step(ChannelAction::Wait, ChannelProtocol::Sse);
+        never.match_kind = Set(Some(ChannelMatch::Regex));
+        never.expression = Set(Some("cancelled".to_string()));
+        never.timeout = Set(Some(200));
+        let never = never.insert(&trx).await.expect("wait step");
+        let er = execution(&trx).await;
+        let variables = Variables::new();
+        let controller = ChannelController::new(&trx, variables.clone(), Channels::new(), ExecutionToken::new());
+
+        controller.execute(open.id, &er, None).await.expect("open");
+        controller.execute(wait.id, &er, None).await.expect("wait");
+        let result = controller.execute(never.id, &er, None).await;
+
+        assert_eq!(variables.get("order_id").as_deref(), Some("42"));
+        assert!(matches!(result, Err(EngineError::Timeout(_, 200))));
+        let log = item_log::Entity::find()
+            .filter(item_log::Column::StepId.eq(never.id))
+            .one(&trx).await.expect("item log").expect("item log of the step");
+        assert_eq!(log.status, ItemLogStatus::TimedOut);
+    }
+}
@@ -0,0 +1,92 @@
+//! Fixtures shared by the engine integration tests, every test file takes only what it needs
+#![allow(dead_code)]
+
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DatabaseTransaction, DbBackend,
+              EntityTrait, Schema, TransactionTrait};
+use sea_orm::sea_query::TableCreateStatement;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// table - create statement of the entity table on sqlite
+pub fn table<E: EntityTrait>(entity: E) -> TableCreateStatement {
+    Schema::new(DbBackend::Sqlite).create_table_from_entity(entity)
+}
+
+/// db - sqlite in memory with the tables, on a single connection so every query sees the same
+/// database. The foreign keys are off since only the tables under test are created, not their parents
+pub async fn db(tables: Vec<TableCreateStatement>) -> DatabaseConnection {
+    let mut options = ConnectOptions::new("sqlite::memory:".to_string());
+    options.max_connections(1).min_connections(1);
+    let db = Database::connect(options).await.expect("sqlite in memory");
+    db.execute_unprepared("PRAGMA foreign_keys = OFF").await.expect("foreign keys off");
+    for table in tables.iter() {
+        db.execute(db.get_database_backend().build(table)).await.expect("create table");
+    }
+    db
+}
+
+/// trx - transaction on the sqlite in memory with the tables
+pub async fn trx(tables: Vec<TableCreateStatement>) -> DatabaseTransaction {
+    db(tables).await.begin().await.expect("transaction")
+}
+
+/// listen - listener on a free local port with its `127.0.0.1:<port>` address
+pub async fn listen() -> (TcpListener, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let address = listener.local_addr().expect("address").to_string();
+    (listener, address)
+}
+
+/// read_request - read the raw http request, the body up to the content length
+pub async fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    loop {
+        let read = stream.read(&mut buf).await.expect("read");
+        request.extend_from_slice(&buf[..read]);
+        let text = String::from_utf8_lossy(&request).to_string();
+        if let Some((head, body)) = text.split_once("\r\n\r\n") {
+            let length = head.lines()
+                .find_map(|line| line.to_lowercase().strip_prefix("content-length:").map(|l| l.trim().to_string()))
+                .and_then(|l| l.parse::<usize>().ok())
+                .unwrap_or(0);
+            if body.len() >= length {
+                break;
+            }
+        }
+        if read == 0 {
+            break;
+        }
+    }
+    String::from_utf8_lossy(&request).to_string()
+}
+
+/// serve - answer the first request with the raw response and give back the raw request
+pub async fn serve(response: &'static str) -> (String, JoinHandle<String>) {
+    let (listener, address) = listen().await;
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.expect("accept");
+        let request = read_request(&mut stream).await;
+        stream.write_all(response.as_bytes()).await.expect("write");
+        request
+    });
+    (format!("http://{address}"), handle)
+}
+
+/// serve_with - answer every request, one connection after the other, with the raw response
+/// the handler makes from the raw request
+pub async fn serve_with<F>(mut handler: F) -> String
+where
+    F: FnMut(&str) -> String + Send + 'static,
+{
+    let (listener, address) = listen().await;
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.expect("accept");
+            let request = read_request(&mut stream).await;
+            stream.write_all(handler(&request).as_bytes()).await.expect("write");
+        }
+    });
+    format!("http://{address}")
+}
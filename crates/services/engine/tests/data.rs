@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use engine::controller::data::{placeholders, DataScope};
+
+    #[test]
+    fn placeholders_in_value() {
+        assert_eq!(placeholders("${host}/login?user=${ user }"), vec!["host", "user"]);
+        assert!(placeholders("no placeholder ${broken").is_empty());
+    }
+
+    #[test]
+    fn resolve_with_scope() {
+        let mut scope = DataScope::new();
+        scope.insert("host", "https://orca.dev");
+        scope.insert("user", "mani");
+        assert_eq!(
+            scope.resolve("${host}/login?user=${ user }"),
+            Ok("https://orca.dev/login?user=mani".to_string())
+        );
+        assert_eq!(scope.resolve("${host}/${path}"), Err(vec!["path".to_string()]));
+    }
+}
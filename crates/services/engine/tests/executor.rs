@@ -0,0 +1,214 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait};
+    use sea_orm::ActiveValue::Set;
+    use uuid::Uuid;
+
+    use cerium::client::Client;
+    use cerium::client::driver::Driver;
+    use cerium::client::driver::mock::{Interaction, MockDriver, MockPage};
+    use cerium::client::driver::pool::{PoolConfig, SessionPool};
+    use cerium::error::CeriumResult;
+    use engine::controller::validator::{ExecutionReport, ReportItem};
+    use engine::server::executor::Executor;
+    use engine::server::registry::ExecutionRegistry;
+    use entity::prelude::{case_block, data_binding};
+    use entity::prelude::case_block::{BlockKind, BlockType};
+    use entity::test::ui::action::action;
+    use entity::test::ui::action::action::ActionKind;
+    use entity::test::ui::action::group;
+    use entity::test::ui::action::group::ActionGroupKind;
+    use entity::test::ui::case::case;
+    use entity::test::ui::log::item_log;
+    use entity::test::ui::request;
+    use entity::test::ui::request::{ExecutionKind, ExecutionStatus, ExecutionType};
+
+    use crate::common::{self, table};
+
+    const PAGE: &str = "https://orca.test/";
+
+    /// case - test case opening the page `opens` times, committed so the background run sees it
+    async fn case(db: &DatabaseConnection, opens: i32) -> Uuid {
+        case_opening(db, opens, PAGE).await
+    }
+
+    /// case_opening - test case opening the given page `opens` times
+    async fn case_opening(db: &DatabaseConnection, opens: i32, page: &str) -> Uuid {
+        let (case_id, group_id) = (Uuid::new_v4(), Uuid::new_v4());
+        case::ActiveModel {
+            id: Set(case_id),
+            name: Set("Open".to_string()),
+            description: Set(None),
+            app_id: Set(Uuid::new_v4()),
+        }.insert(db).await.expect("case");
+        group::ActiveModel {
+            id: Set(group_id),
+            name: Set("Steps".to_string()),
+            description: Set(None),
+            type_field: Set(ActionGroupKind::ActionGroup),
+            app_id: Set(Uuid::new_v4()),
+        }.insert(db).await.expect("action group");
+        for order in 1..=opens {
+            action::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                execution_order: Set(order),
+                description: Set(None),
+                kind: Set(ActionKind::Open),
+                data_kind: Set(None),
+                data_value: Set(Some(page.to_string())),
+                target_kind: Set(None),
+                target_value: Set(None),
+                element_id: Set(None),
+                timeout: Set(None),
+                action_group_id: Set(group_id),
+            }.insert(db).await.expect("action");
+        }
+        case_block::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            execution_order: Set(1),
+            kind: Set(BlockKind::Reference),
+            name: Set(None),
+            desc: Set(None),
+            type_field: Set(BlockType::ActionGroup),
+            reference: Set(Some(group_id)),
+            parent_id: Set(None),
+            timeout: Set(None),
+            case_id: Set(case_id),
+        }.insert(db).await.expect("case block");
+        case_id
+    }
+
+    /// trigger - run the test case in the background on the sessions of the pool
+    async fn trigger(db: &DatabaseConnection, case_id: Uuid, pool: SessionPool,
+                     registry: &ExecutionRegistry) -> request::Model {
+        let er = request::new(case_id, ExecutionType::TestCase, ExecutionKind::Trigger,
+                              ExecutionStatus::Started, 0, false, None)
+            .insert(db).await.expect("execution request");
+        let client = Client::with_db(None, db.clone()).await;
+        Executor::new(client, registry.clone()).with_pool(Some(pool)).trigger(er.clone());
+        er
+    }
+
+    /// finished - wait for the execution to reach its final status
+    async fn finished(db: &DatabaseConnection, id: i32) -> request::Model {
+        for _ in 0..250 {
+            let er = request::Entity::find_by_id(id).one(db).await.expect("query").expect("execution request");
+            if !matches!(er.status, ExecutionStatus::Started | ExecutionStatus::Running) {
+                return er;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("execution {id} did not finish");
+    }
+
+    async fn db() -> DatabaseConnection {
+        common::db(vec![
+            table(case::Entity),
+            table(case_block::Entity),
+            table(group::Entity),
+            table(action::Entity),
+            table(item_log::Entity),
+            table(request::Entity),
+            table(data_binding::Entity),
+        ]).await
+    }
+
+    fn pool(driver: MockDriver) -> SessionPool {
+        SessionPool::new(PoolConfig::default(), move || {
+            let driver = driver.clone();
+            async move { Ok(Arc::new(driver) as Arc<dyn Driver>) }
+        })
+    }
+
+    /// session - browser session factory blowing up inside the execution
+    fn session() -> CeriumResult<Arc<dyn Driver>> {
+        panic!("browser session factory")
+    }
+
+    #[tokio::test]
+    async fn finished_execution_is_completed() {
+        let db = db().await;
+        let registry = ExecutionRegistry::new();
+        let case_id = case(&db, 2).await;
+        let driver = MockDriver::new().page(PAGE, MockPage::new());
+
+        let er = trigger(&db, case_id, pool(driver.clone()), &registry).await;
+        let er = finished(&db, er.id).await;
+
+        assert_eq!(er.status, ExecutionStatus::Completed);
+        assert!(!registry.is_running(er.id));
+        let opens = driver.interactions().into_iter().filter(|i| matches!(i, Interaction::Open(_))).count();
+        assert_eq!(opens, 2);
+    }
+
+    #[tokio::test]
+    async fn cancelled_execution_stops_after_the_step() {
+        let db = db().await;
+        let registry = ExecutionRegistry::new();
+        let case_id = case(&db, 3).await;
+        let driver = MockDriver::new()
+            .page(PAGE, MockPage::new())
+            .delay_on(Interaction::Open(PAGE.to_string()), Duration::from_millis(200));
+
+        let er = trigger(&db, case_id, pool(driver.clone()), &registry).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(registry.cancel(er.id));
+        let er = finished(&db, er.id).await;
+
+        assert_eq!(er.status, ExecutionStatus::Cancelled);
+        assert!(!registry.is_running(er.id));
+        let opens = driver.interactions().into_iter().filter(|i| matches!(i, Interaction::Open(_))).count();
+        assert!(opens < 3);
+    }
+
+    #[tokio::test]
+    async fn panicked_execution_is_failed() {
+        let db = db().await;
+        let registry = ExecutionRegistry::new();
+        let case_id = case(&db, 1).await;
+        let pool = SessionPool::new(PoolConfig::default(), || async { session() });
+
+        let er = trigger(&db, case_id, pool, &registry).await;
+        let er = finished(&db, er.id).await;
+
+        assert_eq!(er.status, ExecutionStatus::Failed);
+        assert!(!registry.is_running(er.id));
+    }
+
+    #[tokio::test]
+    async fn dry_run_saves_the_report_without_a_browser_session() {
+        let db = db().await;
+        let registry = ExecutionRegistry::new();
+        let case_id = case_opening(&db, 1, "${page}").await;
+        let sessions = Arc::new(AtomicUsize::new(0));
+        let pool = {
+            let sessions = sessions.clone();
+            SessionPool::new(PoolConfig::default(), move || {
+                sessions.fetch_add(1, Ordering::SeqCst);
+                async { session() }
+            })
+        };
+        let er = request::new(case_id, ExecutionType::TestCase, ExecutionKind::Trigger,
+                              ExecutionStatus::Started, 0, true, None)
+            .insert(&db).await.expect("execution request");
+        let client = Client::with_db(None, db.clone()).await;
+        Executor::new(client, registry.clone()).with_pool(Some(pool)).trigger(er.clone());
+
+        let er = finished(&db, er.id).await;
+
+        assert_eq!(er.status, ExecutionStatus::Failed);
+        assert_eq!(sessions.load(Ordering::SeqCst), 0);
+        let report: ExecutionReport = serde_json::from_value(er.report.expect("report")).expect("execution report");
+        assert_eq!((report.cases, report.blocks, report.actions), (1, 1, 1));
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].item, ReportItem::Action);
+        assert!(report.issues[0].block_id.is_some());
+        assert_eq!(report.issues[0].message, r#"Unresolved placeholder(s) ["page"] in data_value"#);
+    }
+}
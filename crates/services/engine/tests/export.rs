@@ -0,0 +1,209 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ActiveModelTrait, DatabaseTransaction};
+    use sea_orm::ActiveValue::Set;
+    use uuid::Uuid;
+
+    use engine::error::EngineError;
+    use engine::export::ExportFormat;
+    use engine::export::rust::{fn_name, RustExporter};
+    use entity::prelude::case_block::{self, BlockKind, BlockType};
+    use entity::prelude::target::ActionTargetKind;
+    use entity::test::ui::action::action::{self, ActionKind};
+    use entity::test::ui::action::group::{self, ActionGroupKind};
+    use entity::test::ui::case::case;
+    use entity::test::ui::elements::{self, ElementCreateType};
+    use entity::test::ui::screen;
+
+    use crate::common::{self, table};
+
+    async fn trx() -> DatabaseTransaction {
+        common::trx(vec![
+            table(group::Entity),
+            table(action::Entity),
+            table(screen::Entity),
+            table(elements::Entity),
+        ]).await
+    }
+
+    fn step(order: i32, kind: ActionKind, target: Option<(ActionTargetKind, &str)>,
+            data: Option<&str>, group_id: Uuid) -> action::ActiveModel {
+        action::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            execution_order: Set(order),
+            description: Set(None),
+            kind: Set(kind),
+            data_kind: Set(None),
+            data_value: Set(data.map(str::to_string)),
+            target_kind: Set(target.as_ref().map(|(kind, _)| kind.clone())),
+            target_value: Set(target.map(|(_, value)| value.to_string())),
+            element_id: Set(None),
+            timeout: Set(None),
+            action_group_id: Set(group_id),
+        }
+    }
+
+    fn block(order: i32, type_field: BlockType, reference: Option<Uuid>) -> case_block::Model {
+        case_block::Model {
+            id: Uuid::new_v4(),
+            execution_order: order,
+            kind: if reference.is_some() { BlockKind::Reference } else { BlockKind::SelfReference },
+            name: None,
+            desc: None,
+            type_field,
+            reference,
+            parent_id: None,
+            timeout: None,
+            case_id: Uuid::nil(),
+            children: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn case_is_exported_as_thirtyfour_test() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+        let group = group::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set("Sign in".to_string()),
+            description: Set(None),
+            type_field: Set(ActionGroupKind::ActionGroup),
+            app_id: Set(app_id),
+        }.insert(&trx).await.expect("group");
+        let screen = screen::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set("Login".to_string()),
+            description: Set(None),
+            app_id: Set(app_id),
+        }.insert(&trx).await.expect("screen");
+        let submit = elements::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set("Submit".to_string()),
+            description: Set(None),
+            locator_value: Set("app-login >>> button".to_string()),
+            element_type: Set(None),
+            created_type: Set(ElementCreateType::Manual),
+            locator_type: Set(ActionTargetKind::Shadow),
+            proposed_locator_id: Set(None),
+            screen_id: Set(screen.id),
+        }.insert(&trx).await.expect("element");
+        let steps = [
+            step(1, ActionKind::Open, None, Some("https://orca.test/login"), group.id),
+            step(2, ActionKind::Enter, Some((ActionTargetKind::Id, "email")), Some("orca@orca.test"), group.id),
+            step(3, ActionKind::Click, None, None, group.id),
+            step(4, ActionKind::VerifyText, Some((ActionTargetKind::Css, "h1")), Some("Welcome"), group.id),
+        ];
+        for (index, mut step) in steps.into_iter().enumerate() {
+            if index == 2 {
+                step.element_id = Set(Some(submit.id));
+            }
+            step.insert(&trx).await.expect("action");
+        }
+        let case = case::Model {
+            id: Uuid::new_v4(),
+            name: "Login with email".to_string(),
+            description: None,
+            app_id,
+        };
+        let mut wrapper = block(1, BlockType::Block, None);
+        wrapper.children = Some(vec![block(1, BlockType::ActionGroup, Some(group.id))]);
+
+        let code = RustExporter::new(&trx).export(&case, &[wrapper]).await.expect("export");
+
+        assert!(code.contains("use thirtyfour::prelude::*;"));
+        assert!(code.contains("#[tokio::test]\nasync fn login_with_email() -> WebDriverResult<()>"));
+        assert!(code.contains("driver.goto(\"https://orca.test/login\").await?;"));
+        assert!(code.contains("driver.find(By::Id(\"email\")).await?.send_keys(\"orca@orca.test\").await?;"));
+        assert!(code.contains(
+            "driver.find(By::Css(\"app-login\")).await?.get_shadow_root().await?.find(By::Css(\"button\")).await?.click().await?;"
+        ));
+        assert!(code.contains("assert_eq!(driver.find(By::Css(\"h1\")).await?.inner_html().await?, \"Welcome\");"));
+        let open = code.find("driver.goto").expect("open");
+        let click = code.find(".click()").expect("click");
+        assert!(open < click);
+        syn::parse_file(&code).expect("generated source is valid rust");
+    }
+
+    #[tokio::test]
+    async fn control_flow_blocks_are_not_exported() {
+        let trx = trx().await;
+        let case = case::Model {
+            id: Uuid::new_v4(),
+            name: "Loop".to_string(),
+            description: None,
+            app_id: Uuid::new_v4(),
+        };
+        for type_field in [BlockType::Loop, BlockType::Condition] {
+            let mut parent = block(1, type_field, None);
+            parent.children = Some(vec![block(1, BlockType::ActionGroup, Some(Uuid::new_v4()))]);
+
+            let result = RustExporter::new(&trx).export(&case, &[parent]).await;
+
+            assert!(matches!(result, Err(EngineError::Unsupported(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn skipped_actions_and_placeholders_are_commented() {
+        let trx = trx().await;
+        let group = group::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set("Search".to_string()),
+            description: Set(None),
+            type_field: Set(ActionGroupKind::ActionGroup),
+            app_id: Set(Uuid::new_v4()),
+        }.insert(&trx).await.expect("group");
+        let double_click = step(2, ActionKind::DoubleClick, Some((ActionTargetKind::Css, "td")), None, group.id)
+            .insert(&trx).await.expect("action");
+        step(1, ActionKind::Open, None, Some("${base_url}/search"), group.id).insert(&trx).await.expect("action");
+        step(3, ActionKind::Enter, Some((ActionTargetKind::Id, "q")), Some("orca"), group.id)
+            .insert(&trx).await.expect("action");
+        let case = case::Model {
+            id: Uuid::new_v4(),
+            name: "Search".to_string(),
+            description: None,
+            app_id: group.app_id,
+        };
+
+        let code = RustExporter::new(&trx).export(&case, &[block(1, BlockType::ActionGroup, Some(group.id))]).await
+            .expect("export");
+
+        assert!(code.contains(&format!("// DoubleClick action {} is not executed by Orca, skipped", double_click.id)));
+        assert!(!code.contains("double_click_element"));
+        assert!(code.contains("driver.goto(\"${base_url}/search\").await?; // TODO: replace the Orca placeholder"));
+        assert!(code.contains("driver.find(By::Id(\"q\")).await?.send_keys(\"orca\").await?;\n"));
+        syn::parse_file(&code).expect("generated source is valid rust");
+    }
+
+    #[tokio::test]
+    async fn deleted_action_group_fails_the_export() {
+        let trx = trx().await;
+        let case = case::Model {
+            id: Uuid::new_v4(),
+            name: "Search".to_string(),
+            description: None,
+            app_id: Uuid::new_v4(),
+        };
+
+        let result = RustExporter::new(&trx).export(&case, &[block(1, BlockType::ActionGroup, Some(Uuid::new_v4()))]).await;
+
+        assert!(matches!(result, Err(EngineError::MissingParameter(name, _)) if name == "ActionGroup"));
+    }
+
+    #[test]
+    fn test_function_name() {
+        assert_eq!(fn_name("Login with e-mail"), "login_with_e_mail");
+        assert_eq!(fn_name("  2FA  "), "case_2fa");
+        assert_eq!(fn_name("!!"), "exported_case");
+        assert_eq!(fn_name("Loop"), "case_loop");
+        assert_eq!(fn_name("self"), "case_self");
+    }
+
+    #[test]
+    fn export_format() {
+        assert_eq!(ExportFormat::parse("Rust").ok(), Some(ExportFormat::Rust));
+        assert!(ExportFormat::parse("java").is_err());
+    }
+}
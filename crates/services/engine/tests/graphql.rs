@@ -0,0 +1,61 @@
+#[cfg(all(test, feature = "api-automation"))]
+mod tests {
+    use serde_json::json;
+
+    use engine::controller::data::DataScope;
+    use engine::controller::graphql::{errors, payload, variables};
+    use entity::api::step::GraphQl;
+
+    fn document(query: &str) -> GraphQl {
+        GraphQl { query: query.to_string(), variables: None, operation_name: None }
+    }
+
+    #[test]
+    fn operation_of_the_document() {
+        assert_eq!(document("{ users { id } }").operation(), Some("query"));
+        assert_eq!(document("# list\nquery Users { users { id } }").operation(), Some("query"));
+        assert_eq!(document("mutation { createUser(name: \"orca\") { id } }").operation(), Some("mutation"));
+        assert_eq!(document("fragment F on User { id name }\nsubscription { onUser { ...F } }").operation(),
+                   Some("subscription"));
+        assert_eq!(document("fragment F on User { id }").operation(), None);
+    }
+
+    #[test]
+    fn variables_keep_the_json_type() {
+        let mut scope = DataScope::new();
+        scope.insert_json("id", &json!(42));
+        scope.insert("name", "orca");
+        scope.insert("zip", "00042");
+        let value = json!({"id": "${id}", "zip": "${zip}",
+                           "input": {"label": "user ${name}", "tags": ["${name}"]}, "limit": 10});
+
+        let resolved = variables(&scope, &value).expect("variables");
+
+        assert_eq!(resolved, json!({"id": 42, "zip": "00042",
+                                    "input": {"label": "user orca", "tags": ["orca"]}, "limit": 10}));
+        assert_eq!(variables(&scope, &json!({"token": "${token}"})), Err(vec!["token".to_string()]));
+    }
+
+    #[test]
+    fn payload_of_the_request() {
+        let mut graphql = document("query User($id: ID!) { user(id: $id) { name } }");
+        graphql.operation_name = Some("User".to_string());
+
+        assert_eq!(payload(&graphql, Some(json!({"id": 42}))), json!({
+            "query": "query User($id: ID!) { user(id: $id) { name } }",
+            "variables": {"id": 42},
+            "operationName": "User"
+        }));
+        assert_eq!(payload(&document("{ me { id } }"), None), json!({"query": "{ me { id } }"}));
+    }
+
+    #[test]
+    fn errors_of_the_response() {
+        assert_eq!(errors(r#"{"data": {"user": {"name": "orca"}}}"#), None);
+        assert_eq!(errors(r#"{"data": null, "errors": []}"#), None);
+        assert_eq!(errors(r#"{"data": null, "errors": [{"message": "Not found", "path": ["user", 0]}, {"message": "Denied"}]}"#)
+                       .as_deref(),
+                   Some("GraphQL errors: Not found at 'user.0'; Denied"));
+        assert!(errors("<html>").is_some_and(|message| message.starts_with("GraphQL response is not json")));
+    }
+}
@@ -0,0 +1,107 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder};
+    use uuid::Uuid;
+
+    use engine::import::side::{side_keys, side_target, SideImporter, SideProject};
+    use entity::prelude::case_block;
+    use entity::prelude::target::ActionTargetKind;
+    use entity::test::ui::action::action;
+    use entity::test::ui::action::action::ActionKind;
+    use entity::test::ui::action::group;
+    use entity::test::ui::case::case;
+    use entity::test::ui::suit::{suite, suite_block};
+
+    use crate::common::{self, table};
+
+    async fn trx() -> DatabaseTransaction {
+        common::trx(vec![
+            table(group::Entity),
+            table(action::Entity),
+            table(case::Entity),
+            table(case_block::Entity),
+            table(suite::Entity),
+            table(suite_block::Entity),
+        ]).await
+    }
+
+    fn project() -> SideProject {
+        serde_json::from_value(serde_json::json!({
+            "id": "c5d1", "version": "2.0", "name": "Legacy", "url": "https://orca.test",
+            "tests": [{
+                "id": "t1", "name": "Login",
+                "commands": [
+                    {"id": "1", "command": "open", "target": "/login", "value": ""},
+                    {"id": "2", "command": "setWindowSize", "target": "1280x800", "value": ""},
+                    {"id": "3", "command": "type", "target": "id=email", "value": "orca@orca.test"},
+                    {"id": "4", "command": "click", "target": "css=#submit", "value": ""},
+                    {"id": "5", "command": "assertText", "target": "//h1", "value": "Welcome"},
+                    {"id": "6", "command": "click", "target": "dom=document.forms[0]", "value": ""},
+                    {"id": "7", "command": "sendKeys", "target": "id=search", "value": "orca${KEY_ENTER}"},
+                    {"id": "8", "command": "doubleClick", "target": "css=.row", "value": ""},
+                    {"id": "9", "command": "sendKeys", "target": "id=search", "value": "${KEY_F5}"}
+                ]
+            }],
+            "suites": [{"id": "s1", "name": "Smoke", "tests": ["t1", "t404"]}],
+            "urls": ["https://orca.test/"], "plugins": []
+        })).expect("side project")
+    }
+
+    #[test]
+    fn side_targets() {
+        assert_eq!(side_target("id=email"), Some((ActionTargetKind::Id, "email".to_string())));
+        assert_eq!(side_target("css=a[href='/?x=1']"), Some((ActionTargetKind::Css, "a[href='/?x=1']".to_string())));
+        assert_eq!(side_target("//div[@id='x']"), Some((ActionTargetKind::Xpath, "//div[@id='x']".to_string())));
+        assert_eq!(side_target("linkText=Home"), Some((ActionTargetKind::LinkText, "Home".to_string())));
+        assert_eq!(side_target("dom=document.body"), None);
+    }
+
+    #[test]
+    fn side_keys_are_sent_as_webdriver_keys() {
+        assert_eq!(side_keys("orca${KEY_ENTER}"), Ok("orca\u{E007}".to_string()));
+        assert_eq!(side_keys("${KEY_TAB}${KEY_DEL}"), Ok("\u{E004}\u{E017}".to_string()));
+        assert_eq!(side_keys("${email}"), Ok("${email}".to_string()));
+        assert_eq!(side_keys("${KEY_F5}"), Err("KEY_F5".to_string()));
+    }
+
+    #[tokio::test]
+    async fn side_tests_are_imported_as_cases() {
+        let trx = trx().await;
+
+        let report = SideImporter::new(&trx, Uuid::new_v4()).import(project()).await.expect("import");
+
+        assert_eq!((report.cases, report.action_groups, report.actions, report.suites), (1, 1, 5, 1));
+        let actions = action::Entity::find()
+            .order_by_asc(action::Column::ExecutionOrder)
+            .all(&trx).await.expect("actions");
+        let kinds: Vec<ActionKind> = actions.iter().map(|a| a.kind.clone()).collect();
+        assert_eq!(kinds, vec![ActionKind::Open, ActionKind::Enter, ActionKind::Click, ActionKind::VerifyText,
+                               ActionKind::Enter]);
+        assert_eq!(actions[0].data_value.as_deref(), Some("https://orca.test/login"));
+        assert_eq!(actions[1].target_kind, Some(ActionTargetKind::Id));
+        assert_eq!(actions[3].data_value.as_deref(), Some("Welcome"));
+        assert_eq!(actions[4].data_value.as_deref(), Some("orca\u{E007}"));
+        let blocks = case_block::Entity::find().all(&trx).await.expect("case blocks");
+        assert_eq!(blocks[0].reference, Some(actions[0].action_group_id));
+    }
+
+    #[tokio::test]
+    async fn unsupported_commands_are_reported() {
+        let trx = trx().await;
+
+        let report = SideImporter::new(&trx, Uuid::new_v4()).import(project()).await.expect("import");
+
+        let items: Vec<(&str, usize, &str)> = report.unsupported.iter()
+            .map(|i| (i.source.as_str(), i.index, i.item.as_str()))
+            .collect();
+        assert_eq!(items, vec![("Login", 2, "setWindowSize"), ("Login", 6, "click"), ("Login", 8, "doubleClick"),
+                               ("Login", 9, "sendKeys"), ("Smoke", 2, "t404")]);
+        let case = case::Entity::find().one(&trx).await.expect("case").expect("case exists");
+        let blocks = suite_block::Entity::find()
+            .filter(suite_block::Column::Reference.eq(case.id))
+            .all(&trx).await.expect("suite blocks");
+        assert_eq!(blocks.len(), 1);
+    }
+}
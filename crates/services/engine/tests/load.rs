@@ -0,0 +1,193 @@
+mod common;
+
+#[cfg(all(test, feature = "api-automation"))]
+mod tests {
+    use sea_orm::{ActiveModelTrait, DatabaseTransaction};
+    use sea_orm::ActiveValue::Set;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use engine::controller::data::Variables;
+    use engine::controller::load::{LoadConfig, LoadController, percentile};
+    use engine::error::EngineError;
+    use engine::server::registry::ExecutionToken;
+    use entity::api::{assertion, spec, step};
+    use entity::api::assertion::AssertionKind;
+    use entity::api::step::ApiMethod;
+    use entity::prelude::case_block;
+    use entity::prelude::case_block::{BlockKind, BlockType};
+    use entity::test::ui::request;
+    use entity::test::ui::request::{ExecutionKind, ExecutionStatus, ExecutionType};
+
+    use crate::common::{self, serve_with, table};
+
+    async fn trx() -> DatabaseTransaction {
+        common::trx(vec![
+            table(request::Entity),
+            table(case_block::Entity),
+            table(spec::Entity),
+            table(step::Entity),
+            table(assertion::Entity),
+        ]).await
+    }
+
+    /// serve - order api answering every request, `POST /orders` creates the order with the
+    /// next id and `GET /orders/:id` finds only the orders with the even id
+    async fn serve() -> String {
+        let mut next = 0;
+        serve_with(move |request| {
+            let (status, body) = match request.split_whitespace().take(2).collect::<Vec<_>>()[..] {
+                ["POST", "/orders"] => {
+                    next += 1;
+                    ("201 Created", format!(r#"{{"id":"{next}"}}"#))
+                }
+                ["GET", path] if path.trim_start_matches("/orders/").parse::<u32>().is_ok_and(|id| id % 2 == 0) => {
+                    ("200 OK", r#"{"status":"PAID"}"#.to_string())
+                }
+                _ => ("404 Not Found", "{}".to_string()),
+            };
+            format!("HTTP/1.1 {status}\r\ncontent-type: application/json\r\n\
+                     content-length: {}\r\nconnection: close\r\n\r\n{body}", body.len())
+        }).await
+    }
+
+    async fn api_step(trx: &DatabaseTransaction, method: ApiMethod, url: String,
+                      expected_status: i32) -> step::Model {
+        step::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(format!("{method:?} {url}")),
+            description: Set(None),
+            method: Set(method),
+            url: Set(url),
+            headers: Set(None),
+            query: Set(None),
+            body: Set(None),
+            auth: Set(None),
+            expected_status: Set(Some(expected_status)),
+            timeout: Set(Some(5000)),
+            spec_id: Set(None),
+            operation_id: Set(None),
+            graphql: Set(None),
+            app_id: Set(Uuid::new_v4()),
+        }.insert(trx).await.expect("api step")
+    }
+
+    async fn block(trx: &DatabaseTransaction, case_id: Uuid, order: i32, type_field: BlockType, reference: Uuid) {
+        case_block::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            execution_order: Set(order),
+            kind: Set(BlockKind::Reference),
+            name: Set(None),
+            desc: Set(None),
+            type_field: Set(type_field),
+            reference: Set(Some(reference)),
+            parent_id: Set(None),
+            timeout: Set(None),
+            case_id: Set(case_id),
+        }.insert(trx).await.expect("case block");
+    }
+
+    async fn execution(trx: &DatabaseTransaction, case_id: Uuid) -> request::Model {
+        request::new(case_id, ExecutionType::TestCase, ExecutionKind::Trigger,
+                     ExecutionStatus::Running, 0, false, None)
+            .insert(trx).await.expect("execution request")
+    }
+
+    fn config(load: serde_json::Value) -> LoadConfig {
+        serde_json::from_value(load).expect("load config")
+    }
+
+    #[test]
+    fn config_and_percentiles_are_checked() {
+        assert!(config(json!({"users": 10, "duration": 60000, "ramp_up": 5000})).check().is_ok());
+        assert!(config(json!({"users": 0})).check().is_err());
+        assert!(config(json!({"users": 501})).check().is_err());
+        assert!(config(json!({"users": 1, "iterations": 0})).check().is_err());
+        assert!(config(json!({"users": 1, "duration": 1000, "ramp_up": 1000})).check().is_err());
+        assert!(config(json!({"users": 1, "max_error_rate": 1.5})).check().is_err());
+
+        let latencies: Vec<u64> = (1..=100).collect();
+        assert_eq!(percentile(&latencies, 50.0), 50);
+        assert_eq!(percentile(&latencies, 95.0), 95);
+        assert_eq!(percentile(&latencies, 99.0), 99);
+        assert_eq!(percentile(&[7], 99.0), 7);
+        assert_eq!(percentile(&[], 50.0), 0);
+    }
+
+    #[tokio::test]
+    async fn virtual_users_run_the_api_steps() {
+        let trx = trx().await;
+        let url = serve().await;
+        let case_id = Uuid::new_v4();
+        let create = api_step(&trx, ApiMethod::Post, format!("{url}/orders"), 201).await;
+        assertion::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            execution_order: Set(1),
+            kind: Set(AssertionKind::JsonPath),
+            expression: Set(Some("$.id".to_string())),
+            match_mode: Set(None),
+            expected: Set(None),
+            variable: Set(Some("order_id".to_string())),
+            step_id: Set(create.id),
+        }.insert(&trx).await.expect("assertion");
+        let find = api_step(&trx, ApiMethod::Get, format!("{url}/orders/${{order_id}}"), 200).await;
+        block(&trx, case_id, 1, BlockType::ApiStep, create.id).await;
+        block(&trx, case_id, 2, BlockType::ApiStep, find.id).await;
+        let er = execution(&trx, case_id).await;
+        let config = config(json!({"users": 2, "iterations": 3, "ramp_up": 50}));
+
+        let report = LoadController::new(&trx, Variables::new(), ExecutionToken::new())
+            .run(case_id, &er, &config).await.expect("load test");
+
+        assert_eq!((report.users, report.iterations), (2, 6));
+        assert_eq!((report.requests, report.errors), (12, 3));
+        assert_eq!(report.error_rate, 0.25);
+        assert!(report.throughput > 0.0);
+        assert!(report.latency.p50 <= report.latency.p95 && report.latency.p95 <= report.latency.p99);
+        assert_eq!(report.steps.iter().map(|step| (step.step_id, step.requests, step.errors)).collect::<Vec<_>>(),
+                   vec![(create.id, 6, 0), (find.id, 6, 3)]);
+        assert_eq!(report.failures.get("status 404"), Some(&3));
+        assert!(!report.timed_out);
+        assert!(report.check(Some(0.5)).is_ok());
+        assert!(report.check(Some(0.1)).is_err());
+    }
+
+    #[tokio::test]
+    async fn users_are_stopped_at_the_execution_timeout() {
+        let trx = trx().await;
+        let (listener, address) = common::listen().await;
+        // accept the requests and never answer them
+        tokio::spawn(async move {
+            let mut streams = vec![];
+            while let Ok((stream, _)) = listener.accept().await {
+                streams.push(stream);
+            }
+        });
+        let case_id = Uuid::new_v4();
+        let hang = api_step(&trx, ApiMethod::Get, format!("http://{address}/orders"), 200).await;
+        block(&trx, case_id, 1, BlockType::ApiStep, hang.id).await;
+        let mut er = execution(&trx, case_id).await;
+        er.timeout = Some(200);
+        let config = config(json!({"users": 2, "duration": 60000}));
+
+        let report = LoadController::new(&trx, Variables::new(), ExecutionToken::new())
+            .run(case_id, &er, &config).await.expect("load test");
+
+        assert!(report.timed_out);
+        assert_eq!((report.users, report.requests), (2, 0));
+        assert!(report.duration < 2000);
+    }
+
+    #[tokio::test]
+    async fn only_api_steps_can_be_load_tested() {
+        let trx = trx().await;
+        let case_id = Uuid::new_v4();
+        block(&trx, case_id, 1, BlockType::ActionGroup, Uuid::new_v4()).await;
+        let er = execution(&trx, case_id).await;
+
+        let result = LoadController::new(&trx, Variables::new(), ExecutionToken::new())
+            .run(case_id, &er, &config(json!({"users": 1}))).await;
+
+        assert!(matches!(result, Err(EngineError::Unsupported(_))));
+    }
+}
@@ -0,0 +1,177 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+    use sea_orm::ActiveValue::Set;
+    use serde_json::{json, Value};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+    use uuid::Uuid;
+
+    use engine::server::mock::{MockConfig, MockServer, RECORDED_PATH};
+    use entity::api::step::ApiMethod;
+    use entity::common::attachment;
+    use entity::common::attachment::StorageCategory;
+    use entity::test::mock::endpoint;
+    use entity::test::mock::endpoint::MockFault;
+    use entity::test::mock::request as mock_request;
+    use entity::test::ui::request;
+    use entity::test::ui::request::{ExecutionKind, ExecutionStatus, ExecutionType};
+
+    use crate::common::{self, table};
+
+    async fn db() -> DatabaseConnection {
+        common::db(vec![
+            table(request::Entity),
+            table(attachment::Entity),
+            table(endpoint::Entity),
+            table(mock_request::Entity),
+        ]).await
+    }
+
+    async fn add_endpoint(db: &DatabaseConnection, app_id: Uuid, method: Option<ApiMethod>, path: &str,
+                          attachment_id: Option<i32>, fault: Option<MockFault>) -> endpoint::Model {
+        endpoint::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(path.to_string()),
+            method: Set(method),
+            path: Set(path.to_string()),
+            status: Set(200),
+            headers: Set(Some(json!({"content-type": "application/json"}))),
+            attachment_id: Set(attachment_id),
+            delay: Set(Some(10)),
+            fault: Set(fault),
+            app_id: Set(app_id),
+        }.insert(db).await.expect("mock endpoint")
+    }
+
+    /// send - send the raw request and read the raw response until the connection closes
+    async fn send(url: &str, raw: String) -> String {
+        let mut stream = TcpStream::connect(url.trim_start_matches("http://")).await.expect("connect");
+        stream.write_all(raw.as_bytes()).await.expect("write");
+        let mut response = Vec::new();
+        let _ = stream.read_to_end(&mut response).await;
+        String::from_utf8_lossy(&response).to_string()
+    }
+
+    #[test]
+    fn mock_config_is_read_from_the_request_args() {
+        let mut er = request::Model {
+            id: 1,
+            description: None,
+            is_dry_run: false,
+            ref_id: Uuid::new_v4(),
+            ref_type: ExecutionType::TestCase,
+            kind: ExecutionKind::Trigger,
+            status: ExecutionStatus::Started,
+            args: None,
+            timeout: None,
+            report: None,
+            log_id: 0,
+            created_at: chrono::Utc::now().into(),
+            created_by: "system".to_string(),
+            finished_at: chrono::Utc::now().into(),
+            updated_at: chrono::Utc::now().into(),
+        };
+        assert_eq!(MockConfig::from_request(&er), None);
+        er.args = Some(json!({"mock": true}));
+        assert_eq!(MockConfig::from_request(&er), Some(MockConfig { host: "127.0.0.1".to_string(), port: 0 }));
+        er.args = Some(json!({"mock": {"host": "0.0.0.0", "port": 8089}}));
+        assert_eq!(MockConfig::from_request(&er), Some(MockConfig { host: "0.0.0.0".to_string(), port: 8089 }));
+    }
+
+    #[tokio::test]
+    async fn endpoints_answer_and_requests_are_recorded() {
+        let db = db().await;
+        let app_id = Uuid::new_v4();
+        let er = request::new(Uuid::new_v4(), ExecutionType::TestCase, ExecutionKind::Trigger,
+                              ExecutionStatus::Running, 0, false, None)
+            .insert(&db).await.expect("execution request");
+        let data = attachment::ActiveModel {
+            id: Default::default(),
+            category: Set(StorageCategory::MockData),
+            reference_id: Set(Some(app_id)),
+            path: Set("mock/user.json".to_string()),
+            name: Set("user.json".to_string()),
+            desc: Set(None),
+            attachment: Set(Some(br#"{"id":"7","name":"orca"}"#.to_vec())),
+        }.insert(&db).await.expect("attachment");
+        let user = add_endpoint(&db, app_id, Some(ApiMethod::Get), "/users/:id", Some(data.id), None).await;
+        let pay = add_endpoint(&db, app_id, Some(ApiMethod::Post), "/pay", None, Some(MockFault::ServerError)).await;
+        add_endpoint(&db, app_id, None, "/reset/*", None, Some(MockFault::Reset)).await;
+        let config = MockConfig { host: "127.0.0.1".to_string(), port: 0 };
+
+        let server = MockServer::start(db.clone(), er.id, app_id, &config).await.expect("mock server");
+
+        let res = send(server.url(), "GET /users/7?full=true HTTP/1.1\r\nhost: mock\r\n\r\n".to_string()).await;
+        assert!(res.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(res.contains("content-type: application/json\r\n"));
+        assert!(res.ends_with(r#"{"id":"7","name":"orca"}"#));
+
+        let body = r#"{"amount":10}"#;
+        let raw = format!("POST /pay HTTP/1.1\r\ncontent-length: {}\r\n\r\n{body}", body.len());
+        assert!(send(server.url(), raw).await.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+        assert_eq!(send(server.url(), "PUT /reset/now HTTP/1.1\r\n\r\n".to_string()).await, "");
+        assert!(send(server.url(), "GET /pay HTTP/1.1\r\n\r\n".to_string()).await.starts_with("HTTP/1.1 404"));
+
+        let recorded = mock_request::Entity::find()
+            .filter(mock_request::Column::ExecutionId.eq(er.id))
+            .all(&db).await.expect("mock requests");
+        assert_eq!(recorded.len(), 4);
+        assert_eq!(recorded, server.requests());
+        assert_eq!((recorded[0].endpoint_id, recorded[0].status), (Some(user.id), Some(200)));
+        assert_eq!(recorded[0].query.as_deref(), Some("full=true"));
+        assert_eq!(recorded[0].headers, json!({"host": "mock"}));
+        assert_eq!((recorded[1].endpoint_id, recorded[1].body.as_deref()), (Some(pay.id), Some(body)));
+        assert_eq!(recorded[2].status, None);
+        assert_eq!((recorded[3].endpoint_id, recorded[3].status), (None, Some(404)));
+
+        let res = send(server.url(), format!("GET {RECORDED_PATH} HTTP/1.1\r\n\r\n")).await;
+        let (_, body) = res.split_once("\r\n\r\n").expect("response body");
+        let body: Value = serde_json::from_str(body).expect("recorded requests");
+        assert_eq!(body.as_array().map(Vec::len), Some(4));
+        assert_eq!(body[1]["path"], json!("/pay"));
+    }
+
+    #[tokio::test]
+    async fn chunked_request_is_recorded_and_drop_stops_the_server() {
+        let db = db().await;
+        let app_id = Uuid::new_v4();
+        let er = request::new(Uuid::new_v4(), ExecutionType::TestCase, ExecutionKind::Trigger,
+                              ExecutionStatus::Running, 0, false, None)
+            .insert(&db).await.expect("execution request");
+        add_endpoint(&db, app_id, Some(ApiMethod::Post), "/orders", None, None).await;
+        let config = MockConfig { host: "127.0.0.1".to_string(), port: 0 };
+        let server = MockServer::start(db.clone(), er.id, app_id, &config).await.expect("mock server");
+
+        let raw = "POST /orders HTTP/1.1\r\ntransfer-encoding: chunked\r\n\r\n4\r\n{\"a\"\r\n3\r\n:1}\r\n0\r\n\r\n";
+        assert!(send(server.url(), raw.to_string()).await.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert_eq!(server.requests()[0].body.as_deref(), Some(r#"{"a":1}"#));
+
+        let address = server.url().trim_start_matches("http://").to_string();
+        drop(server);
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        assert!(TcpStream::connect(address).await.is_err());
+    }
+
+    #[test]
+    fn endpoint_matches_the_method_and_path() {
+        let endpoint = endpoint::Model {
+            id: Uuid::new_v4(),
+            name: "user".to_string(),
+            method: Some(ApiMethod::Get),
+            path: "/users/:id/*".to_string(),
+            status: 200,
+            headers: None,
+            attachment_id: None,
+            delay: None,
+            fault: None,
+            app_id: Uuid::new_v4(),
+        };
+        assert!(endpoint.matches("GET", "/users/7/orders"));
+        assert!(!endpoint.matches("POST", "/users/7/orders"));
+        assert!(!endpoint.matches("GET", "/users/7"));
+        assert!(!endpoint.matches("GET", "/accounts/7/orders"));
+    }
+}
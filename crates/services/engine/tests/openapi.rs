@@ -0,0 +1,157 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter};
+    use serde_json::{json, Value};
+    use uuid::Uuid;
+
+    use engine::error::EngineError;
+    use engine::import::openapi::{expected_status, parse, OpenApiImporter};
+    use entity::api::step::ApiMethod;
+    use entity::api::{spec, step};
+
+    use crate::common::{self, table};
+
+    async fn trx() -> DatabaseTransaction {
+        common::trx(vec![
+            table(spec::Entity),
+            table(step::Entity),
+        ]).await
+    }
+
+    fn petstore() -> Value {
+        json!({
+            "openapi": "3.0.3",
+            "info": {"title": "Petstore", "version": "1.0.0"},
+            "servers": [{"url": "https://petstore.test/v3/"}],
+            "paths": {
+                "/pet/{petId}": {
+                    "parameters": [{"$ref": "#/components/parameters/PetId"}],
+                    "get": {
+                        "operationId": "getPetById",
+                        "summary": "Find pet by ID",
+                        "parameters": [
+                            {"name": "fields", "in": "query", "schema": {"type": "string", "enum": ["name", "tags"]}},
+                            {"name": "X-Trace", "in": "header", "example": "orca"}
+                        ],
+                        "responses": {"default": {}, "404": {}, "200": {}}
+                    },
+                    "trace": {"responses": {"200": {}}}
+                },
+                "/pet": {
+                    "post": {
+                        "requestBody": {
+                            "content": {"application/json": {"schema": {"$ref": "#/components/schemas/Pet"}}}
+                        },
+                        "responses": {"201": {}, "200": {}}
+                    }
+                }
+            },
+            "components": {
+                "parameters": {
+                    "PetId": {"name": "petId", "in": "path", "required": true,
+                              "schema": {"type": "integer", "example": 42}}
+                },
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "properties": {
+                            "name": {"type": "string", "example": "doggie"},
+                            "age": {"type": "integer"},
+                            "tags": {"type": "array", "items": {"type": "string"}}
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn document_is_parsed_per_operation() {
+        let root = parse(&petstore()).expect("parse");
+        assert_eq!(root.info.title, "Petstore");
+        assert_eq!(root.servers[0].url, "https://petstore.test/v3/");
+        let operations: Vec<(&str, &str)> = root.paths.iter()
+            .map(|p| (p.method.as_str(), p.operation_id.as_str()))
+            .collect();
+        assert_eq!(operations, vec![("post", "post /pet"), ("get", "getPetById"), ("trace", "trace /pet/{petId}")]);
+        let get = &root.paths[1];
+        assert_eq!(get.parameters.len(), 3);
+        assert_eq!(get.parameters[0].example.as_deref(), Some("42"));
+        assert_eq!(get.parameters[0].schema.default, None);
+        assert_eq!(get.parameters[1].example.as_deref(), Some("name"));
+        assert_eq!(expected_status(&get.responses), Some(200));
+        assert!(parse(&json!({"swagger": "2.0"})).is_err());
+    }
+
+    #[tokio::test]
+    async fn operations_are_imported_as_api_steps() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+
+        let report = OpenApiImporter::new(&trx, app_id).import(petstore(), None).await.expect("import");
+
+        assert_eq!(report.api_steps, 2);
+        assert_eq!(report.unsupported.len(), 1);
+        assert_eq!(report.unsupported[0].item, "trace");
+        let spec = spec::Entity::find().one(&trx).await.expect("spec").expect("stored spec");
+        assert_eq!((spec.title.as_str(), spec.openapi.as_str()), ("Petstore", "3.0.3"));
+
+        let get = step::Entity::find()
+            .filter(step::Column::OperationId.eq("getPetById"))
+            .one(&trx).await.expect("step").expect("get pet step");
+        assert_eq!(get.name, "Find pet by ID");
+        assert_eq!(get.method, ApiMethod::Get);
+        assert_eq!(get.url, "https://petstore.test/v3/pet/42");
+        assert_eq!(get.query, Some(json!({"fields": "name"})));
+        assert_eq!(get.headers, Some(json!({"X-Trace": "orca"})));
+        assert_eq!(get.expected_status, Some(200));
+        assert_eq!(get.spec_id, Some(spec.id));
+
+        let post = step::Entity::find()
+            .filter(step::Column::Method.eq(ApiMethod::Post))
+            .one(&trx).await.expect("step").expect("add pet step");
+        assert_eq!(post.expected_status, Some(200));
+        assert_eq!(post.headers, Some(json!({"content-type": "application/json"})));
+        let body: Value = serde_json::from_str(post.body.as_deref().expect("body")).expect("json body");
+        assert_eq!(body, json!({"name": "doggie", "age": 0, "tags": ["string"]}));
+    }
+
+    #[tokio::test]
+    async fn reimport_updates_the_steps_of_the_operations() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+        OpenApiImporter::new(&trx, app_id).import(petstore(), None).await.expect("import");
+        let mut document = petstore();
+        document["paths"]["/pet/{petId}"]["get"]["summary"] = json!("Get a pet");
+
+        let report = OpenApiImporter::new(&trx, app_id).import(document, None).await.expect("reimport");
+
+        assert_eq!((report.api_steps, report.updated_api_steps), (0, 2));
+        assert_eq!(step::Entity::find().all(&trx).await.expect("steps").len(), 2);
+        assert_eq!(spec::Entity::find().all(&trx).await.expect("specs").len(), 1);
+        let get = step::Entity::find()
+            .filter(step::Column::OperationId.eq("getPetById"))
+            .one(&trx).await.expect("step").expect("get pet step");
+        assert_eq!(get.name, "Get a pet");
+    }
+
+    #[tokio::test]
+    async fn relative_server_needs_the_base_url() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+        let mut document = petstore();
+        document["servers"] = json!([{"url": "/v3"}]);
+
+        let result = OpenApiImporter::new(&trx, app_id).import(document.clone(), None).await;
+        assert!(matches!(result, Err(EngineError::MissingParameter(..))));
+
+        OpenApiImporter::new(&trx, app_id)
+            .import(document, Some("https://petstore.test/v3".to_string())).await.expect("import");
+        let get = step::Entity::find()
+            .filter(step::Column::OperationId.eq("getPetById"))
+            .one(&trx).await.expect("step").expect("get pet step");
+        assert_eq!(get.url, "https://petstore.test/v3/pet/42");
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use cerium::client::driver::Driver;
+    use cerium::client::driver::mock::{Interaction, MockDriver};
+    use cerium::client::driver::pool::{PoolConfig, SessionPool};
+
+    /// pool - pool of the mock sessions, the drivers opened by the pool are kept for the asserts
+    fn pool(config: PoolConfig) -> (SessionPool, Arc<Mutex<Vec<MockDriver>>>) {
+        let opened = Arc::new(Mutex::new(Vec::new()));
+        let drivers = opened.clone();
+        let pool = SessionPool::new(config, move || {
+            let drivers = drivers.clone();
+            async move {
+                let driver = MockDriver::new();
+                drivers.lock().unwrap().push(driver.clone());
+                Ok(Arc::new(driver) as Arc<dyn Driver>)
+            }
+        });
+        (pool, opened)
+    }
+
+    #[tokio::test]
+    async fn released_session_is_reset_and_reused() {
+        let (pool, opened) = pool(PoolConfig::default());
+
+        let lease = pool.lease().await.expect("lease");
+        lease.driver().open("https://orca.test").await.expect("open");
+        lease.release().await;
+        let lease = pool.lease().await.expect("lease");
+
+        assert_eq!(opened.lock().unwrap().len(), 1);
+        assert_eq!(lease.driver().current_url().await.expect("url"), "about:blank");
+        assert!(opened.lock().unwrap()[0].interactions().contains(&Interaction::Reset));
+    }
+
+    #[tokio::test]
+    async fn expired_session_is_closed() {
+        let (pool, opened) = pool(PoolConfig { size: 1, idle_timeout: Duration::from_millis(10) });
+
+        pool.lease().await.expect("lease").release().await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let _lease = pool.lease().await.expect("lease");
+
+        let opened = opened.lock().unwrap();
+        assert_eq!(opened.len(), 2);
+        assert_eq!(opened[0].interactions().last(), Some(&Interaction::Quit));
+    }
+
+    #[tokio::test]
+    async fn failed_reset_closes_the_session() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = count.clone();
+        let pool = SessionPool::new(PoolConfig::default(), move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok(Arc::new(MockDriver::new().fail_on(Interaction::Reset)) as Arc<dyn Driver>) }
+        });
+
+        pool.lease().await.expect("lease").release().await;
+
+        assert_eq!(pool.idle_count(), 0);
+        pool.lease().await.expect("lease").discard().await.expect("discard");
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn lease_waits_for_the_free_session() {
+        let (pool, opened) = pool(PoolConfig { size: 1, ..PoolConfig::default() });
+
+        let lease = pool.lease().await.expect("lease");
+        let waiting = tokio::time::timeout(Duration::from_millis(50), pool.lease()).await;
+        assert!(waiting.is_err());
+        lease.release().await;
+        pool.lease().await.expect("lease");
+
+        assert_eq!(opened.lock().unwrap().len(), 1);
+    }
+}
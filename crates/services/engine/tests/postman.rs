@@ -0,0 +1,204 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder};
+    use serde_json::{json, Value};
+    use uuid::Uuid;
+
+    use engine::import::postman::{translate, PostmanGrouping, PostmanImporter};
+    use entity::api::step;
+    use entity::api::step::ApiMethod;
+    use entity::prelude::case_block;
+    use entity::prelude::case_block::BlockType;
+    use entity::test::profile::{data as profile_data, profile};
+    use entity::test::ui::case::case;
+
+    use crate::common::{self, table};
+
+    async fn trx() -> DatabaseTransaction {
+        common::trx(vec![
+            table(step::Entity),
+            table(case::Entity),
+            table(case_block::Entity),
+            table(profile::Entity),
+            table(profile_data::Entity),
+        ]).await
+    }
+
+    fn collection() -> Value {
+        json!({
+            "info": {
+                "name": "Users API",
+                "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+            },
+            "auth": {"type": "bearer", "bearer": [{"key": "token", "value": "{{token}}", "type": "string"}]},
+            "variable": [{"key": "base_url", "value": "https://users.test"}],
+            "item": [
+                {"name": "Health", "request": "{{base_url}}/health"},
+                {
+                    "name": "Users",
+                    "item": [
+                        {
+                            "name": "Create user",
+                            "event": [{"listen": "test", "script": {"exec": ["pm.test()"]}}],
+                            "request": {
+                                "method": "POST",
+                                "header": [
+                                    {"key": "X-Trace", "value": "{{trace}}"},
+                                    {"key": "X-Off", "value": "1", "disabled": true}
+                                ],
+                                "url": {
+                                    "raw": "{{base_url}}/users?notify=false",
+                                    "host": ["{{base_url}}"],
+                                    "path": ["users"],
+                                    "query": [{"key": "notify", "value": "false"}]
+                                },
+                                "body": {"mode": "raw", "raw": "{\"name\": \"{{name}}\"}",
+                                         "options": {"raw": {"language": "json"}}}
+                            }
+                        },
+                        {
+                            "name": "Admin",
+                            "auth": {"type": "basic", "basic": [
+                                {"key": "username", "value": "admin"}, {"key": "password", "value": "{{password}}"}
+                            ]},
+                            "item": [{
+                                "name": "Delete user",
+                                "request": {"method": "DELETE", "url": "{{base_url}}/users/{{user_id}}?id={{$guid}}"}
+                            }]
+                        },
+                        {"name": "Upload", "request": {"method": "POST", "url": "{{base_url}}/upload",
+                                                      "body": {"mode": "formdata", "formdata": []}}}
+                    ]
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn postman_variables_are_translated() {
+        assert_eq!(translate("{{base_url}}/users/{{ id }}"), "${base_url}/users/${id}");
+        assert_eq!(translate("no variables {{"), "no variables {{");
+        assert!(PostmanGrouping::parse(Some("suite")).is_err());
+        assert_eq!(PostmanGrouping::parse(None).unwrap(), PostmanGrouping::Folder);
+    }
+
+    #[tokio::test]
+    async fn folders_are_imported_as_test_cases() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+
+        let report = PostmanImporter::new(&trx, app_id)
+            .import(collection(), PostmanGrouping::Folder).await.expect("import");
+
+        assert_eq!((report.api_steps, report.cases, report.profiles), (3, 2, 1));
+        let reasons: Vec<&str> = report.unsupported.iter().map(|issue| issue.reason.as_str()).collect();
+        assert_eq!(reasons, vec!["Pre-request and test scripts are not imported",
+                                 "Postman dynamic variable '$guid'",
+                                 "Unsupported body mode 'formdata'"]);
+
+        let create = step::Entity::find()
+            .filter(step::Column::Method.eq(ApiMethod::Post))
+            .one(&trx).await.expect("step").expect("create user step");
+        assert_eq!(create.url, "${base_url}/users");
+        assert_eq!(create.query, Some(json!({"notify": "false"})));
+        assert_eq!(create.headers, Some(json!({"X-Trace": "${trace}", "content-type": "application/json"})));
+        assert_eq!(create.body.as_deref(), Some(r#"{"name": "${name}"}"#));
+        assert_eq!(create.auth, Some(json!({"type": "bearer", "token": "${token}"})));
+
+        let delete = step::Entity::find()
+            .filter(step::Column::Method.eq(ApiMethod::Delete))
+            .one(&trx).await.expect("step").expect("delete user step");
+        assert_eq!(delete.url, "${base_url}/users/${user_id}");
+        assert_eq!(delete.auth, Some(json!({"type": "basic", "username": "admin", "password": "${password}"})));
+
+        let users = case::Entity::find()
+            .filter(case::Column::Name.eq("Users"))
+            .one(&trx).await.expect("case").expect("users case");
+        let blocks = case_block::Entity::find()
+            .filter(case_block::Column::CaseId.eq(users.id))
+            .order_by_asc(case_block::Column::ExecutionOrder)
+            .all(&trx).await.expect("blocks");
+        assert_eq!(blocks.iter().map(|b| b.reference).collect::<Vec<_>>(), vec![Some(create.id), Some(delete.id)]);
+        assert!(blocks.iter().all(|b| b.type_field == BlockType::ApiStep));
+
+        let data = profile_data::Entity::find().all(&trx).await.expect("profile data");
+        assert_eq!(data.len(), 1);
+        assert_eq!((data[0].key.as_str(), data[0].value.as_str()), ("base_url", "https://users.test"));
+    }
+
+    #[tokio::test]
+    async fn collection_grouping_creates_single_case() {
+        let trx = trx().await;
+
+        let report = PostmanImporter::new(&trx, Uuid::new_v4())
+            .import(collection(), PostmanGrouping::Collection).await.expect("import");
+
+        assert_eq!((report.api_steps, report.cases), (3, 1));
+        let cases = case::Entity::find().all(&trx).await.expect("cases");
+        assert_eq!(cases[0].name, "Users API");
+        assert!(PostmanImporter::new(&trx, Uuid::new_v4())
+            .import(json!({"info": {"name": "Old", "schema": "v1"}}), PostmanGrouping::Folder).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn graphql_body_is_imported_as_graphql_document() {
+        let trx = trx().await;
+        let graphql = |variables: &str| json!({
+            "mode": "graphql",
+            "graphql": {"query": "query User($id: ID!) { user(id: $id) { name } }", "variables": variables}
+        });
+        let collection = json!({
+            "info": {"name": "GraphQL", "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"},
+            "item": [
+                {"name": "User", "request": {"method": "POST", "url": "{{base_url}}/graphql",
+                                            "body": graphql("{\"id\": \"{{user_id}}\"}")}},
+                {"name": "Broken", "request": {"method": "POST", "url": "{{base_url}}/graphql",
+                                              "body": graphql("{\"id\": {{user_id}}}")}}
+            ]
+        });
+
+        let report = PostmanImporter::new(&trx, Uuid::new_v4())
+            .import(collection, PostmanGrouping::Collection).await.expect("import");
+
+        assert_eq!(report.api_steps, 1);
+        assert_eq!(report.unsupported.iter().map(|issue| issue.reason.as_str()).collect::<Vec<_>>(),
+                   vec!["GraphQL variables are not json"]);
+        let step = step::Entity::find().one(&trx).await.expect("step").expect("graphql step");
+        assert_eq!(step.body, None);
+        assert_eq!(step.graphql, Some(json!({
+            "query": "query User($id: ID!) { user(id: $id) { name } }",
+            "variables": {"id": "${user_id}"}
+        })));
+        assert_eq!(step.graphql().expect("graphql").and_then(|graphql| graphql.operation()), Some("query"));
+    }
+
+    #[tokio::test]
+    async fn environment_is_imported_as_profile() {
+        let trx = trx().await;
+        let environment = json!({
+            "name": "Staging",
+            "values": [
+                {"key": "base_url", "value": "https://staging.test", "type": "default", "enabled": true},
+                {"key": "token", "value": "{{secret}}", "type": "secret", "enabled": true},
+                {"key": "unused", "value": "x", "enabled": false}
+            ]
+        });
+
+        let report = PostmanImporter::new(&trx, Uuid::new_v4())
+            .import_environment(environment).await.expect("import");
+
+        assert_eq!(report.profiles, 1);
+        let profile = profile::Entity::find().one(&trx).await.expect("profile").expect("staging profile");
+        assert_eq!(profile.name, "Staging");
+        let data = profile_data::Entity::find()
+            .filter(profile_data::Column::ProfileId.eq(profile.id))
+            .order_by_asc(profile_data::Column::Key)
+            .all(&trx).await.expect("profile data");
+        let values: Vec<(&str, &str, &str)> = data.iter()
+            .map(|d| (d.key.as_str(), d.value.as_str(), d.value_type.as_str()))
+            .collect();
+        assert_eq!(values, vec![("base_url", "https://staging.test", "string"), ("token", "${secret}", "secret")]);
+    }
+}
@@ -0,0 +1,177 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use sea_orm::{ColumnTrait, DatabaseTransaction, EntityTrait, QueryFilter, QueryOrder};
+    use uuid::Uuid;
+
+    use engine::server::recorder::{CandidateLocator, RecordedElement, RecordedEvent, Recorder, RecordingRegistry,
+                                   RecordingSession};
+    use entity::prelude::target::ActionTargetKind;
+    use entity::test::ui::action::action;
+    use entity::test::ui::action::action::ActionKind;
+    use entity::test::ui::action::group;
+    use entity::test::ui::elements::ElementCreateType;
+    use entity::test::ui::{elements, locator, screen};
+
+    use crate::common::{self, table};
+
+    const LOGIN: &str = "https://orca.test/login?next=home";
+    const HOME: &str = "https://orca.test/home";
+
+    async fn trx() -> DatabaseTransaction {
+        common::trx(vec![
+            table(group::Entity),
+            table(action::Entity),
+            table(screen::Entity),
+            table(elements::Entity),
+            table(locator::Entity),
+        ]).await
+    }
+
+    fn email() -> RecordedElement {
+        RecordedElement {
+            name: Some("Email".to_string()),
+            tag: Some("input".to_string()),
+            locators: vec![
+                CandidateLocator { kind: ActionTargetKind::Id, value: "email".to_string() },
+                CandidateLocator { kind: ActionTargetKind::Name, value: "email".to_string() },
+            ],
+        }
+    }
+
+    fn submit() -> RecordedElement {
+        RecordedElement {
+            name: None,
+            tag: Some("button".to_string()),
+            locators: vec![CandidateLocator { kind: ActionTargetKind::Css, value: "#submit".to_string() }],
+        }
+    }
+
+    /// login - open the login page, type the email key by key and submit
+    fn login() -> Vec<RecordedEvent> {
+        let input = |value: &str| RecordedEvent::Input {
+            url: LOGIN.to_string(), element: email(), value: value.to_string(),
+        };
+        vec![
+            RecordedEvent::Navigate { url: LOGIN.to_string(), typed: true },
+            input("o"),
+            input("orca"),
+            input("orca@orca.test"),
+            RecordedEvent::Click { url: LOGIN.to_string(), element: submit() },
+            RecordedEvent::Navigate { url: HOME.to_string(), typed: false },
+        ]
+    }
+
+    #[tokio::test]
+    async fn recording_is_saved_as_action_group() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+        let registry = RecordingRegistry::new();
+        let recording = registry.start(app_id, "Login".to_string());
+        assert_eq!(registry.push(recording.id, login()), Some(6));
+        let session = registry.remove(recording.id).expect("session");
+
+        let group = Recorder::new(&trx, app_id).save(session).await.expect("save recording");
+
+        let actions = action::Entity::find()
+            .filter(action::Column::ActionGroupId.eq(group.id))
+            .order_by_asc(action::Column::ExecutionOrder)
+            .all(&trx).await.expect("actions");
+        let kinds: Vec<ActionKind> = actions.iter().map(|a| a.kind.clone()).collect();
+        assert_eq!(kinds, vec![ActionKind::Open, ActionKind::Enter, ActionKind::Click]);
+        assert_eq!(actions[0].data_value.as_deref(), Some(LOGIN));
+        assert_eq!(actions[1].data_value.as_deref(), Some("orca@orca.test"));
+        assert!(actions[1..].iter().all(|a| a.element_id.is_some() && a.target_value.is_none()));
+    }
+
+    #[tokio::test]
+    async fn recorded_elements_go_to_the_object_repository() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+        let session = RecordingRegistry::new().start(app_id, "Login".to_string());
+        let session = RecordingSession { events: login(), ..session };
+
+        Recorder::new(&trx, app_id).save(session).await.expect("save recording");
+
+        let screens = screen::Entity::find().all(&trx).await.expect("screens");
+        assert_eq!(screens.len(), 1);
+        assert_eq!(screens[0].name, "https://orca.test/login");
+        let elements = elements::Entity::find().all(&trx).await.expect("elements");
+        assert_eq!(elements.len(), 2);
+        let email = elements.iter().find(|e| e.name == "Email").expect("email element");
+        assert_eq!(email.created_type, ElementCreateType::Record);
+        assert_eq!((email.locator_type.clone(), email.locator_value.as_str()), (ActionTargetKind::Id, "email"));
+        let fallbacks = locator::Entity::find()
+            .filter(locator::Column::ElementId.eq(email.id))
+            .all(&trx).await.expect("fallbacks");
+        assert_eq!(fallbacks.len(), 1);
+        assert_eq!(fallbacks[0].locator_type, ActionTargetKind::Name);
+    }
+
+    #[tokio::test]
+    async fn typed_navigation_after_the_click_is_kept() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+        let session = RecordingRegistry::new().start(app_id, "Login".to_string());
+        let mut events = login();
+        events.push(RecordedEvent::Click { url: HOME.to_string(), element: submit() });
+        events.push(RecordedEvent::Navigate { url: LOGIN.to_string(), typed: true });
+        let session = RecordingSession { events, ..session };
+
+        let group = Recorder::new(&trx, app_id).save(session).await.expect("save recording");
+
+        let actions = action::Entity::find()
+            .filter(action::Column::ActionGroupId.eq(group.id))
+            .order_by_asc(action::Column::ExecutionOrder)
+            .all(&trx).await.expect("actions");
+        let kinds: Vec<ActionKind> = actions.iter().map(|a| a.kind.clone()).collect();
+        assert_eq!(kinds, vec![ActionKind::Open, ActionKind::Enter, ActionKind::Click, ActionKind::Click, ActionKind::Open]);
+        assert_eq!(actions[4].data_value.as_deref(), Some(LOGIN));
+    }
+
+    #[tokio::test]
+    async fn recording_again_reuses_the_repository_elements() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+        let registry = RecordingRegistry::new();
+        for name in ["Login", "Login again"] {
+            let session = registry.start(app_id, name.to_string());
+            let session = RecordingSession { events: login(), ..session };
+            Recorder::new(&trx, app_id).save(session).await.expect("save recording");
+        }
+
+        assert_eq!(screen::Entity::find().all(&trx).await.expect("screens").len(), 1);
+        assert_eq!(elements::Entity::find().all(&trx).await.expect("elements").len(), 2);
+        assert_eq!(locator::Entity::find().all(&trx).await.expect("fallbacks").len(), 1);
+        let element_ids: Vec<Option<Uuid>> = action::Entity::find()
+            .filter(action::Column::Kind.eq(ActionKind::Click))
+            .all(&trx).await.expect("actions")
+            .into_iter().map(|a| a.element_id).collect();
+        assert_eq!(element_ids.len(), 2);
+        assert_eq!(element_ids[0], element_ids[1]);
+    }
+
+    #[test]
+    fn unknown_session_is_not_recorded() {
+        assert_eq!(RecordingRegistry::new().push(Uuid::new_v4(), login()), None);
+    }
+
+    #[test]
+    fn idle_and_extra_sessions_are_dropped() {
+        let registry = RecordingRegistry::with_limits(Duration::from_millis(50), 2);
+        let app_id = Uuid::new_v4();
+        let first = registry.start(app_id, "First".to_string());
+        let second = registry.start(app_id, "Second".to_string());
+        assert_eq!(registry.push(first.id, login()), Some(6));
+        let third = registry.start(app_id, "Third".to_string());
+        assert!(registry.get(second.id).is_none());
+        assert!(registry.get(first.id).is_some());
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert_eq!(registry.push(third.id, login()), None);
+        assert!(registry.remove(first.id).is_none());
+    }
+}
@@ -0,0 +1,24 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use engine::server::scheduler::next_run;
+
+    #[test]
+    fn next_run_in_timezone() {
+        let after = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").expect("valid date");
+        // 09:00 every day in Asia/Kolkata is 03:30 UTC
+        let next = next_run("0 0 9 * * *", "Asia/Kolkata", after).expect("valid schedule");
+        assert_eq!(
+            next.with_timezone(&Utc).to_rfc3339(),
+            "2024-01-01T03:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn next_run_invalid_schedule() {
+        let after = Utc::now().into();
+        assert!(next_run("not a cron", "UTC", after).is_err());
+        assert!(next_run("0 0 9 * * *", "Mars/Olympus", after).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+mod common;
+
+#[cfg(all(test, feature = "api-automation"))]
+mod tests {
+    use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, Database, DatabaseTransaction, DbBackend,
+                  EntityTrait, QueryFilter, Value, Values};
+    use sea_orm::ActiveValue::Set;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use engine::controller::data::{DataScope, Variables};
+    use engine::controller::sql::{bind, returns_rows, SqlConnections, SqlController};
+    use engine::error::EngineError;
+    use engine::server::registry::ExecutionToken;
+    use entity::test::sql::{connection, step};
+    use entity::test::ui::log::item_log;
+    use entity::test::ui::log::item_log::{ItemLogStatus, ItemLogType};
+    use entity::test::ui::request;
+    use entity::test::ui::request::{ExecutionKind, ExecutionStatus, ExecutionType};
+
+    use crate::common::{self, table};
+
+    async fn trx() -> DatabaseTransaction {
+        common::trx(vec![
+            table(request::Entity),
+            table(item_log::Entity),
+            table(connection::Entity),
+            table(step::Entity),
+        ]).await
+    }
+
+    /// shop - sqlite file the SQL steps connect to, like the database of the application under test
+    async fn shop() -> String {
+        let path = std::env::temp_dir().join(format!("orca-shop-{}.db", Uuid::new_v4()));
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+        let db = Database::connect(&url).await.expect("shop database");
+        db.execute_unprepared("CREATE TABLE orders (id INTEGER PRIMARY KEY, status TEXT NOT NULL, total REAL)")
+            .await.expect("orders table");
+        db.close().await.expect("close");
+        url
+    }
+
+    fn sql_step(app_id: Uuid, query: &str) -> step::ActiveModel {
+        step::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            name: Set(query.to_string()),
+            description: Set(None),
+            connection: Set("shop".to_string()),
+            query: Set(query.to_string()),
+            expected_rows: Set(None),
+            checks: Set(None),
+            timeout: Set(Some(5000)),
+            app_id: Set(app_id),
+        }
+    }
+
+    #[test]
+    fn placeholders_are_bound_as_parameters() {
+        let mut scope = DataScope::new();
+        scope.insert("id", "42");
+        scope.insert("status", "PAID");
+        scope.insert("paid", "true");
+        let query = "SELECT * FROM orders WHERE id = ${id} AND status = ${ status } AND paid = ${paid}";
+
+        let postgres = bind(&scope, query, DbBackend::Postgres).expect("statement");
+        assert_eq!(postgres.sql, "SELECT * FROM orders WHERE id = $1 AND status = $2 AND paid = $3");
+        assert_eq!(postgres.values, Some(Values(vec![
+            Value::String(Some(Box::new("42".to_string()))),
+            Value::String(Some(Box::new("PAID".to_string()))),
+            Value::String(Some(Box::new("true".to_string()))),
+        ])));
+        let sqlite = bind(&scope, query, DbBackend::Sqlite).expect("statement");
+        assert_eq!(sqlite.sql, "SELECT * FROM orders WHERE id = ? AND status = ? AND paid = ?");
+        assert_eq!(bind(&scope, "DELETE FROM orders WHERE id = ${order_id}", DbBackend::Postgres).err(),
+                   Some(vec!["order_id".to_string()]));
+
+        assert!(returns_rows("  select 1"));
+        assert!(returns_rows("WITH paid AS (SELECT 1) SELECT * FROM paid"));
+        assert!(returns_rows("INSERT INTO orders (status) VALUES ('NEW') RETURNING id"));
+        assert!(!returns_rows("DELETE FROM orders"));
+    }
+
+    #[tokio::test]
+    async fn query_results_are_checked_and_stored() {
+        let trx = trx().await;
+        let app_id = Uuid::new_v4();
+        let profile_id = Uuid::new_v4();
+        let url = shop().await;
+        for (profile, url) in [(None, "sqlite::memory:".to_string()), (Some(profile_id), url)] {
+            connection::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                name: Set("shop".to_string()),
+                url: Set(url),
+                profile_id: Set(profile),
+                app_id: Set(app_id),
+            }.insert(&trx).await.expect("connection");
+        }
+        let mut seed = sql_step(app_id, "INSERT INTO orders (id, status, total) VALUES (${order_id}, 'PAID', 9.5)");
+        seed.expected_rows = Set(Some(1));
+        let seed = seed.insert(&trx).await.expect("seed step");
+        let mut verify = sql_step(app_id, "SELECT id, status, total FROM orders WHERE id = ${order_id}");
+        verify.expected_rows = Set(Some(1));
+        verify.checks = Set(Some(json!([
+            {"column": "status", "expected": "PAID", "variable": "order_status"},
+            {"column": "total", "match_mode": "GreaterThan", "expected": "5"}
+        ])));
+        let verify = verify.insert(&trx).await.expect("verify step");
+        let mut missing = sql_step(app_id, "SELECT status FROM orders WHERE id = ${order_id}");
+        missing.checks = Set(Some(json!([{"column": "status", "expected": "REFUNDED"}])));
+        let missing = missing.insert(&trx).await.expect("missing step");
+        let mut er = request::new(Uuid::new_v4(), ExecutionType::TestCase, ExecutionKind::Trigger,
+                                  ExecutionStatus::Running, 0, false, None);
+        er.args = Set(Some(json!({"profile_id": profile_id.to_string()})));
+        let er = er.insert(&trx).await.expect("execution request");
+        let variables = Variables::new();
+        variables.set("order_id", "7");
+        let connections = SqlConnections::new();
+        let controller = SqlController::new(&trx, variables.clone(), connections.clone(), ExecutionToken::new());
+
+        controller.execute(seed.id, &er, None).await.expect("seed");
+        controller.execute(verify.id, &er, None).await.expect("verify");
+        let result = controller.execute(missing.id, &er, None).await;
+
+        assert_eq!(variables.get("order_status").as_deref(), Some("PAID"));
+        assert!(matches!(result, Err(EngineError::AssertionFailed(message))
+            if message == "row 0 column 'status' expected Equals 'REFUNDED' but got 'PAID'"));
+        let logs = item_log::Entity::find()
+            .filter(item_log::Column::RefType.eq(ItemLogType::SqlStep))
+            .all(&trx).await.expect("item logs");
+        let statuses: Vec<ItemLogStatus> = logs.into_iter().map(|log| log.status).collect();
+        assert_eq!(statuses, vec![ItemLogStatus::Success, ItemLogStatus::Success, ItemLogStatus::Failed]);
+        connections.close_all().await;
+    }
+
+    #[tokio::test]
+    async fn connection_is_reused_within_the_execution() {
+        let connections = SqlConnections::new();
+        let db = connections.connect("sqlite::memory:").await.expect("connect");
+        let again = connections.connect("sqlite::memory:").await.expect("connect");
+        again.execute_unprepared("SELECT 1").await.expect("open connection");
+
+        connections.close_all().await;
+
+        assert!(db.execute_unprepared("SELECT 1").await.is_err());
+        assert!(again.execute_unprepared("SELECT 1").await.is_err());
+    }
+}
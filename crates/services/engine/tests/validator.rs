@@ -0,0 +1,317 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ActiveModelTrait, DatabaseTransaction};
+    use sea_orm::ActiveValue::Set;
+    use uuid::Uuid;
+
+    use engine::controller::validator::{check_selector, ExecutionReport, ReportIssue, ReportItem, Validator};
+    use entity::prelude::{case_block, data_binding};
+    use entity::prelude::case_block::{BlockKind, BlockType};
+    use entity::prelude::data_binding::DataBindingKind;
+    use entity::prelude::target::ActionTargetKind;
+    use entity::test::{datatable, field};
+    use entity::test::field::FieldDataKind;
+    use entity::test::profile::data as profile_data;
+    use entity::test::profile::profile;
+    use entity::test::ui::action::action;
+    use entity::test::ui::action::action::ActionKind;
+    use entity::test::ui::action::group;
+    use entity::test::ui::action::group::ActionGroupKind;
+    use entity::test::ui::case::case;
+
+    use crate::common::{self, table};
+
+    /// Fixture - sqlite in memory with the tables the validator walks
+    struct Fixture {
+        trx: DatabaseTransaction,
+        case_id: Uuid,
+    }
+
+    impl Fixture {
+        async fn new() -> Self {
+            let trx = common::trx(vec![
+                table(case::Entity),
+                table(case_block::Entity),
+                table(data_binding::Entity),
+                table(group::Entity),
+                table(action::Entity),
+                table(datatable::Entity),
+                table(field::Entity),
+                table(profile::Entity),
+                table(profile_data::Entity),
+            ]).await;
+            let case_id = Uuid::new_v4();
+            case::ActiveModel {
+                id: Set(case_id),
+                name: Set("Login".to_string()),
+                description: Set(None),
+                app_id: Set(Uuid::new_v4()),
+            }.insert(&trx).await.expect("case");
+            Self { trx, case_id }
+        }
+
+        /// group - action group with the actions in the given order
+        async fn group(&self, actions: Vec<action::ActiveModel>) -> Uuid {
+            let group_id = Uuid::new_v4();
+            group::ActiveModel {
+                id: Set(group_id),
+                name: Set("Steps".to_string()),
+                description: Set(None),
+                type_field: Set(ActionGroupKind::ActionGroup),
+                app_id: Set(Uuid::new_v4()),
+            }.insert(&self.trx).await.expect("action group");
+            for (order, mut action) in actions.into_iter().enumerate() {
+                action.execution_order = Set(order as i32 + 1);
+                action.action_group_id = Set(group_id);
+                action.insert(&self.trx).await.expect("action");
+            }
+            group_id
+        }
+
+        async fn block(&self, order: i32, kind: BlockKind, type_field: BlockType, reference: Option<Uuid>) -> Uuid {
+            self.child(None, order, kind, type_field, reference).await
+        }
+
+        /// child - case block under the parent block
+        async fn child(&self, parent_id: Option<Uuid>, order: i32, kind: BlockKind, type_field: BlockType,
+                       reference: Option<Uuid>) -> Uuid {
+            let block_id = Uuid::new_v4();
+            case_block::ActiveModel {
+                id: Set(block_id),
+                execution_order: Set(order),
+                kind: Set(kind),
+                name: Set(None),
+                desc: Set(None),
+                type_field: Set(type_field),
+                reference: Set(reference),
+                parent_id: Set(parent_id),
+                timeout: Set(None),
+                case_id: Set(self.case_id),
+            }.insert(&self.trx).await.expect("case block");
+            block_id
+        }
+
+        /// binding - data binding of the block
+        async fn binding(&self, block_id: Uuid, kind: DataBindingKind, key: &str, value: &str) -> Uuid {
+            let binding_id = Uuid::new_v4();
+            data_binding::ActiveModel {
+                id: Set(binding_id),
+                key: Set(key.to_string()),
+                value: Set(value.to_string()),
+                kind: Set(kind),
+                block_id: Set(block_id),
+            }.insert(&self.trx).await.expect("data binding");
+            binding_id
+        }
+
+        /// table - datatable with the string fields
+        async fn table(&self, id: i32, fields: &[&str]) {
+            datatable::ActiveModel {
+                id: Set(id),
+                name: Set("Users".to_string()),
+                table_name: Set(format!("table_{id}")),
+                description: Set(None),
+                app_id: Set(Uuid::new_v4()),
+            }.insert(&self.trx).await.expect("datatable");
+            for field_id in fields {
+                field::ActiveModel {
+                    field_id: Set(field_id.to_string()),
+                    table_id: Set(id),
+                    name: Set(field_id.to_string()),
+                    kind: Set(FieldDataKind::String),
+                    option: Set(None),
+                }.insert(&self.trx).await.expect("field");
+            }
+        }
+
+        /// profile - profile with the data
+        async fn profile(&self, data: &[(&str, &str)]) -> Uuid {
+            let profile_id = Uuid::new_v4();
+            profile::ActiveModel {
+                id: Set(profile_id),
+                name: Set("Staging".to_string()),
+                description: Set(None),
+                app_id: Set(Uuid::new_v4()),
+            }.insert(&self.trx).await.expect("profile");
+            for (key, value) in data {
+                profile_data::ActiveModel {
+                    id: Set(Uuid::new_v4()),
+                    key: Set(key.to_string()),
+                    value_type: Set("string".to_string()),
+                    value: Set(value.to_string()),
+                    description: Set(None),
+                    profile_id: Set(profile_id),
+                }.insert(&self.trx).await.expect("profile data");
+            }
+            profile_id
+        }
+
+        async fn validate(&self, profile_id: Option<Uuid>) -> ExecutionReport {
+            Validator::new(&self.trx).validate_case(self.case_id, profile_id).await.expect("validation")
+        }
+    }
+
+    fn step(kind: ActionKind, target: Option<&str>, data: Option<&str>) -> action::ActiveModel {
+        action::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            execution_order: Set(0),
+            description: Set(None),
+            kind: Set(kind),
+            data_kind: Set(None),
+            data_value: Set(data.map(str::to_string)),
+            target_kind: Set(target.map(|_| ActionTargetKind::Css)),
+            target_value: Set(target.map(str::to_string)),
+            element_id: Set(None),
+            timeout: Set(None),
+            action_group_id: Set(Uuid::nil()),
+        }
+    }
+
+    /// issue - issue of the item found in the block
+    fn issue(item: ReportItem, item_id: impl ToString, block_id: Uuid, message: &str) -> ReportIssue {
+        ReportIssue {
+            item,
+            item_id: item_id.to_string(),
+            block_id: Some(block_id),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn valid_selectors() {
+        assert!(check_selector(&ActionTargetKind::Css, "#search-form > fieldset button").is_ok());
+        assert!(check_selector(&ActionTargetKind::Xpath, "//*[@id='search-form']/fieldset/button").is_ok());
+        assert!(check_selector(&ActionTargetKind::Id, "searchInput").is_ok());
+        assert!(check_selector(&ActionTargetKind::Tag, "button").is_ok());
+        assert!(check_selector(&ActionTargetKind::LinkText, "Sign in").is_ok());
+        assert!(check_selector(&ActionTargetKind::Shadow, "app-shell >>> #login").is_ok());
+    }
+
+    #[test]
+    fn invalid_selectors() {
+        assert!(check_selector(&ActionTargetKind::Css, "div[").is_err());
+        assert!(check_selector(&ActionTargetKind::Xpath, "//*[@id='search-form'").is_err());
+        assert!(check_selector(&ActionTargetKind::ClassName, "btn btn-primary").is_err());
+        assert!(check_selector(&ActionTargetKind::Shadow, "app-shell >>> div[").is_err());
+        assert!(check_selector(&ActionTargetKind::Name, " ").is_err());
+    }
+
+    #[tokio::test]
+    async fn actions_missing_their_fields_are_reported() {
+        let fixture = Fixture::new().await;
+        let mut no_kind = step(ActionKind::Click, Some("#submit"), None);
+        no_kind.target_kind = Set(None);
+        let mut no_value = step(ActionKind::Click, None, None);
+        no_value.target_kind = Set(Some(ActionTargetKind::Css));
+        let no_data = step(ActionKind::Enter, Some("#email"), None);
+        let ids = [&no_kind, &no_value, &no_data].map(|action| action.id.clone().unwrap());
+        let group_id = fixture.group(vec![no_kind, no_value, no_data]).await;
+        let block_id = fixture.block(1, BlockKind::Reference, BlockType::ActionGroup, Some(group_id)).await;
+
+        let report = fixture.validate(None).await;
+
+        assert_eq!(report.actions, 3);
+        assert_eq!(report.issues, vec![
+            issue(ReportItem::Action, ids[0], block_id, "Click is missing target_kind"),
+            issue(ReportItem::Action, ids[1], block_id, "Click is missing target_value"),
+            issue(ReportItem::Action, ids[2], block_id, "Enter is missing data_value"),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn unresolved_profile_placeholder_is_reported() {
+        let fixture = Fixture::new().await;
+        let password = step(ActionKind::Enter, Some("#password"), Some("${password}"));
+        let password_id = password.id.clone().unwrap();
+        let group_id = fixture.group(vec![
+            step(ActionKind::Enter, Some("#email"), Some("${email}")),
+            password,
+        ]).await;
+        let block_id = fixture.block(1, BlockKind::Reference, BlockType::ActionGroup, Some(group_id)).await;
+        let profile_id = fixture.profile(&[("email", "orca@orca.test")]).await;
+
+        let report = fixture.validate(Some(profile_id)).await;
+
+        assert_eq!(report.issues, vec![
+            issue(ReportItem::Action, password_id, block_id,
+                  r#"Unresolved placeholder(s) ["password"] in data_value"#),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn datatable_binding_to_a_missing_field_is_reported() {
+        let fixture = Fixture::new().await;
+        fixture.table(1, &["email"]).await;
+        let group_id = fixture.group(vec![step(ActionKind::Enter, Some("#password"), Some("${password}"))]).await;
+        let block_id = fixture.block(1, BlockKind::SelfReference, BlockType::DataTable, None).await;
+        fixture.binding(block_id, DataBindingKind::Field, "email", "1.email").await;
+        let binding_id = fixture.binding(block_id, DataBindingKind::Field, "password", "1.password").await;
+        fixture.child(Some(block_id), 1, BlockKind::Reference, BlockType::ActionGroup, Some(group_id)).await;
+
+        let report = fixture.validate(None).await;
+
+        assert_eq!(report.issues, vec![
+            issue(ReportItem::DataBinding, binding_id, block_id, "Field 'password' not found in DataTable 1"),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn deleted_action_group_is_reported_on_its_block() {
+        let fixture = Fixture::new().await;
+        let group_id = Uuid::new_v4();
+        let block_id = fixture.block(1, BlockKind::Reference, BlockType::ActionGroup, Some(group_id)).await;
+
+        let report = fixture.validate(None).await;
+
+        assert_eq!(report.issues, vec![
+            issue(ReportItem::ActionGroup, group_id, block_id, "Action Group not found"),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn reference_block_without_reference_is_reported_on_itself() {
+        let fixture = Fixture::new().await;
+        let block_id = fixture.block(1, BlockKind::Reference, BlockType::ActionGroup, None).await;
+
+        let report = fixture.validate(None).await;
+
+        assert_eq!(report.issues, vec![
+            issue(ReportItem::Block, block_id, block_id, "Reference block has no reference"),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn datatable_block_with_a_missing_table_is_reported_on_itself() {
+        let fixture = Fixture::new().await;
+        let block_id = fixture.block(1, BlockKind::SelfReference, BlockType::DataTable, None).await;
+        let binding_id = fixture.binding(block_id, DataBindingKind::Field, "email", "99.email").await;
+
+        let report = fixture.validate(None).await;
+
+        assert_eq!(report.issues, vec![
+            issue(ReportItem::DataBinding, binding_id, block_id, "DataTable 99 not found"),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn clean_case_has_no_issues() {
+        let fixture = Fixture::new().await;
+        fixture.table(1, &["email"]).await;
+        let group_id = fixture.group(vec![
+            step(ActionKind::Open, None, Some("${url}")),
+            step(ActionKind::Enter, Some("#email"), Some("${email}")),
+            step(ActionKind::Click, Some("#submit"), None),
+        ]).await;
+        let block_id = fixture.block(1, BlockKind::SelfReference, BlockType::DataTable, None).await;
+        fixture.binding(block_id, DataBindingKind::Field, "email", "1.email").await;
+        fixture.child(Some(block_id), 1, BlockKind::Reference, BlockType::ActionGroup, Some(group_id)).await;
+        let profile_id = fixture.profile(&[("url", "https://orca.test/login")]).await;
+
+        let report = fixture.validate(Some(profile_id)).await;
+
+        assert!(report.is_valid(), "{:?}", report.issues);
+        assert_eq!((report.cases, report.blocks, report.actions), (1, 2, 3));
+    }
+}